@@ -714,9 +714,20 @@ struct RowIdWithScore;
 struct BM25Result;
 struct BoolResult;
 struct StringResult;
+struct U64Result;
+struct BytesResult;
+struct ForceMergeResult;
+struct ReindexProgressResult;
+struct SegmentChangesResult;
+struct VerifyIndexResult;
+struct RowIdWithError;
+struct BatchIndexResult;
 struct DocWithFreq;
 struct FieldTokenNums;
 struct Statistics;
+struct SegmentStatistics;
+struct FieldSpaceUsage;
+struct IndexStatisticsResult;
 
 #ifndef CXXBRIDGE1_STRUCT_RowIdWithScore
 #define CXXBRIDGE1_STRUCT_RowIdWithScore
@@ -764,6 +775,102 @@ struct StringResult final {
 };
 #endif // CXXBRIDGE1_STRUCT_StringResult
 
+#ifndef CXXBRIDGE1_STRUCT_U64Result
+#define CXXBRIDGE1_STRUCT_U64Result
+struct U64Result final {
+  ::std::uint64_t result;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_U64Result
+
+#ifndef CXXBRIDGE1_STRUCT_BytesResult
+#define CXXBRIDGE1_STRUCT_BytesResult
+struct BytesResult final {
+  ::rust::Vec<::std::uint8_t> result;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_BytesResult
+
+#ifndef CXXBRIDGE1_STRUCT_ForceMergeResult
+#define CXXBRIDGE1_STRUCT_ForceMergeResult
+struct ForceMergeResult final {
+  ::std::uint32_t segments_before;
+  ::std::uint32_t segments_after;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_ForceMergeResult
+
+#ifndef CXXBRIDGE1_STRUCT_ReindexProgressResult
+#define CXXBRIDGE1_STRUCT_ReindexProgressResult
+struct ReindexProgressResult final {
+  ::std::uint64_t reindexed_docs;
+  ::std::uint64_t total_docs;
+  bool finished;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_ReindexProgressResult
+
+#ifndef CXXBRIDGE1_STRUCT_SegmentChangesResult
+#define CXXBRIDGE1_STRUCT_SegmentChangesResult
+struct SegmentChangesResult final {
+  ::std::uint64_t opstamp;
+  ::rust::Vec<::rust::String> added_files;
+  ::rust::Vec<::rust::String> removed_files;
+  ::rust::String meta_json;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_SegmentChangesResult
+
+#ifndef CXXBRIDGE1_STRUCT_VerifyIndexResult
+#define CXXBRIDGE1_STRUCT_VerifyIndexResult
+struct VerifyIndexResult final {
+  ::rust::Vec<::rust::String> problems;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_VerifyIndexResult
+
+#ifndef CXXBRIDGE1_STRUCT_RowIdWithError
+#define CXXBRIDGE1_STRUCT_RowIdWithError
+struct RowIdWithError final {
+  ::std::uint64_t row_id;
+  ::rust::String error_msg;
+
+  bool operator==(RowIdWithError const &) const noexcept;
+  bool operator!=(RowIdWithError const &) const noexcept;
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_RowIdWithError
+
+#ifndef CXXBRIDGE1_STRUCT_BatchIndexResult
+#define CXXBRIDGE1_STRUCT_BatchIndexResult
+struct BatchIndexResult final {
+  ::std::uint64_t indexed_count;
+  ::rust::Vec<::RowIdWithError> failed_rows;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_BatchIndexResult
+
 #ifndef CXXBRIDGE1_STRUCT_DocWithFreq
 #define CXXBRIDGE1_STRUCT_DocWithFreq
 struct DocWithFreq final {
@@ -800,6 +907,50 @@ struct Statistics final {
 };
 #endif // CXXBRIDGE1_STRUCT_Statistics
 
+#ifndef CXXBRIDGE1_STRUCT_SegmentStatistics
+#define CXXBRIDGE1_STRUCT_SegmentStatistics
+struct SegmentStatistics final {
+  ::rust::String segment_id;
+  ::std::uint32_t max_doc;
+  ::std::uint32_t num_deleted_docs;
+  ::std::uint64_t postings_bytes;
+  ::std::uint64_t positions_bytes;
+  ::std::uint64_t term_dict_bytes;
+  ::std::uint64_t fast_fields_bytes;
+  ::std::uint64_t fieldnorms_bytes;
+  ::std::uint64_t store_bytes;
+  ::std::uint64_t deletes_bytes;
+  ::std::uint64_t total_bytes;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_SegmentStatistics
+
+#ifndef CXXBRIDGE1_STRUCT_FieldSpaceUsage
+#define CXXBRIDGE1_STRUCT_FieldSpaceUsage
+struct FieldSpaceUsage final {
+  ::rust::String field_name;
+  ::std::uint64_t num_bytes;
+
+  bool operator==(FieldSpaceUsage const &) const noexcept;
+  bool operator!=(FieldSpaceUsage const &) const noexcept;
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_FieldSpaceUsage
+
+#ifndef CXXBRIDGE1_STRUCT_IndexStatisticsResult
+#define CXXBRIDGE1_STRUCT_IndexStatisticsResult
+struct IndexStatisticsResult final {
+  ::rust::Vec<::SegmentStatistics> segments;
+  ::rust::Vec<::FieldSpaceUsage> fields;
+  ::std::uint64_t total_bytes;
+  ::std::int32_t error_code;
+  ::rust::String error_msg;
+
+  using IsRelocatable = ::std::true_type;
+};
+#endif // CXXBRIDGE1_STRUCT_IndexStatisticsResult
+
 ::BoolResult ffi_varify_index_parameter(::std::string const &index_json_parameter) noexcept;
 
 // Create tantivy index.
@@ -809,6 +960,37 @@ struct Statistics final {
 // - `index_json_parameter`: config index with json.
 ::BoolResult ffi_create_index_with_parameter(::std::string const &index_path, ::std::vector<::std::string> const &column_names, ::std::string const &index_json_parameter) noexcept;
 
+// Create an in-memory tantivy index, no file will be written.
+// It's written, committed and searched with `index_name` like a disk index.
+// arguments:
+// - `index_name`: logical name of index, can't be an existing path.
+// - `column_names`: which columns will be used to build index.
+// - `index_json_parameter`: config index with json.
+::BoolResult ffi_create_ram_index_with_parameter(::std::string const &index_name, ::std::vector<::std::string> const &column_names, ::std::string const &index_json_parameter) noexcept;
+
+// Save committed segments of an in-memory index as a disk index.
+// arguments:
+// - `index_name`: logical name of in-memory index.
+// - `index_path`: index directory, existing files will be removed.
+::BoolResult ffi_persist_ram_index(::std::string const &index_name, ::std::string const &index_path) noexcept;
+
+// Open existing tantivy index if compatible, otherwise create it.
+// Existing index with different column configs or schema will cause an error.
+// arguments:
+// - `index_path`: index directory.
+// - `column_names`: which columns will be used to build index.
+// - `index_json_parameter`: config index with json.
+// - `wipe_existing`: remove existing index and recreate it.
+::BoolResult ffi_open_or_create_index_with_parameter(::std::string const &index_path, ::std::vector<::std::string> const &column_names, ::std::string const &index_json_parameter, bool wipe_existing) noexcept;
+
+// Add new columns to an existing tantivy index, documents indexed before
+// don't have these columns. The merged config is persisted with the index.
+// arguments:
+// - `index_path`: index directory.
+// - `column_names`: new columns, can't exist in the index.
+// - `index_json_parameter`: tokenizer configs of new columns.
+::BoolResult ffi_add_columns_to_index(::std::string const &index_path, ::std::vector<::std::string> const &column_names, ::std::string const &index_json_parameter) noexcept;
+
 // Create tantivy index by default.
 // argements:
 // - `index_path`: index directory.
@@ -841,17 +1023,319 @@ struct Statistics final {
 // - `bool_column_docs`: align with column_names.
 ::BoolResult ffi_index_multi_type_column_docs(::std::string const &index_path, ::std::uint64_t row_id, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::string> const &bool_column_docs) noexcept;
 
+// Index multi type column docs with given rowId, including u64 and ip columns.
+// arguments:
+// - `index_path`: index directory.
+// - `row_id`: row_id given by ClickHouse.
+// - `text_column_names`: align with column_docs.
+// - `text_column_docs`: align with column_names.
+// - `i64_column_names`: align with column_docs.
+// - `i64_column_docs`: align with column_names.
+// - `f64_column_names`: align with column_docs.
+// - `f64_column_docs`: align with column_names.
+// - `bytes_column_names`: align with column_docs.
+// - `bytes_column_docs`: align with column_names.
+// - `date_column_names`: align with column_docs.
+// - `date_column_docs`: align with column_names.
+// - `bool_column_names`: align with column_docs.
+// - `bool_column_docs`: align with column_names.
+// - `u64_column_names`: align with column_docs.
+// - `u64_column_docs`: align with column_names.
+// - `ip_column_names`: align with column_docs.
+// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+::BoolResult ffi_index_multi_type_column_docs_with_u64_and_ip(::std::string const &index_path, ::std::uint64_t row_id, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::string> const &bool_column_docs, ::std::vector<::std::string> const &u64_column_names, ::std::vector<::std::uint64_t> const &u64_column_docs, ::std::vector<::std::string> const &ip_column_names, ::std::vector<::std::string> const &ip_column_docs) noexcept;
+
+// Replace the document of given rowId with multi type columns.
+// Deleting old document and adding the new one happen under the same writer lock,
+// both of them become visible on next commit.
+// arguments:
+// - `index_path`: index directory.
+// - `row_id`: row_id given by ClickHouse.
+// - `text_column_names`: align with column_docs.
+// - `text_column_docs`: align with column_names.
+// - `i64_column_names`: align with column_docs.
+// - `i64_column_docs`: align with column_names.
+// - `f64_column_names`: align with column_docs.
+// - `f64_column_docs`: align with column_names.
+// - `bytes_column_names`: align with column_docs.
+// - `bytes_column_docs`: align with column_names.
+// - `date_column_names`: align with column_docs.
+// - `date_column_docs`: align with column_names.
+// - `bool_column_names`: align with column_docs.
+// - `bool_column_docs`: align with column_names.
+// - `u64_column_names`: align with column_docs.
+// - `u64_column_docs`: align with column_names.
+// - `ip_column_names`: align with column_docs.
+// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+::BoolResult ffi_upsert_multi_type_column_docs(::std::string const &index_path, ::std::uint64_t row_id, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::string> const &bool_column_docs, ::std::vector<::std::string> const &u64_column_names, ::std::vector<::std::uint64_t> const &u64_column_docs, ::std::vector<::std::string> const &ip_column_names, ::std::vector<::std::string> const &ip_column_docs) noexcept;
+
+// Index multi type columns with given rowId, including json columns.
+// Each json doc should be a JSON object string, its nested keys can be queried
+// with a dotted path column name, e.g. `attrs.color`.
+// arguments:
+// - `index_path`: index directory.
+// - `row_id`: row_id given by ClickHouse.
+// - `text_column_names`: align with column_docs.
+// - `text_column_docs`: align with column_names.
+// - `i64_column_names`: align with column_docs.
+// - `i64_column_docs`: align with column_names.
+// - `f64_column_names`: align with column_docs.
+// - `f64_column_docs`: align with column_names.
+// - `bytes_column_names`: align with column_docs.
+// - `bytes_column_docs`: align with column_names.
+// - `date_column_names`: align with column_docs.
+// - `date_column_docs`: align with column_names.
+// - `bool_column_names`: align with column_docs.
+// - `bool_column_docs`: align with column_names.
+// - `u64_column_names`: align with column_docs.
+// - `u64_column_docs`: align with column_names.
+// - `ip_column_names`: align with column_docs.
+// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+// - `json_column_names`: align with column_docs.
+// - `json_column_docs`: align with column_names.
+::BoolResult ffi_index_multi_type_column_docs_with_json(::std::string const &index_path, ::std::uint64_t row_id, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::string> const &bool_column_docs, ::std::vector<::std::string> const &u64_column_names, ::std::vector<::std::uint64_t> const &u64_column_docs, ::std::vector<::std::string> const &ip_column_names, ::std::vector<::std::string> const &ip_column_docs, ::std::vector<::std::string> const &json_column_names, ::std::vector<::std::string> const &json_column_docs) noexcept;
+
+// Index multi-valued columns with given rowId, each column may contain several values.
+// Each `*_column_offsets` aligns with `*_column_names`, `offsets[c]` is the end position
+// (exclusive) of column `c` values in `*_column_docs`, the start is `offsets[c - 1]` or 0.
+// Values of a column are indexed as tantivy multi-value field, BM25, term and range
+// queries match a row if any of its values matches.
+// arguments:
+// - `index_path`: index directory.
+// - `row_id`: row_id given by ClickHouse.
+// - `text_column_names`: text columns name.
+// - `text_column_offsets`: end offsets of text columns values.
+// - `text_column_docs`: text columns values.
+// - `i64_column_names`: i64 columns name.
+// - `i64_column_offsets`: end offsets of i64 columns values.
+// - `i64_column_docs`: i64 columns values.
+// - `f64_column_names`: f64 columns name.
+// - `f64_column_offsets`: end offsets of f64 columns values.
+// - `f64_column_docs`: f64 columns values.
+// - `bytes_column_names`: bytes columns name.
+// - `bytes_column_offsets`: end offsets of bytes columns values.
+// - `bytes_column_docs`: bytes columns values.
+// - `date_column_names`: date columns name.
+// - `date_column_offsets`: end offsets of date columns values.
+// - `date_column_docs`: date columns values, RFC3339 format.
+// - `bool_column_names`: bool columns name.
+// - `bool_column_offsets`: end offsets of bool columns values.
+// - `bool_column_docs`: bool columns values, `true` or `false`.
+// - `u64_column_names`: u64 columns name.
+// - `u64_column_offsets`: end offsets of u64 columns values.
+// - `u64_column_docs`: u64 columns values.
+// - `ip_column_names`: ip columns name.
+// - `ip_column_offsets`: end offsets of ip columns values.
+// - `ip_column_docs`: ip columns values, IPv4 or IPv6 address strings.
+::BoolResult ffi_index_multi_valued_column_docs(::std::string const &index_path, ::std::uint64_t row_id, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::uint64_t> const &text_column_offsets, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::uint64_t> const &i64_column_offsets, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<::std::uint64_t> const &f64_column_offsets, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::uint64_t> const &bytes_column_offsets, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::uint64_t> const &date_column_offsets, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::uint64_t> const &bool_column_offsets, ::std::vector<::std::string> const &bool_column_docs, ::std::vector<::std::string> const &u64_column_names, ::std::vector<::std::uint64_t> const &u64_column_offsets, ::std::vector<::std::uint64_t> const &u64_column_docs, ::std::vector<::std::string> const &ip_column_names, ::std::vector<::std::uint64_t> const &ip_column_offsets, ::std::vector<::std::string> const &ip_column_docs) noexcept;
+
+// Index a batch of rows with multi type columns under a single writer lock.
+// Each `*_column_docs` is laid out column by column, its size must be
+// `*_column_names.size() * row_ids.size()`, the value of column `c` for row `r`
+// is located at `c * row_ids.size() + r`.
+// arguments:
+// - `index_path`: index directory.
+// - `row_ids`: a group of row_ids given by DingoDB.
+// - `text_column_names`: text columns name.
+// - `text_column_docs`: text columns value.
+// - `i64_column_names`: i64 columns name.
+// - `i64_column_docs`: i64 columns value.
+// - `f64_column_names`: f64 columns name.
+// - `f64_column_docs`: f64 columns value.
+// - `bytes_column_names`: bytes columns name.
+// - `bytes_column_docs`: bytes columns value.
+// - `date_column_names`: date columns name.
+// - `date_column_docs`: date columns value, RFC3339 format.
+// - `bool_column_names`: bool columns name.
+// - `bool_column_docs`: bool columns value, `true` or `false`.
+// - `u64_column_names`: u64 columns name.
+// - `u64_column_docs`: u64 columns value.
+// - `ip_column_names`: ip columns name.
+// - `ip_column_docs`: ip columns value, IPv4 or IPv6 address strings.
+::BatchIndexResult ffi_index_multi_type_column_docs_batch(::std::string const &index_path, ::std::vector<::std::uint64_t> const &row_ids, ::std::vector<::std::string> const &text_column_names, ::std::vector<::std::string> const &text_column_docs, ::std::vector<::std::string> const &i64_column_names, ::std::vector<::std::int64_t> const &i64_column_docs, ::std::vector<::std::string> const &f64_column_names, ::std::vector<double> const &f64_column_docs, ::std::vector<::std::string> const &bytes_column_names, ::std::vector<::std::string> const &bytes_column_docs, ::std::vector<::std::string> const &date_column_names, ::std::vector<::std::string> const &date_column_docs, ::std::vector<::std::string> const &bool_column_names, ::std::vector<::std::string> const &bool_column_docs, ::std::vector<::std::string> const &u64_column_names, ::std::vector<::std::uint64_t> const &u64_column_docs, ::std::vector<::std::string> const &ip_column_names, ::std::vector<::std::string> const &ip_column_docs) noexcept;
+
 // Delete a group of rowIds.
 // arguments:
 // - `index_path`: index directory.
 // - `row_ids`: a group of rowIds need be deleted.
 ::BoolResult ffi_delete_row_ids(::std::string const &index_path, ::std::vector<::std::uint64_t> const &row_ids) noexcept;
 
+// Queue delete terms of a group of rowIds without commit.
+// Deleted rows become invisible after next `ffi_index_writer_commit`.
+// Returns the opstamp assigned to the last delete operation.
+// arguments:
+// - `index_path`: index directory.
+// - `row_ids`: a group of rowIds need be deleted.
+::U64Result ffi_delete_row_ids_deferred(::std::string const &index_path, ::std::vector<::std::uint64_t> const &row_ids) noexcept;
+
+// Delete rows whose rowId is in `[start_row_id, end_row_id)`, then commit.
+// arguments:
+// - `index_path`: index directory.
+// - `start_row_id`: first rowId need be deleted.
+// - `end_row_id`: rowId after the last one need be deleted.
+::BoolResult ffi_delete_row_id_range(::std::string const &index_path, ::std::uint64_t start_row_id, ::std::uint64_t end_row_id) noexcept;
+
+// Delete rows matching a term of a column, then commit.
+// Text terms are tokenized, rows matching any token are deleted.
+// arguments:
+// - `index_path`: index directory.
+// - `column_name`: which column will be matched.
+// - `term`: term value, parsed with the column type.
+::BoolResult ffi_delete_by_term(::std::string const &index_path, ::std::string const &column_name, ::std::string const &term) noexcept;
+
+// Delete rows whose column value is in `[lower_bound, upper_bound]`, then commit.
+// arguments:
+// - `index_path`: index directory.
+// - `column_name`: numeric, datetime or ip column.
+// - `lower_bound`: lower bound value, empty means unbounded.
+// - `upper_bound`: upper bound value, empty means unbounded.
+::BoolResult ffi_delete_by_range(::std::string const &index_path, ::std::string const &column_name, ::std::string const &lower_bound, ::std::string const &upper_bound) noexcept;
+
+// Merge searchable segments down to `max_segments`, deleted docs will be purged.
+// It waits until merge finished and reloads the index reader.
+// Background merging is paused during the call, the merge policy is restored afterwards.
+// arguments:
+// - `index_path`: index directory.
+// - `max_segments`: target segments count, should be greater than 0.
+::ForceMergeResult ffi_force_merge(::std::string const &index_path, ::std::uint32_t max_segments) noexcept;
+
+// Rebuild index with a new json parameter from stored documents, row_ids are kept.
+// All columns should be stored, column types can't be changed. The new index is built
+// in a sibling directory, then swapped with the old one. Returns reindexed docs count.
+// arguments:
+// - `index_path`: index directory.
+// - `index_json_parameter`: new json parameter of the index.
+::U64Result ffi_reindex_with_parameter(::std::string const &index_path, ::std::string const &index_json_parameter) noexcept;
+
+// Get progress of the running or last finished reindex.
+// arguments:
+// - `index_path`: index directory.
+::ReindexProgressResult ffi_get_reindex_progress(::std::string const &index_path) noexcept;
+
+// Create a point-in-time snapshot of committed segments, segment files are hard linked
+// (or copied) into `snapshot_dir` together with meta.json and custom index setting.
+// Segment files are pinned until released. Returns the opstamp of the snapshot.
+// arguments:
+// - `index_path`: index directory.
+// - `snapshot_dir`: snapshot directory, can't exist before.
+::U64Result ffi_create_snapshot(::std::string const &index_path, ::std::string const &snapshot_dir) noexcept;
+
+// Unpin segment files of a snapshot, files in `snapshot_dir` are kept.
+// arguments:
+// - `snapshot_dir`: snapshot directory.
+::BoolResult ffi_release_snapshot(::std::string const &snapshot_dir) noexcept;
+
+// Install a snapshot as the index in `index_path`, existing index files are replaced once
+// the snapshot is installed.
+// arguments:
+// - `snapshot_dir`: snapshot directory.
+// - `index_path`: index directory.
+::BoolResult ffi_restore_snapshot(::std::string const &snapshot_dir, ::std::string const &index_path) noexcept;
+
+// List segment files added and removed since a follower's segment files, together with
+// meta.json of the latest commit. Listed files are pinned for the follower until its
+// next listing or `ffi_release_segment_changes`. Pins only hold while the leader index
+// writer loaded during listing is alive, list again after the writer is reloaded.
+// arguments:
+// - `index_path`: leader index directory.
+// - `follower_id`: identifies the follower, each follower has its own pins.
+// - `known_files`: segment files of the follower, list them with empty `known_files`.
+::SegmentChangesResult ffi_list_segment_changes(::std::string const &index_path, ::std::string const &follower_id, ::std::vector<::std::string> const &known_files) noexcept;
+
+// Unpin segment files listed for a follower by `ffi_list_segment_changes`.
+// arguments:
+// - `index_path`: leader index directory.
+// - `follower_id`: identifies the follower.
+::BoolResult ffi_release_segment_changes(::std::string const &index_path, ::std::string const &follower_id) noexcept;
+
+// Read bytes of a segment file listed by the last `ffi_list_segment_changes` of
+// a follower. The footer is excluded, `ffi_write_segment_file` appends it again.
+// arguments:
+// - `index_path`: leader index directory.
+// - `follower_id`: identifies the follower.
+// - `file_name`: segment file name.
+::BytesResult ffi_read_segment_file(::std::string const &index_path, ::std::string const &follower_id, ::std::string const &file_name) noexcept;
+
+// Write a segment file into a follower index, it's visible after changes applied.
+// arguments:
+// - `index_path`: follower index directory.
+// - `file_name`: segment file name.
+// - `content`: segment file bytes.
+::BoolResult ffi_write_segment_file(::std::string const &index_path, ::std::string const &file_name, ::std::vector<::std::uint8_t> const &content) noexcept;
+
+// Install meta.json of the leader into a follower index and remove stale segment files.
+// The follower can't have a loaded writer, its reader will be reopened if loaded.
+// arguments:
+// - `index_path`: follower index directory.
+// - `meta_json`: meta.json listed from the leader.
+// - `removed_files`: segment files removed from the leader.
+::BoolResult ffi_apply_segment_changes(::std::string const &index_path, ::std::string const &meta_json, ::std::vector<::std::string> const &removed_files) noexcept;
+
+// Attach segments of source indexes to the target index without re-ingesting.
+// Schemas and json parameters must be equal, source indexes are not changed.
+// Returns attached docs count.
+// arguments:
+// - `index_path`: target index directory.
+// - `source_paths`: source index directories.
+// - `row_id_conflict_policy`: `fail`, or `keep_target` to delete conflicting source docs.
+::U64Result ffi_merge_indexes(::std::string const &index_path, ::std::vector<::std::string> const &source_paths, ::std::string const &row_id_conflict_policy) noexcept;
+
+// Split an index by row_id, rows below `split_row_id` go to `left_path`, others go to
+// `right_path`. Both carry the json parameter of source, source index is not changed.
+// arguments:
+// - `index_path`: source index directory.
+// - `split_row_id`: the first row_id of right index.
+// - `left_path`: left index directory, existing files will be removed.
+// - `right_path`: right index directory, existing files will be removed.
+::BoolResult ffi_split_index(::std::string const &index_path, ::std::uint64_t split_row_id, ::std::string const &left_path, ::std::string const &right_path) noexcept;
+
+// Verify an index directory before serving from it, returns found problems.
+// Checksums of managed files, segment files listed in meta.json and
+// custom_index_setting.json are checked, `problems` is empty if index is intact.
+// arguments:
+// - `index_path`: index directory.
+::VerifyIndexResult ffi_verify_index(::std::string const &index_path) noexcept;
+
 // Commit index writer
 // arguments:
 // - `index_path`: index directory.
 ::BoolResult ffi_index_writer_commit(::std::string const &index_path) noexcept;
 
+// Commit index writer with a payload, such as raft log index.
+// The payload is recorded in meta.json and can be read by `ffi_get_commit_payload`.
+// arguments:
+// - `index_path`: index directory.
+// - `payload`: opaque string saved with this commit.
+::BoolResult ffi_index_writer_commit_with_payload(::std::string const &index_path, ::std::string const &payload) noexcept;
+
+// Rollback index writer, discard all operations after last commit.
+// arguments:
+// - `index_path`: index directory.
+::BoolResult ffi_index_writer_rollback(::std::string const &index_path) noexcept;
+
+// Prepare a two-phase commit with a payload, such as raft log index.
+// Pending documents are flushed, other write operations are rejected
+// until `ffi_index_writer_commit_prepared` or `ffi_index_writer_abort_prepared`.
+// arguments:
+// - `index_path`: index directory.
+// - `payload`: opaque string saved with the prepared commit.
+::BoolResult ffi_index_writer_prepare_commit_with_payload(::std::string const &index_path, ::std::string const &payload) noexcept;
+
+// Commit the prepared commit, its payload is recorded in meta.json.
+// arguments:
+// - `index_path`: index directory.
+::BoolResult ffi_index_writer_commit_prepared(::std::string const &index_path) noexcept;
+
+// Abort the prepared commit, discard all operations after last commit.
+// arguments:
+// - `index_path`: index directory.
+::BoolResult ffi_index_writer_abort_prepared(::std::string const &index_path) noexcept;
+
+// Get the payload of last commit, empty string means no payload.
+// arguments:
+// - `index_path`: index directory.
+::StringResult ffi_get_commit_payload(::std::string const &index_path) noexcept;
+
 // Free index writer
 // arguments:
 // - `index_path`: index directory.
@@ -946,6 +1430,15 @@ struct Statistics final {
 // - `pattern`: pattern should be given by ClickHouse.
 ::rust::Vec<::std::uint8_t> ffi_regex_term_bitmap(::std::string const &index_path, ::std::string const &column_name, ::std::string const &pattern) noexcept;
 
+// Execute a range query on u64, i64, f64, bool, datetime or ip column,
+// and return rowIds u8 bitmap. Both bounds are inclusive.
+// arguments:
+// - `index_path`: index directory.
+// - `column_name`: which column will execute search.
+// - `lower_bound`: parsed with column type, empty string means unbounded.
+// - `upper_bound`: parsed with column type, empty string means unbounded.
+::rust::Vec<::std::uint8_t> ffi_range_query_bitmap(::std::string const &index_path, ::std::string const &column_name, ::std::string const &lower_bound, ::std::string const &upper_bound) noexcept;
+
 // Execute a regex query and return rowIds u8 bitmap.
 // arguments:
 // - `index_path`: index directory.
@@ -1004,6 +1497,12 @@ struct Statistics final {
 // - `index_path`: index directory.
 ::StringResult ffi_get_index_meta_json(::std::string const &index_path) noexcept;
 
+// Get statistics of the loaded index reader, including on-disk size of each segment
+// broken down by component, and space usage of each field over all segments.
+// arguments:
+// - `index_path`: index directory.
+::IndexStatisticsResult ffi_get_index_statistics(::std::string const &index_path) noexcept;
+
 // Get index json parameter
 // arguments:
 // - `index_path`: index directory.
@@ -15,9 +15,13 @@ use log4rs::Config;
 use once_cell::sync::Lazy;
 use once_cell::sync::OnceCell;
 use roaring::RoaringBitmap;
+use std::collections::HashMap;
+use std::net::Ipv6Addr;
 use std::sync::Arc;
 use std::sync::Mutex;
 use tantivy::DateTime;
+use tantivy::IndexMeta;
+use tantivy::SegmentId;
 
 use super::converter::Converter;
 use super::converter::CxxElementStrategy;
@@ -26,6 +30,7 @@ use super::converter::CxxVectorStringStrategy;
 use super::converter::CxxVectorStringToBoolStrategy;
 use super::converter::CxxVectorStringToBytesStrategy;
 use super::converter::CxxVectorStringToDateTimeStrategy;
+use super::converter::CxxVectorStringToIpStrategy;
 
 // Cache queries results.
 // The cache's key is composed of reader.address, query_str, index_directory, use_regex.
@@ -34,9 +39,20 @@ pub static CACHE_FOR_SKIP_INDEX: Lazy<
     FlurryCache<(usize, String, String, bool), Arc<RoaringBitmap>>,
 > = Lazy::new(|| FlurryCache::with_capacity(1000));
 
+// Cache min and max row_id of each segment, `None` if unknown.
+// Segments are immutable, so the cache's key is segment id.
+pub static SEGMENT_ROW_ID_RANGE_CACHE: Lazy<FlurryCache<SegmentId, Option<(u64, u64)>>> =
+    Lazy::new(|| FlurryCache::with_capacity(10000));
+
 // Custom index settings file name.
 pub static INDEX_INFO_FILE_NAME: &str = "custom_index_setting.json";
 
+// Tantivy index meta file name, it exists after index created.
+pub static META_FILE_NAME: &str = "meta.json";
+
+// Attempts of `force_merge` to plan segment groups again after background merges consumed them.
+pub static FORCE_MERGE_MAX_ATTEMPTS: usize = 3;
+
 // Log callback function type.
 pub type LogCallback = extern "C" fn(i32, *const c_char, *const c_char);
 
@@ -57,6 +73,20 @@ pub static FFI_INDEX_WRITER_CACHE: Lazy<IndexWriterBridgeCache> =
 pub static FFI_INDEX_SEARCHER_CACHE: Lazy<IndexReaderBridgeCache> =
     Lazy::new(|| IndexReaderBridgeCache::new());
 
+// Reindex progress of each index, (reindexed docs, total docs, finished).
+pub static REINDEX_PROGRESS: Lazy<Mutex<HashMap<String, (u64, u64, bool)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// Index metas pinned by snapshots, keyed by snapshot directory.
+pub static SNAPSHOT_PINS: Lazy<Mutex<HashMap<String, IndexMeta>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+// Index metas pinned by the last listing of segment changes of each follower,
+// keyed by (index directory, follower id). A pinned meta only protects its segment files
+// while the writer index it's loaded from is alive, see `list_segment_changes`.
+pub static REPLICATION_PINS: Lazy<Mutex<HashMap<(String, String), IndexMeta>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
 /// Convert 'CxxString' to 'String'
 pub static CXX_STRING_CONERTER: Lazy<Converter<CxxString, String, CxxElementStrategy>> =
     Lazy::new(|| Converter::new(CxxElementStrategy));
@@ -81,6 +111,11 @@ pub static CXX_VECTOR_STRING_TO_BOOL_CONERTER: Lazy<
     Converter<CxxVector<CxxString>, Vec<bool>, CxxVectorStringToBoolStrategy>,
 > = Lazy::new(|| Converter::new(CxxVectorStringToBoolStrategy));
 
+/// Convert 'CxxVector<CxxString>' to 'Vec<Ipv6Addr>'
+pub static CXX_VECTOR_STRING_TO_IP_CONERTER: Lazy<
+    Converter<CxxVector<CxxString>, Vec<Ipv6Addr>, CxxVectorStringToIpStrategy>,
+> = Lazy::new(|| Converter::new(CxxVectorStringToIpStrategy));
+
 /// Convert 'CxxVector<T> to Vec<T>'
 pub fn cxx_vector_converter<T>() -> Converter<CxxVector<T>, Vec<T>, CxxVectorStrategy<T>>
 where
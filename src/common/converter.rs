@@ -1,3 +1,4 @@
+use std::net::{IpAddr, Ipv6Addr};
 use std::str::Utf8Error;
 
 use cxx::{vector::VectorElement, CxxString, CxxVector};
@@ -97,14 +98,7 @@ impl ConvertStrategy<CxxVector<CxxString>, Vec<DateTime>> for CxxVectorStringToD
                     Ok(str) => str,
                     Err(e) => return Err(CxxConvertError::CxxVectorConvertError(e.to_string())),
                 };
-                match OffsetDateTime::parse(str, &Rfc3339) {
-                    Ok(t) => {
-                        return Ok(DateTime::from_utc(t));
-                    }
-                    Err(e) => {
-                        return Err(CxxConvertError::CxxDateVectorConvertError(e.to_string()));
-                    }
-                }
+                parse_datetime(str)
             })
             .collect()
     }
@@ -121,19 +115,52 @@ impl ConvertStrategy<CxxVector<CxxString>, Vec<bool>> for CxxVectorStringToBoolS
                     Ok(str) => str,
                     Err(e) => return Err(CxxConvertError::CxxVectorConvertError(e.to_string())),
                 };
-                match str.parse::<bool>() {
-                    Ok(t) => {
-                        return Ok(t);
-                    }
-                    Err(e) => {
-                        return Err(CxxConvertError::CxxBoolVectorConvertError(e.to_string()));
-                    }
-                }
+                parse_bool(str)
             })
             .collect()
     }
 }
 
+pub struct CxxVectorStringToIpStrategy;
+
+impl ConvertStrategy<CxxVector<CxxString>, Vec<Ipv6Addr>> for CxxVectorStringToIpStrategy {
+    fn convert(&self, items: &CxxVector<CxxString>) -> Result<Vec<Ipv6Addr>, CxxConvertError> {
+        items
+            .iter()
+            .map(|item| {
+                let str = match item.to_str() {
+                    Ok(str) => str,
+                    Err(e) => return Err(CxxConvertError::CxxVectorConvertError(e.to_string())),
+                };
+                parse_ip(str)
+            })
+            .collect()
+    }
+}
+
+// Parse a RFC3339 datetime string, such as `2024-05-20T12:00:00Z`.
+pub fn parse_datetime(str: &str) -> Result<DateTime, CxxConvertError> {
+    match OffsetDateTime::parse(str, &Rfc3339) {
+        Ok(t) => Ok(DateTime::from_utc(t)),
+        Err(e) => Err(CxxConvertError::CxxDateVectorConvertError(e.to_string())),
+    }
+}
+
+// Parse a boolean string, only `true` and `false` are accepted.
+pub fn parse_bool(str: &str) -> Result<bool, CxxConvertError> {
+    str.parse::<bool>()
+        .map_err(|e| CxxConvertError::CxxBoolVectorConvertError(e.to_string()))
+}
+
+// Parse an IPv4 or IPv6 address string, IPv4 is stored as IPv4-mapped IPv6 address.
+pub fn parse_ip(str: &str) -> Result<Ipv6Addr, CxxConvertError> {
+    match str.parse::<IpAddr>() {
+        Ok(IpAddr::V4(ip)) => Ok(ip.to_ipv6_mapped()),
+        Ok(IpAddr::V6(ip)) => Ok(ip),
+        Err(e) => Err(CxxConvertError::CxxIpVectorConvertError(e.to_string())),
+    }
+}
+
 pub struct Converter<T, U, S>
 where
     S: ConvertStrategy<T, U>,
@@ -80,6 +80,8 @@ pub enum CxxConvertError {
     CxxDateVectorConvertError(String),
     #[error("Failed to convert cxx boolean variable. '{0}'")]
     CxxBoolVectorConvertError(String),
+    #[error("Failed to convert cxx ip address variable. '{0}'")]
+    CxxIpVectorConvertError(String),
 }
 
 /// The library's error enum
@@ -111,4 +113,7 @@ pub enum TantivySearchError {
 
     #[error("An invalid argument was passed: '{0}'")]
     InvalidArgument(String),
+
+    #[error("Existing index is incompatible: '{0}'")]
+    IncompatibleIndex(String),
 }
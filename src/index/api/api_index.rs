@@ -1,13 +1,17 @@
 use crate::index::implements::api_index_impl::*;
 use crate::logger::logger_bridge::TantivySearchLogger;
-use crate::BoolResult;
 use crate::{common::constants::LOG_CALLBACK, ERROR};
 use crate::{
     cxx_vector_converter, CXX_STRING_CONERTER, CXX_VECTOR_STRING_CONERTER,
     CXX_VECTOR_STRING_TO_BOOL_CONERTER, CXX_VECTOR_STRING_TO_BYTES_CONERTER,
-    CXX_VECTOR_STRING_TO_DATE_CONERTER,
+    CXX_VECTOR_STRING_TO_DATE_CONERTER, CXX_VECTOR_STRING_TO_IP_CONERTER,
+};
+use crate::{
+    BatchIndexResult, BoolResult, BytesResult, ForceMergeResult, ReindexProgressResult,
+    SegmentChangesResult, StringResult, U64Result, VerifyIndexResult,
 };
 use cxx::{CxxString, CxxVector};
+use std::net::Ipv6Addr;
 
 use tantivy::DateTime;
 
@@ -74,11 +78,15 @@ pub fn ffi_create_index_with_parameter(
     }
 }
 
-pub fn ffi_create_index(index_path: &CxxString, column_names: &CxxVector<CxxString>) -> BoolResult {
+pub fn ffi_add_columns_to_index(
+    index_path: &CxxString,
+    column_names: &CxxVector<CxxString>,
+    index_json_parameter: &CxxString,
+) -> BoolResult {
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_create_index", "Can't convert 'index_path', message: {}", e);
+            ERROR!(function: "ffi_add_columns_to_index", "Can't convert 'index_path', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
@@ -91,7 +99,7 @@ pub fn ffi_create_index(index_path: &CxxString, column_names: &CxxVector<CxxStri
     let column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_names) {
         Ok(names) => names,
         Err(e) => {
-            ERROR!(function: "ffi_create_index", "Can't convert 'column_names', message: {}", e);
+            ERROR!(function: "ffi_add_columns_to_index", "Can't convert 'column_names', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'column_names', message: {}", e);
             return BoolResult {
                 result: false,
@@ -101,15 +109,29 @@ pub fn ffi_create_index(index_path: &CxxString, column_names: &CxxVector<CxxStri
         }
     };
 
-    match create_index(&index_path, &column_names) {
+    let index_json_parameter: String = match CXX_STRING_CONERTER.convert(index_json_parameter) {
+        Ok(json) => json,
+        Err(e) => {
+            ERROR!(function: "ffi_add_columns_to_index", "Can't convert 'index_json_parameter', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'index_json_parameter', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match add_columns_to_index(&index_path, &column_names, &index_json_parameter) {
         Ok(status) => BoolResult {
             result: status,
             error_code: 0,
             error_msg: String::new(),
         },
         Err(e) => {
-            ERROR!(function: "ffi_create_index", "Error creating index: {}", e);
-            let error_msg_for_cxx: String = format!("Error creating index: {}", e);
+            ERROR!(function: "ffi_add_columns_to_index", "Error adding columns: {}", e);
+            let error_msg_for_cxx: String = format!("Error adding columns: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -119,17 +141,16 @@ pub fn ffi_create_index(index_path: &CxxString, column_names: &CxxVector<CxxStri
     }
 }
 
-pub fn ffi_index_multi_column_docs(
-    index_path: &CxxString,
-    row_id: u64,
+pub fn ffi_create_ram_index_with_parameter(
+    index_name: &CxxString,
     column_names: &CxxVector<CxxString>,
-    column_docs: &CxxVector<CxxString>,
+    index_json_parameter: &CxxString,
 ) -> BoolResult {
-    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
-        Ok(path) => path,
+    let index_name: String = match CXX_STRING_CONERTER.convert(index_name) {
+        Ok(name) => name,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'index_path', message: {}", e);
-            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            ERROR!(function: "ffi_create_ram_index_with_parameter", "Can't convert 'index_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_name', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -141,7 +162,7 @@ pub fn ffi_index_multi_column_docs(
     let column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_names) {
         Ok(names) => names,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'column_names', message: {}", e);
+            ERROR!(function: "ffi_create_ram_index_with_parameter", "Can't convert 'column_names', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'column_names', message: {}", e);
             return BoolResult {
                 result: false,
@@ -151,11 +172,11 @@ pub fn ffi_index_multi_column_docs(
         }
     };
 
-    let column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_docs) {
-        Ok(docs) => docs,
+    let index_json_parameter: String = match CXX_STRING_CONERTER.convert(index_json_parameter) {
+        Ok(json) => json,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'column_docs', message: {}", e);
-            let error_msg_for_cxx: String = format!("Can't convert 'column_docs', message: {}", e);
+            ERROR!(function: "ffi_create_ram_index_with_parameter", "Can't convert 'index_json_parameter', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_json_parameter', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -164,36 +185,15 @@ pub fn ffi_index_multi_column_docs(
         }
     };
 
-    if column_names.len() != column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "column_names size doesn't match column_docs size");
-        let error_msg_for_cxx: String =
-            "column_names size doesn't match column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
-    }
-
-    if column_names.len() == 0 || column_docs.len() == 0 {
-        ERROR!(function: "ffi_index_multi_column_docs", "column_names and column_docs can't be empty");
-        let error_msg_for_cxx: String = "column_names and column_docs can't be empty".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
-    }
-
-    match index_multi_column_docs(&index_path, row_id, &column_names, &column_docs) {
-        Ok(status) => BoolResult {
-            result: status,
+    match create_ram_index_with_parameter(&index_name, &column_names, &index_json_parameter) {
+        Ok(result) => BoolResult {
+            result,
             error_code: 0,
             error_msg: String::new(),
         },
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Error indexing multi-column docs: {}", e);
-            let error_msg_for_cxx: String = format!("Error indexing multi-column docs: {}", e);
+            ERROR!(function: "ffi_create_ram_index_with_parameter", "Error creating in-memory index: {}", e);
+            let error_msg_for_cxx: String = format!("Error creating in-memory index: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -203,26 +203,24 @@ pub fn ffi_index_multi_column_docs(
     }
 }
 
-pub fn ffi_index_multi_type_column_docs(
-    index_path: &CxxString,
-    row_id: u64,
-    text_column_names: &CxxVector<CxxString>,
-    text_column_docs: &CxxVector<CxxString>,
-    i64_column_names: &CxxVector<CxxString>,
-    i64_column_docs: &CxxVector<i64>,
-    f64_column_names: &CxxVector<CxxString>,
-    f64_column_docs: &CxxVector<f64>,
-    bytes_column_names: &CxxVector<CxxString>,
-    bytes_column_docs: &CxxVector<CxxString>,
-    date_column_names: &CxxVector<CxxString>,
-    date_column_docs: &CxxVector<CxxString>,
-    bool_column_names: &CxxVector<CxxString>,
-    bool_column_docs: &CxxVector<CxxString>,
-) -> BoolResult {
+pub fn ffi_persist_ram_index(index_name: &CxxString, index_path: &CxxString) -> BoolResult {
+    let index_name: String = match CXX_STRING_CONERTER.convert(index_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_persist_ram_index", "Can't convert 'index_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_name', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'index_path', message: {}", e);
+            ERROR!(function: "ffi_persist_ram_index", "Can't convert 'index_path', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
@@ -232,27 +230,35 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    let text_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_names)
-    {
-        Ok(names) => names,
+    match persist_ram_index(&index_name, &index_path) {
+        Ok(result) => BoolResult {
+            result,
+            error_code: 0,
+            error_msg: String::new(),
+        },
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'text_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'text_column_names', message: {}", e);
+            ERROR!(function: "ffi_persist_ram_index", "Error persisting in-memory index: {}", e);
+            let error_msg_for_cxx: String = format!("Error persisting in-memory index: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
                 error_msg: error_msg_for_cxx,
             };
         }
-    };
+    }
+}
 
-    let text_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_docs) {
-        Ok(docs) => docs,
+pub fn ffi_open_or_create_index_with_parameter(
+    index_path: &CxxString,
+    column_names: &CxxVector<CxxString>,
+    index_json_parameter: &CxxString,
+    wipe_existing: bool,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'text_column_docs', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'text_column_docs', message: {}", e);
+            ERROR!(function: "ffi_open_or_create_index_with_parameter", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -261,23 +267,11 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if text_column_names.len() != text_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "text_column_names size doesn't match text_column_docs size");
-        let error_msg_for_cxx: String =
-            "text_column_names size doesn't match text_column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
-    }
-
-    let i64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(i64_column_names) {
+    let column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_names) {
         Ok(names) => names,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'i64_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'i64_column_names', message: {}", e);
+            ERROR!(function: "ffi_open_or_create_index_with_parameter", "Can't convert 'column_names', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_names', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -286,13 +280,12 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    // convert CxxVector<i64> to Vec<i64>
-    let i64_column_docs = match cxx_vector_converter::<i64>().convert(i64_column_docs) {
-        Ok(docs) => docs,
+    let index_json_parameter: String = match CXX_STRING_CONERTER.convert(index_json_parameter) {
+        Ok(json) => json,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'i64_column_docs', message: {}", e);
+            ERROR!(function: "ffi_open_or_create_index_with_parameter", "Can't convert 'index_json_parameter', message: {}", e);
             let error_msg_for_cxx: String =
-                format!("Can't convert 'i64_column_docs', message: {}", e);
+                format!("Can't convert 'index_json_parameter', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -301,23 +294,35 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if i64_column_names.len() != i64_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "i64_column_names size doesn't match i64_column_docs size");
-        let error_msg_for_cxx: String =
-            "i64_column_names size doesn't match i64_column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
+    match open_or_create_index_with_parameter(
+        &index_path,
+        &column_names,
+        &index_json_parameter,
+        wipe_existing,
+    ) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_open_or_create_index_with_parameter", "Error opening or creating index: {}", e);
+            let error_msg_for_cxx: String = format!("Error opening or creating index: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
     }
+}
 
-    let f64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(f64_column_names) {
-        Ok(names) => names,
+pub fn ffi_create_index(index_path: &CxxString, column_names: &CxxVector<CxxString>) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'f64_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'f64_column_names', message: {}", e);
+            ERROR!(function: "ffi_create_index", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -326,13 +331,11 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    // convert CxxVector<f64> to Vec<f64>
-    let f64_column_docs = match cxx_vector_converter::<f64>().convert(f64_column_docs) {
-        Ok(docs) => docs,
+    let column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_names) {
+        Ok(names) => names,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'f64_column_docs', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'f64_column_docs', message: {}", e);
+            ERROR!(function: "ffi_create_index", "Can't convert 'column_names', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_names', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -341,41 +344,35 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if f64_column_names.len() != f64_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "f64_column_names size doesn't match f64_column_docs size");
-        let error_msg_for_cxx: String =
-            "f64_column_names size doesn't match f64_column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
-    }
-
-    let bytes_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER
-        .convert(bytes_column_names)
-    {
-        Ok(names) => names,
+    match create_index(&index_path, &column_names) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'bytes_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'bytes_column_names', message: {}", e);
+            ERROR!(function: "ffi_create_index", "Error creating index: {}", e);
+            let error_msg_for_cxx: String = format!("Error creating index: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
                 error_msg: error_msg_for_cxx,
             };
         }
-    };
+    }
+}
 
-    let bytes_column_docs: Vec<Vec<u8>> = match CXX_VECTOR_STRING_TO_BYTES_CONERTER
-        .convert(bytes_column_docs)
-    {
-        Ok(docs) => docs,
+pub fn ffi_index_multi_column_docs(
+    index_path: &CxxString,
+    row_id: u64,
+    column_names: &CxxVector<CxxString>,
+    column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'bytes_column_docs', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'bytes_column_docs', message: {}", e);
+            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -384,24 +381,11 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if bytes_column_names.len() != bytes_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "bytes_column_names size doesn't match bytes_column_docs size");
-        let error_msg_for_cxx: String =
-            "bytes_column_names size doesn't match bytes_column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
-    }
-
-    let date_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(date_column_names)
-    {
+    let column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_names) {
         Ok(names) => names,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'date_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'date_column_names', message: {}", e);
+            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'column_names', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_names', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -410,14 +394,11 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    let date_column_docs: Vec<DateTime> = match CXX_VECTOR_STRING_TO_DATE_CONERTER
-        .convert(date_column_docs)
-    {
+    let column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(column_docs) {
         Ok(docs) => docs,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'date_column_docs', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'date_column_docs', message: {}", e);
+            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'column_docs', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_docs', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -426,10 +407,10 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if date_column_names.len() != date_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "date_column_names size doesn't match date_column_docs size");
+    if column_names.len() != column_docs.len() {
+        ERROR!(function: "ffi_index_multi_column_docs", "column_names size doesn't match column_docs size");
         let error_msg_for_cxx: String =
-            "date_column_names size doesn't match date_column_docs size".to_string();
+            "column_names size doesn't match column_docs size".to_string();
         return BoolResult {
             result: false,
             error_code: -1,
@@ -437,13 +418,2373 @@ pub fn ffi_index_multi_type_column_docs(
         };
     }
 
-    let bool_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(bool_column_names)
-    {
-        Ok(names) => names,
-        Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'bool_column_names', message: {}", e);
-            let error_msg_for_cxx: String =
+    if column_names.len() == 0 || column_docs.len() == 0 {
+        ERROR!(function: "ffi_index_multi_column_docs", "column_names and column_docs can't be empty");
+        let error_msg_for_cxx: String = "column_names and column_docs can't be empty".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    match index_multi_column_docs(&index_path, row_id, &column_names, &column_docs) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_column_docs", "Error indexing multi-column docs: {}", e);
+            let error_msg_for_cxx: String = format!("Error indexing multi-column docs: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_multi_type_column_docs(
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    handle_multi_type_column_docs(
+        "ffi_index_multi_type_column_docs",
+        false,
+        index_path,
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+}
+
+pub fn ffi_index_multi_type_column_docs_with_u64_and_ip(
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: &CxxVector<CxxString>,
+    u64_column_docs: &CxxVector<u64>,
+    ip_column_names: &CxxVector<CxxString>,
+    ip_column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    handle_multi_type_column_docs(
+        "ffi_index_multi_type_column_docs_with_u64_and_ip",
+        false,
+        index_path,
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        Some(u64_column_names),
+        Some(u64_column_docs),
+        Some(ip_column_names),
+        Some(ip_column_docs),
+        None,
+        None,
+    )
+}
+
+pub fn ffi_upsert_multi_type_column_docs(
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: &CxxVector<CxxString>,
+    u64_column_docs: &CxxVector<u64>,
+    ip_column_names: &CxxVector<CxxString>,
+    ip_column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    handle_multi_type_column_docs(
+        "ffi_upsert_multi_type_column_docs",
+        true,
+        index_path,
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        Some(u64_column_names),
+        Some(u64_column_docs),
+        Some(ip_column_names),
+        Some(ip_column_docs),
+        None,
+        None,
+    )
+}
+
+pub fn ffi_index_multi_type_column_docs_with_json(
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: &CxxVector<CxxString>,
+    u64_column_docs: &CxxVector<u64>,
+    ip_column_names: &CxxVector<CxxString>,
+    ip_column_docs: &CxxVector<CxxString>,
+    json_column_names: &CxxVector<CxxString>,
+    json_column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    handle_multi_type_column_docs(
+        "ffi_index_multi_type_column_docs_with_json",
+        false,
+        index_path,
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        Some(u64_column_names),
+        Some(u64_column_docs),
+        Some(ip_column_names),
+        Some(ip_column_docs),
+        Some(json_column_names),
+        Some(json_column_docs),
+    )
+}
+
+// Convert cxx arguments, then index or upsert a document with multi type columns.
+fn handle_multi_type_column_docs(
+    function_name: &str,
+    upsert: bool,
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: Option<&CxxVector<CxxString>>,
+    u64_column_docs: Option<&CxxVector<u64>>,
+    ip_column_names: Option<&CxxVector<CxxString>>,
+    ip_column_docs: Option<&CxxVector<CxxString>>,
+    json_column_names: Option<&CxxVector<CxxString>>,
+    json_column_docs: Option<&CxxVector<CxxString>>,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'text_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'text_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if text_column_names.len() != text_column_docs.len() {
+        ERROR!(function: function_name, "text_column_names size doesn't match text_column_docs size");
+        let error_msg_for_cxx: String =
+            "text_column_names size doesn't match text_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let i64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(i64_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'i64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    // convert CxxVector<i64> to Vec<i64>
+    let i64_column_docs = match cxx_vector_converter::<i64>().convert(i64_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'i64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if i64_column_names.len() != i64_column_docs.len() {
+        ERROR!(function: function_name, "i64_column_names size doesn't match i64_column_docs size");
+        let error_msg_for_cxx: String =
+            "i64_column_names size doesn't match i64_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let f64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(f64_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'f64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    // convert CxxVector<f64> to Vec<f64>
+    let f64_column_docs = match cxx_vector_converter::<f64>().convert(f64_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'f64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if f64_column_names.len() != f64_column_docs.len() {
+        ERROR!(function: function_name, "f64_column_names size doesn't match f64_column_docs size");
+        let error_msg_for_cxx: String =
+            "f64_column_names size doesn't match f64_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let bytes_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER
+        .convert(bytes_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'bytes_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_docs: Vec<Vec<u8>> = match CXX_VECTOR_STRING_TO_BYTES_CONERTER
+        .convert(bytes_column_docs)
+    {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'bytes_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if bytes_column_names.len() != bytes_column_docs.len() {
+        ERROR!(function: function_name, "bytes_column_names size doesn't match bytes_column_docs size");
+        let error_msg_for_cxx: String =
+            "bytes_column_names size doesn't match bytes_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let date_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(date_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'date_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_docs: Vec<DateTime> =
+        match CXX_VECTOR_STRING_TO_DATE_CONERTER.convert(date_column_docs) {
+            Ok(docs) => docs,
+            Err(e) => {
+                ERROR!(function: function_name, "Can't convert 'date_column_docs', message: {}", e);
+                let error_msg_for_cxx: String =
+                    format!("Can't convert 'date_column_docs', message: {}", e);
+                return BoolResult {
+                    result: false,
+                    error_code: -1,
+                    error_msg: error_msg_for_cxx,
+                };
+            }
+        };
+
+    if date_column_names.len() != date_column_docs.len() {
+        ERROR!(function: function_name, "date_column_names size doesn't match date_column_docs size");
+        let error_msg_for_cxx: String =
+            "date_column_names size doesn't match date_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let bool_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(bool_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'bool_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bool_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_docs: Vec<bool> =
+        match CXX_VECTOR_STRING_TO_BOOL_CONERTER.convert(bool_column_docs) {
+            Ok(docs) => docs,
+            Err(e) => {
+                ERROR!(function: function_name, "Can't convert 'bool_column_docs', message: {}", e);
+                let error_msg_for_cxx: String =
+                    format!("Can't convert 'bool_column_docs', message: {}", e);
+                return BoolResult {
+                    result: false,
+                    error_code: -1,
+                    error_msg: error_msg_for_cxx,
+                };
+            }
+        };
+
+    if bool_column_names.len() != bool_column_docs.len() {
+        ERROR!(function: function_name, "bool_column_names size doesn't match bool_column_docs size");
+        let error_msg_for_cxx: String =
+            "bool_column_names size doesn't match bool_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let u64_column_names: Vec<String> = match u64_column_names
+        .map(|names| CXX_VECTOR_STRING_CONERTER.convert(names))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'u64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    // convert CxxVector<u64> to Vec<u64>
+    let u64_column_docs: Vec<u64> = match u64_column_docs
+        .map(|docs| cxx_vector_converter::<u64>().convert(docs))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'u64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if u64_column_names.len() != u64_column_docs.len() {
+        ERROR!(function: function_name, "u64_column_names size doesn't match u64_column_docs size");
+        let error_msg_for_cxx: String =
+            "u64_column_names size doesn't match u64_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let ip_column_names: Vec<String> = match ip_column_names
+        .map(|names| CXX_VECTOR_STRING_CONERTER.convert(names))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'ip_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_docs: Vec<Ipv6Addr> = match ip_column_docs
+        .map(|docs| CXX_VECTOR_STRING_TO_IP_CONERTER.convert(docs))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'ip_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if ip_column_names.len() != ip_column_docs.len() {
+        ERROR!(function: function_name, "ip_column_names size doesn't match ip_column_docs size");
+        let error_msg_for_cxx: String =
+            "ip_column_names size doesn't match ip_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let json_column_names: Vec<String> = match json_column_names
+        .map(|names| CXX_VECTOR_STRING_CONERTER.convert(names))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'json_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'json_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let json_column_docs: Vec<String> = match json_column_docs
+        .map(|docs| CXX_VECTOR_STRING_CONERTER.convert(docs))
+        .unwrap_or(Ok(Vec::new()))
+    {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: function_name, "Can't convert 'json_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'json_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if json_column_names.len() != json_column_docs.len() {
+        ERROR!(function: function_name, "json_column_names size doesn't match json_column_docs size");
+        let error_msg_for_cxx: String =
+            "json_column_names size doesn't match json_column_docs size".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    if (text_column_names.len()
+        + i64_column_names.len()
+        + f64_column_names.len()
+        + bytes_column_names.len()
+        + date_column_docs.len()
+        + bool_column_docs.len()
+        + u64_column_names.len()
+        + ip_column_names.len()
+        + json_column_names.len())
+        == 0
+        || (text_column_docs.len()
+            + i64_column_docs.len()
+            + f64_column_docs.len()
+            + bytes_column_docs.len()
+            + date_column_docs.len()
+            + bool_column_docs.len()
+            + u64_column_docs.len()
+            + ip_column_docs.len()
+            + json_column_docs.len())
+            == 0
+    {
+        ERROR!(function: function_name, "column_names and column_docs can't be empty");
+        let error_msg_for_cxx: String = "column_names and column_docs can't be empty".to_string();
+        return BoolResult {
+            result: false,
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    let result = if upsert {
+        upsert_multi_type_column_docs(
+            &index_path,
+            row_id,
+            &text_column_names,
+            &text_column_docs,
+            &i64_column_names,
+            &i64_column_docs,
+            &f64_column_names,
+            &f64_column_docs,
+            &bytes_column_names,
+            &bytes_column_docs,
+            &date_column_names,
+            &date_column_docs,
+            &bool_column_names,
+            &bool_column_docs,
+            &u64_column_names,
+            &u64_column_docs,
+            &ip_column_names,
+            &ip_column_docs,
+        )
+    } else {
+        index_multi_type_column_docs(
+            &index_path,
+            row_id,
+            &text_column_names,
+            &text_column_docs,
+            &i64_column_names,
+            &i64_column_docs,
+            &f64_column_names,
+            &f64_column_docs,
+            &bytes_column_names,
+            &bytes_column_docs,
+            &date_column_names,
+            &date_column_docs,
+            &bool_column_names,
+            &bool_column_docs,
+            &u64_column_names,
+            &u64_column_docs,
+            &ip_column_names,
+            &ip_column_docs,
+            &json_column_names,
+            &json_column_docs,
+        )
+    };
+    match result {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: function_name, "Error handling multi-column docs: {}", e);
+            let error_msg_for_cxx: String = format!("Error handling multi-column docs: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_multi_valued_column_docs(
+    index_path: &CxxString,
+    row_id: u64,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_offsets: &CxxVector<u64>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_offsets: &CxxVector<u64>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_offsets: &CxxVector<u64>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_offsets: &CxxVector<u64>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_offsets: &CxxVector<u64>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_offsets: &CxxVector<u64>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: &CxxVector<CxxString>,
+    u64_column_offsets: &CxxVector<u64>,
+    u64_column_docs: &CxxVector<u64>,
+    ip_column_names: &CxxVector<CxxString>,
+    ip_column_offsets: &CxxVector<u64>,
+    ip_column_docs: &CxxVector<CxxString>,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_names)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'text_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(text_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'text_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_docs) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'text_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let i64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(i64_column_names) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'i64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let i64_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(i64_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'i64_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let i64_column_docs: Vec<i64> = match cxx_vector_converter::<i64>().convert(i64_column_docs) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'i64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let f64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(f64_column_names) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'f64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let f64_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(f64_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'f64_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let f64_column_docs: Vec<f64> = match cxx_vector_converter::<f64>().convert(f64_column_docs) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'f64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER
+        .convert(bytes_column_names)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bytes_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(bytes_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bytes_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_docs: Vec<Vec<u8>> = match CXX_VECTOR_STRING_TO_BYTES_CONERTER
+        .convert(bytes_column_docs)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bytes_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(date_column_names)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'date_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(date_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'date_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_docs: Vec<DateTime> = match CXX_VECTOR_STRING_TO_DATE_CONERTER
+        .convert(date_column_docs)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'date_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(bool_column_names)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bool_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bool_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(bool_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bool_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bool_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_docs: Vec<bool> = match CXX_VECTOR_STRING_TO_BOOL_CONERTER
+        .convert(bool_column_docs)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'bool_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bool_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let u64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(u64_column_names) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'u64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let u64_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(u64_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'u64_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let u64_column_docs: Vec<u64> = match cxx_vector_converter::<u64>().convert(u64_column_docs) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'u64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(ip_column_names) {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'ip_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_names', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_offsets: Vec<u64> = match cxx_vector_converter::<u64>()
+        .convert(ip_column_offsets)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'ip_column_offsets', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_offsets', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_docs: Vec<Ipv6Addr> = match CXX_VECTOR_STRING_TO_IP_CONERTER
+        .convert(ip_column_docs)
+    {
+        Ok(value) => value,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Can't convert 'ip_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_docs', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match index_multi_valued_column_docs(
+        &index_path,
+        row_id,
+        &text_column_names,
+        &text_column_offsets,
+        &text_column_docs,
+        &i64_column_names,
+        &i64_column_offsets,
+        &i64_column_docs,
+        &f64_column_names,
+        &f64_column_offsets,
+        &f64_column_docs,
+        &bytes_column_names,
+        &bytes_column_offsets,
+        &bytes_column_docs,
+        &date_column_names,
+        &date_column_offsets,
+        &date_column_docs,
+        &bool_column_names,
+        &bool_column_offsets,
+        &bool_column_docs,
+        &u64_column_names,
+        &u64_column_offsets,
+        &u64_column_docs,
+        &ip_column_names,
+        &ip_column_offsets,
+        &ip_column_docs,
+    ) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_valued_column_docs", "Error indexing multi-valued column docs: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Error indexing multi-valued column docs: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_multi_type_column_docs_batch(
+    index_path: &CxxString,
+    row_ids: &CxxVector<u64>,
+    text_column_names: &CxxVector<CxxString>,
+    text_column_docs: &CxxVector<CxxString>,
+    i64_column_names: &CxxVector<CxxString>,
+    i64_column_docs: &CxxVector<i64>,
+    f64_column_names: &CxxVector<CxxString>,
+    f64_column_docs: &CxxVector<f64>,
+    bytes_column_names: &CxxVector<CxxString>,
+    bytes_column_docs: &CxxVector<CxxString>,
+    date_column_names: &CxxVector<CxxString>,
+    date_column_docs: &CxxVector<CxxString>,
+    bool_column_names: &CxxVector<CxxString>,
+    bool_column_docs: &CxxVector<CxxString>,
+    u64_column_names: &CxxVector<CxxString>,
+    u64_column_docs: &CxxVector<u64>,
+    ip_column_names: &CxxVector<CxxString>,
+    ip_column_docs: &CxxVector<CxxString>,
+) -> BatchIndexResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let row_ids: Vec<u64> = match cxx_vector_converter::<u64>().convert(row_ids) {
+        Ok(ids) => ids,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'row_ids', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'row_ids', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'text_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let text_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(text_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'text_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'text_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let i64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(i64_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'i64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let i64_column_docs: Vec<i64> = match cxx_vector_converter::<i64>().convert(i64_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'i64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'i64_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let f64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(f64_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'f64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let f64_column_docs: Vec<f64> = match cxx_vector_converter::<f64>().convert(f64_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'f64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'f64_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER
+        .convert(bytes_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'bytes_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bytes_column_docs: Vec<Vec<u8>> = match CXX_VECTOR_STRING_TO_BYTES_CONERTER
+        .convert(bytes_column_docs)
+    {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'bytes_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bytes_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(date_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'date_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let date_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(date_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'date_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'date_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(bool_column_names)
+    {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'bool_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
                 format!("Can't convert 'bool_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let bool_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(bool_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'bool_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'bool_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let u64_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(u64_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'u64_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let u64_column_docs: Vec<u64> = match cxx_vector_converter::<u64>().convert(u64_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'u64_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'u64_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_names: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(ip_column_names) {
+        Ok(names) => names,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'ip_column_names', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_names', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let ip_column_docs: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(ip_column_docs) {
+        Ok(docs) => docs,
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Can't convert 'ip_column_docs', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'ip_column_docs', message: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    if row_ids.len() == 0 {
+        ERROR!(function: "ffi_index_multi_type_column_docs_batch", "row_ids can't be empty");
+        let error_msg_for_cxx: String = "row_ids can't be empty".to_string();
+        return BatchIndexResult {
+            indexed_count: 0,
+            failed_rows: Vec::new(),
+            error_code: -1,
+            error_msg: error_msg_for_cxx,
+        };
+    }
+
+    match index_multi_type_column_docs_batch(
+        &index_path,
+        &row_ids,
+        &text_column_names,
+        &text_column_docs,
+        &i64_column_names,
+        &i64_column_docs,
+        &f64_column_names,
+        &f64_column_docs,
+        &bytes_column_names,
+        &bytes_column_docs,
+        &date_column_names,
+        &date_column_docs,
+        &bool_column_names,
+        &bool_column_docs,
+        &u64_column_names,
+        &u64_column_docs,
+        &ip_column_names,
+        &ip_column_docs,
+    ) {
+        Ok(failed_rows) => BatchIndexResult {
+            indexed_count: (row_ids.len() - failed_rows.len()) as u64,
+            failed_rows,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_multi_type_column_docs_batch", "Error indexing multi-type column docs batch: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Error indexing multi-type column docs batch: {}", e);
+            return BatchIndexResult {
+                indexed_count: 0,
+                failed_rows: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let row_ids: Vec<u64> = match cxx_vector_converter::<u64>().convert(row_ids) {
+        Ok(ids) => ids,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids", "Can't convert 'row_ids', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'row_ids', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match delete_row_ids(&index_path, &row_ids) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids", "Error deleting row ids: {}", e);
+            let error_msg_for_cxx: String = format!("Error deleting row ids: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_delete_row_ids_deferred(index_path: &CxxString, row_ids: &CxxVector<u64>) -> U64Result {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids_deferred", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let row_ids: Vec<u64> = match cxx_vector_converter::<u64>().convert(row_ids) {
+        Ok(ids) => ids,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids_deferred", "Can't convert 'row_ids', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'row_ids', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match delete_row_ids_deferred(&index_path, &row_ids) {
+        Ok(opstamp) => U64Result {
+            result: opstamp,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_ids_deferred", "Error deleting row ids: {}", e);
+            let error_msg_for_cxx: String = format!("Error deleting row ids: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_delete_row_id_range(
+    index_path: &CxxString,
+    start_row_id: u64,
+    end_row_id: u64,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_id_range", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match delete_row_id_range(&index_path, start_row_id, end_row_id) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_delete_row_id_range", "Error deleting row id range: {}", e);
+            let error_msg_for_cxx: String = format!("Error deleting row id range: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_delete_by_term(
+    index_path: &CxxString,
+    column_name: &CxxString,
+    term: &CxxString,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_term", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let column_name: String = match CXX_STRING_CONERTER.convert(column_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_term", "Can't convert 'column_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_name', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let term: String = match CXX_STRING_CONERTER.convert(term) {
+        Ok(term) => term,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_term", "Can't convert 'term', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'term', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match delete_by_term(&index_path, &column_name, &term) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_term", "Error deleting by term: {}", e);
+            let error_msg_for_cxx: String = format!("Error deleting by term: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_delete_by_range(
+    index_path: &CxxString,
+    column_name: &CxxString,
+    lower_bound: &CxxString,
+    upper_bound: &CxxString,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_range", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let column_name: String = match CXX_STRING_CONERTER.convert(column_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_range", "Can't convert 'column_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'column_name', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let lower_bound: String = match CXX_STRING_CONERTER.convert(lower_bound) {
+        Ok(bound) => bound,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_range", "Can't convert 'lower_bound', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'lower_bound', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let upper_bound: String = match CXX_STRING_CONERTER.convert(upper_bound) {
+        Ok(bound) => bound,
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_range", "Can't convert 'upper_bound', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'upper_bound', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match delete_by_range(&index_path, &column_name, &lower_bound, &upper_bound) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_delete_by_range", "Error deleting by range: {}", e);
+            let error_msg_for_cxx: String = format!("Error deleting by range: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_commit(index_path: &CxxString) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match commit_index(&index_path) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit", "Error committing index: {}", e);
+            let error_msg_for_cxx: String = format!("Error committing index: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_commit_with_payload(
+    index_path: &CxxString,
+    payload: &CxxString,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit_with_payload", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let payload: String = match CXX_STRING_CONERTER.convert(payload) {
+        Ok(p) => p,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit_with_payload", "Can't convert 'payload', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'payload', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match commit_index_with_payload(&index_path, &payload) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit_with_payload", "Error committing index: {}", e);
+            let error_msg_for_cxx: String = format!("Error committing index: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_rollback(index_path: &CxxString) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_rollback", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match rollback_index(&index_path) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_rollback", "Error rolling back index: {}", e);
+            let error_msg_for_cxx: String = format!("Error rolling back index: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_prepare_commit_with_payload(
+    index_path: &CxxString,
+    payload: &CxxString,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_prepare_commit_with_payload", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let payload: String = match CXX_STRING_CONERTER.convert(payload) {
+        Ok(p) => p,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_prepare_commit_with_payload", "Can't convert 'payload', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'payload', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match prepare_commit_with_payload(&index_path, &payload) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_prepare_commit_with_payload", "Error preparing commit: {}", e);
+            let error_msg_for_cxx: String = format!("Error preparing commit: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_commit_prepared(index_path: &CxxString) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit_prepared", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match commit_prepared(&index_path) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_commit_prepared", "Error committing prepared commit: {}", e);
+            let error_msg_for_cxx: String = format!("Error committing prepared commit: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_index_writer_abort_prepared(index_path: &CxxString) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_abort_prepared", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match abort_prepared(&index_path) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_index_writer_abort_prepared", "Error aborting prepared commit: {}", e);
+            let error_msg_for_cxx: String = format!("Error aborting prepared commit: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_get_commit_payload(index_path: &CxxString) -> StringResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_get_commit_payload", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return StringResult {
+                result: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match get_commit_payload(&index_path) {
+        Ok(payload) => StringResult {
+            result: payload,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_get_commit_payload", "Error getting commit payload: {}", e);
+            let error_msg_for_cxx: String = format!("Error getting commit payload: {}", e);
+            return StringResult {
+                result: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_force_merge(index_path: &CxxString, max_segments: u32) -> ForceMergeResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_force_merge", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return ForceMergeResult {
+                segments_before: 0,
+                segments_after: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match force_merge(&index_path, max_segments) {
+        Ok((segments_before, segments_after)) => ForceMergeResult {
+            segments_before,
+            segments_after,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_force_merge", "Error force merging index: {}", e);
+            let error_msg_for_cxx: String = format!("Error force merging index: {}", e);
+            return ForceMergeResult {
+                segments_before: 0,
+                segments_after: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_reindex_with_parameter(
+    index_path: &CxxString,
+    index_json_parameter: &CxxString,
+) -> U64Result {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_reindex_with_parameter", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let index_json_parameter: String = match CXX_STRING_CONERTER.convert(index_json_parameter) {
+        Ok(json) => json,
+        Err(e) => {
+            ERROR!(function: "ffi_reindex_with_parameter", "Can't convert 'index_json_parameter', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'index_json_parameter', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match reindex_with_parameter(&index_path, &index_json_parameter) {
+        Ok(reindexed_docs) => U64Result {
+            result: reindexed_docs,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_reindex_with_parameter", "Error reindexing: {}", e);
+            let error_msg_for_cxx: String = format!("Error reindexing: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_get_reindex_progress(index_path: &CxxString) -> ReindexProgressResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_get_reindex_progress", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return ReindexProgressResult {
+                reindexed_docs: 0,
+                total_docs: 0,
+                finished: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match get_reindex_progress(&index_path) {
+        Ok((reindexed_docs, total_docs, finished)) => ReindexProgressResult {
+            reindexed_docs,
+            total_docs,
+            finished,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_get_reindex_progress", "Error getting reindex progress: {}", e);
+            let error_msg_for_cxx: String = format!("Error getting reindex progress: {}", e);
+            return ReindexProgressResult {
+                reindexed_docs: 0,
+                total_docs: 0,
+                finished: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_create_snapshot(index_path: &CxxString, snapshot_dir: &CxxString) -> U64Result {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_create_snapshot", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let snapshot_dir: String = match CXX_STRING_CONERTER.convert(snapshot_dir) {
+        Ok(dir) => dir,
+        Err(e) => {
+            ERROR!(function: "ffi_create_snapshot", "Can't convert 'snapshot_dir', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'snapshot_dir', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match create_snapshot(&index_path, &snapshot_dir) {
+        Ok(opstamp) => U64Result {
+            result: opstamp,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_create_snapshot", "Error creating snapshot: {}", e);
+            let error_msg_for_cxx: String = format!("Error creating snapshot: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_release_snapshot(snapshot_dir: &CxxString) -> BoolResult {
+    let snapshot_dir: String = match CXX_STRING_CONERTER.convert(snapshot_dir) {
+        Ok(dir) => dir,
+        Err(e) => {
+            ERROR!(function: "ffi_release_snapshot", "Can't convert 'snapshot_dir', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'snapshot_dir', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match release_snapshot(&snapshot_dir) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_release_snapshot", "Error releasing snapshot: {}", e);
+            let error_msg_for_cxx: String = format!("Error releasing snapshot: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_restore_snapshot(snapshot_dir: &CxxString, index_path: &CxxString) -> BoolResult {
+    let snapshot_dir: String = match CXX_STRING_CONERTER.convert(snapshot_dir) {
+        Ok(dir) => dir,
+        Err(e) => {
+            ERROR!(function: "ffi_restore_snapshot", "Can't convert 'snapshot_dir', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'snapshot_dir', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_restore_snapshot", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match restore_snapshot(&snapshot_dir, &index_path) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_restore_snapshot", "Error restoring snapshot: {}", e);
+            let error_msg_for_cxx: String = format!("Error restoring snapshot: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_list_segment_changes(
+    index_path: &CxxString,
+    follower_id: &CxxString,
+    known_files: &CxxVector<CxxString>,
+) -> SegmentChangesResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_list_segment_changes", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return SegmentChangesResult {
+                opstamp: 0,
+                added_files: Vec::new(),
+                removed_files: Vec::new(),
+                meta_json: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let follower_id: String = match CXX_STRING_CONERTER.convert(follower_id) {
+        Ok(id) => id,
+        Err(e) => {
+            ERROR!(function: "ffi_list_segment_changes", "Can't convert 'follower_id', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'follower_id', message: {}", e);
+            return SegmentChangesResult {
+                opstamp: 0,
+                added_files: Vec::new(),
+                removed_files: Vec::new(),
+                meta_json: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let known_files: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(known_files) {
+        Ok(files) => files,
+        Err(e) => {
+            ERROR!(function: "ffi_list_segment_changes", "Can't convert 'known_files', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'known_files', message: {}", e);
+            return SegmentChangesResult {
+                opstamp: 0,
+                added_files: Vec::new(),
+                removed_files: Vec::new(),
+                meta_json: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match list_segment_changes(&index_path, &follower_id, &known_files) {
+        Ok(segment_changes) => SegmentChangesResult {
+            opstamp: segment_changes.opstamp,
+            added_files: segment_changes.added_files,
+            removed_files: segment_changes.removed_files,
+            meta_json: segment_changes.meta_json,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_list_segment_changes", "Error listing segment changes: {}", e);
+            let error_msg_for_cxx: String = format!("Error listing segment changes: {}", e);
+            return SegmentChangesResult {
+                opstamp: 0,
+                added_files: Vec::new(),
+                removed_files: Vec::new(),
+                meta_json: String::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_release_segment_changes(index_path: &CxxString, follower_id: &CxxString) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_release_segment_changes", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -452,14 +2793,11 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    let bool_column_docs: Vec<bool> = match CXX_VECTOR_STRING_TO_BOOL_CONERTER
-        .convert(bool_column_docs)
-    {
-        Ok(docs) => docs,
+    let follower_id: String = match CXX_STRING_CONERTER.convert(follower_id) {
+        Ok(id) => id,
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Can't convert 'bool_column_docs', message: {}", e);
-            let error_msg_for_cxx: String =
-                format!("Can't convert 'bool_column_docs', message: {}", e);
+            ERROR!(function: "ffi_release_segment_changes", "Can't convert 'follower_id', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'follower_id', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -468,65 +2806,139 @@ pub fn ffi_index_multi_type_column_docs(
         }
     };
 
-    if bool_column_names.len() != bool_column_docs.len() {
-        ERROR!(function: "ffi_index_multi_column_docs", "bool_column_names size doesn't match bool_column_docs size");
-        let error_msg_for_cxx: String =
-            "bool_column_names size doesn't match bool_column_docs size".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
+    match release_segment_changes(&index_path, &follower_id) {
+        Ok(status) => BoolResult {
+            result: status,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_release_segment_changes", "Error releasing segment changes: {}", e);
+            let error_msg_for_cxx: String = format!("Error releasing segment changes: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
     }
+}
 
-    if (text_column_names.len()
-        + i64_column_names.len()
-        + f64_column_names.len()
-        + bytes_column_names.len()
-        + date_column_docs.len()
-        + bool_column_docs.len())
-        == 0
-        || (text_column_docs.len()
-            + i64_column_docs.len()
-            + f64_column_docs.len()
-            + bytes_column_docs.len()
-            + date_column_docs.len()
-            + bool_column_docs.len())
-            == 0
-    {
-        ERROR!(function: "ffi_index_multi_column_docs", "column_names and column_docs can't be empty");
-        let error_msg_for_cxx: String = "column_names and column_docs can't be empty".to_string();
-        return BoolResult {
-            result: false,
-            error_code: -1,
-            error_msg: error_msg_for_cxx,
-        };
+pub fn ffi_read_segment_file(
+    index_path: &CxxString,
+    follower_id: &CxxString,
+    file_name: &CxxString,
+) -> BytesResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_read_segment_file", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BytesResult {
+                result: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let follower_id: String = match CXX_STRING_CONERTER.convert(follower_id) {
+        Ok(id) => id,
+        Err(e) => {
+            ERROR!(function: "ffi_read_segment_file", "Can't convert 'follower_id', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'follower_id', message: {}", e);
+            return BytesResult {
+                result: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let file_name: String = match CXX_STRING_CONERTER.convert(file_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_read_segment_file", "Can't convert 'file_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'file_name', message: {}", e);
+            return BytesResult {
+                result: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match read_segment_file(&index_path, &follower_id, &file_name) {
+        Ok(content) => BytesResult {
+            result: content,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_read_segment_file", "Error reading segment file: {}", e);
+            let error_msg_for_cxx: String = format!("Error reading segment file: {}", e);
+            return BytesResult {
+                result: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
     }
+}
 
-    match index_multi_type_column_docs(
-        &index_path,
-        row_id,
-        &text_column_names,
-        &text_column_docs,
-        &i64_column_names,
-        &i64_column_docs,
-        &f64_column_names,
-        &f64_column_docs,
-        &bytes_column_names,
-        &bytes_column_docs,
-        &date_column_names,
-        &date_column_docs,
-        &bool_column_names,
-        &bool_column_docs,
-    ) {
+pub fn ffi_write_segment_file(
+    index_path: &CxxString,
+    file_name: &CxxString,
+    content: &CxxVector<u8>,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_write_segment_file", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let file_name: String = match CXX_STRING_CONERTER.convert(file_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_write_segment_file", "Can't convert 'file_name', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'file_name', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let content: Vec<u8> = match cxx_vector_converter::<u8>().convert(content) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            ERROR!(function: "ffi_write_segment_file", "Can't convert 'content', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'content', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match write_segment_file(&index_path, &file_name, &content) {
         Ok(status) => BoolResult {
             result: status,
             error_code: 0,
             error_msg: String::new(),
         },
         Err(e) => {
-            ERROR!(function: "ffi_index_multi_column_docs", "Error indexing multi-column docs: {}", e);
-            let error_msg_for_cxx: String = format!("Error indexing multi-column docs: {}", e);
+            ERROR!(function: "ffi_write_segment_file", "Error writing segment file: {}", e);
+            let error_msg_for_cxx: String = format!("Error writing segment file: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -536,11 +2948,15 @@ pub fn ffi_index_multi_type_column_docs(
     }
 }
 
-pub fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> BoolResult {
+pub fn ffi_apply_segment_changes(
+    index_path: &CxxString,
+    meta_json: &CxxString,
+    removed_files: &CxxVector<CxxString>,
+) -> BoolResult {
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_delete_row_ids", "Can't convert 'index_path', message: {}", e);
+            ERROR!(function: "ffi_apply_segment_changes", "Can't convert 'index_path', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
@@ -550,11 +2966,11 @@ pub fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> B
         }
     };
 
-    let row_ids: Vec<u64> = match cxx_vector_converter::<u64>().convert(row_ids) {
-        Ok(ids) => ids,
+    let meta_json: String = match CXX_STRING_CONERTER.convert(meta_json) {
+        Ok(json) => json,
         Err(e) => {
-            ERROR!(function: "ffi_delete_row_ids", "Can't convert 'row_ids', message: {}", e);
-            let error_msg_for_cxx: String = format!("Can't convert 'row_ids', message: {}", e);
+            ERROR!(function: "ffi_apply_segment_changes", "Can't convert 'meta_json', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'meta_json', message: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -563,15 +2979,29 @@ pub fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> B
         }
     };
 
-    match delete_row_ids(&index_path, &row_ids) {
+    let removed_files: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(removed_files) {
+        Ok(files) => files,
+        Err(e) => {
+            ERROR!(function: "ffi_apply_segment_changes", "Can't convert 'removed_files', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'removed_files', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match apply_segment_changes(&index_path, &meta_json, &removed_files) {
         Ok(status) => BoolResult {
             result: status,
             error_code: 0,
             error_msg: String::new(),
         },
         Err(e) => {
-            ERROR!(function: "ffi_delete_row_ids", "Error deleting row ids: {}", e);
-            let error_msg_for_cxx: String = format!("Error deleting row ids: {}", e);
+            ERROR!(function: "ffi_apply_segment_changes", "Error applying segment changes: {}", e);
+            let error_msg_for_cxx: String = format!("Error applying segment changes: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -581,11 +3011,79 @@ pub fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> B
     }
 }
 
-pub fn ffi_index_writer_commit(index_path: &CxxString) -> BoolResult {
+pub fn ffi_merge_indexes(
+    index_path: &CxxString,
+    source_paths: &CxxVector<CxxString>,
+    row_id_conflict_policy: &CxxString,
+) -> U64Result {
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
         Err(e) => {
-            ERROR!(function: "ffi_index_writer_commit", "Can't convert 'index_path', message: {}", e);
+            ERROR!(function: "ffi_merge_indexes", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let source_paths: Vec<String> = match CXX_VECTOR_STRING_CONERTER.convert(source_paths) {
+        Ok(paths) => paths,
+        Err(e) => {
+            ERROR!(function: "ffi_merge_indexes", "Can't convert 'source_paths', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'source_paths', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let row_id_conflict_policy: String = match CXX_STRING_CONERTER.convert(row_id_conflict_policy) {
+        Ok(policy) => policy,
+        Err(e) => {
+            ERROR!(function: "ffi_merge_indexes", "Can't convert 'row_id_conflict_policy', message: {}", e);
+            let error_msg_for_cxx: String =
+                format!("Can't convert 'row_id_conflict_policy', message: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match merge_indexes(&index_path, &source_paths, &row_id_conflict_policy) {
+        Ok(attached_docs) => U64Result {
+            result: attached_docs,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_merge_indexes", "Error merging indexes: {}", e);
+            let error_msg_for_cxx: String = format!("Error merging indexes: {}", e);
+            return U64Result {
+                result: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
+pub fn ffi_split_index(
+    index_path: &CxxString,
+    split_row_id: u64,
+    left_path: &CxxString,
+    right_path: &CxxString,
+) -> BoolResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_split_index", "Can't convert 'index_path', message: {}", e);
             let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
             return BoolResult {
                 result: false,
@@ -595,15 +3093,41 @@ pub fn ffi_index_writer_commit(index_path: &CxxString) -> BoolResult {
         }
     };
 
-    match commit_index(&index_path) {
+    let left_path: String = match CXX_STRING_CONERTER.convert(left_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_split_index", "Can't convert 'left_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'left_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    let right_path: String = match CXX_STRING_CONERTER.convert(right_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_split_index", "Can't convert 'right_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'right_path', message: {}", e);
+            return BoolResult {
+                result: false,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match split_index(&index_path, split_row_id, &left_path, &right_path) {
         Ok(status) => BoolResult {
             result: status,
             error_code: 0,
             error_msg: String::new(),
         },
         Err(e) => {
-            ERROR!(function: "ffi_index_writer_commit", "Error committing index: {}", e);
-            let error_msg_for_cxx: String = format!("Error committing index: {}", e);
+            ERROR!(function: "ffi_split_index", "Error splitting index: {}", e);
+            let error_msg_for_cxx: String = format!("Error splitting index: {}", e);
             return BoolResult {
                 result: false,
                 error_code: -1,
@@ -613,6 +3137,38 @@ pub fn ffi_index_writer_commit(index_path: &CxxString) -> BoolResult {
     }
 }
 
+pub fn ffi_verify_index(index_path: &CxxString) -> VerifyIndexResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_verify_index", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return VerifyIndexResult {
+                problems: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match verify_index(&index_path) {
+        Ok(problems) => VerifyIndexResult {
+            problems,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_verify_index", "Error verifying index: {}", e);
+            let error_msg_for_cxx: String = format!("Error verifying index: {}", e);
+            return VerifyIndexResult {
+                problems: Vec::new(),
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
 pub fn ffi_free_index_writer(index_path: &CxxString) -> BoolResult {
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
@@ -1,20 +1,44 @@
 use crate::logger::logger_bridge::TantivySearchLogger;
 use crate::{common::constants::LOG_CALLBACK, INFO};
 use std::sync::Mutex;
-use tantivy::{Index, IndexWriter, Opstamp, TantivyDocument, Term};
+use tantivy::merge_policy::MergePolicy;
+use tantivy::query::Query;
+use tantivy::{Index, IndexWriter, Opstamp, SegmentId, SegmentMeta, TantivyDocument, Term};
 
 pub struct IndexWriterBridge {
     pub path: String,
     pub index: Index,
     pub writer: Mutex<Option<IndexWriter>>,
+    // Payload kept between `prepare_commit` and `commit_prepared`/`abort_prepared`.
+    // Always locked after `writer`.
+    pub prepared_payload: Mutex<Option<String>>,
 }
 
 impl IndexWriterBridge {
+    // Write operations are rejected while a commit is prepared, same as tantivy `PreparedCommit`
+    // which holds the writer until it's committed or aborted.
+    fn ensure_not_prepared(&self, function_name: &str) -> Result<(), String> {
+        match self.prepared_payload.lock() {
+            Ok(prepared_payload) => {
+                if prepared_payload.is_some() {
+                    Err(format!(
+                        "IndexWriterBridge has a prepared commit, {} is not allowed before commit_prepared or abort_prepared",
+                        function_name
+                    ))
+                } else {
+                    Ok(())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
     // wrapper for IndexWriter.commit()
     pub fn commit(&self) -> Result<Opstamp, String> {
         match self.writer.lock() {
             Ok(mut writer) => {
                 if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("commit")?;
                     writer.commit().map_err(|e| e.to_string())
                 } else {
                     Err("IndexWriterBridge is not available".to_string())
@@ -24,11 +48,124 @@ impl IndexWriterBridge {
         }
     }
 
+    // Two-phase commit, the `payload` is recorded in meta.json together with this commit.
+    pub fn commit_with_payload(&self, payload: &str) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("commit_with_payload")?;
+                    let mut prepared_commit = writer.prepare_commit().map_err(|e| e.to_string())?;
+                    prepared_commit.set_payload(payload);
+                    prepared_commit.commit().map_err(|e| e.to_string())
+                } else {
+                    Err("IndexWriterBridge is not available for commit_with_payload".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // First phase of two-phase commit, flushes pending documents so that errors surface here.
+    // `payload` is kept until `commit_prepared` records it, or `abort_prepared` discards it.
+    pub fn prepare_commit(&self, payload: &str) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("prepare_commit")?;
+                    let opstamp = writer
+                        .prepare_commit()
+                        .map_err(|e| e.to_string())?
+                        .opstamp();
+                    match self.prepared_payload.lock() {
+                        Ok(mut prepared_payload) => *prepared_payload = Some(payload.to_string()),
+                        Err(e) => return Err(format!("Lock error: {}", e)),
+                    }
+                    Ok(opstamp)
+                } else {
+                    Err("IndexWriterBridge is not available for prepare_commit".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // Second phase of two-phase commit, publishes the prepared commit with its payload.
+    // Nothing was added since `prepare_commit`, so preparing again only flushes an empty queue.
+    pub fn commit_prepared(&self) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    let mut prepared_payload = self
+                        .prepared_payload
+                        .lock()
+                        .map_err(|e| format!("Lock error: {}", e))?;
+                    let payload = match prepared_payload.as_ref() {
+                        Some(payload) => payload.clone(),
+                        None => return Err("No prepared commit for commit_prepared".to_string()),
+                    };
+                    let mut prepared_commit = writer.prepare_commit().map_err(|e| e.to_string())?;
+                    prepared_commit.set_payload(&payload);
+                    let opstamp = prepared_commit.commit().map_err(|e| e.to_string())?;
+                    *prepared_payload = None;
+                    Ok(opstamp)
+                } else {
+                    Err("IndexWriterBridge is not available for commit_prepared".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // Abort the prepared commit, documents flushed by `prepare_commit` are discarded.
+    pub fn abort_prepared(&self, merge_policy: Box<dyn MergePolicy>) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    let mut prepared_payload = self
+                        .prepared_payload
+                        .lock()
+                        .map_err(|e| format!("Lock error: {}", e))?;
+                    if prepared_payload.is_none() {
+                        return Err("No prepared commit for abort_prepared".to_string());
+                    }
+                    let opstamp = writer.rollback().map_err(|e| e.to_string())?;
+                    writer.set_merge_policy(merge_policy);
+                    *prepared_payload = None;
+                    Ok(opstamp)
+                } else {
+                    Err("IndexWriterBridge is not available for abort_prepared".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // Wrapper for IndexWriter.rollback(), discards all uncommitted operations.
+    // Tantivy recreates the writer during rollback, so merge policy need to be set again.
+    pub fn rollback(&self, merge_policy: Box<dyn MergePolicy>) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    let opstamp = writer.rollback().map_err(|e| e.to_string())?;
+                    writer.set_merge_policy(merge_policy);
+                    if let Ok(mut prepared_payload) = self.prepared_payload.lock() {
+                        *prepared_payload = None;
+                    }
+                    Ok(opstamp)
+                } else {
+                    Err("IndexWriterBridge is not available for rollback".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
     // wrapper for IndexWriter.add_document()
     pub fn add_document(&self, document: TantivyDocument) -> Result<Opstamp, String> {
         match self.writer.lock() {
             Ok(mut writer) => {
                 if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("add_document")?;
                     writer.add_document(document).map_err(|e| e.to_string())
                 } else {
                     Err("IndexWriterBridge is not available".to_string())
@@ -38,12 +175,56 @@ impl IndexWriterBridge {
         }
     }
 
+    // Add a group of documents under a single writer lock.
+    // Outer error means the writer is unavailable, inner results align with `documents`.
+    pub fn add_documents(
+        &self,
+        documents: Vec<TantivyDocument>,
+    ) -> Result<Vec<Result<Opstamp, String>>, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("add_documents")?;
+                    Ok(documents
+                        .into_iter()
+                        .map(|document| writer.add_document(document).map_err(|e| e.to_string()))
+                        .collect())
+                } else {
+                    Err("IndexWriterBridge is not available for add_documents".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // Delete `term` and add `document` under a single writer lock.
+    // The delete opstamp precedes the add, so only older documents are removed.
+    pub fn upsert_document(
+        &self,
+        term: Term,
+        document: TantivyDocument,
+    ) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("upsert_document")?;
+                    writer.delete_term(term);
+                    writer.add_document(document).map_err(|e| e.to_string())
+                } else {
+                    Err("IndexWriterBridge is not available for upsert_document".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
     // wrapper for IndexWriter.delete_term()
     #[allow(dead_code)]
     pub fn delete_term(&self, term: Term) -> Result<Opstamp, String> {
         match self.writer.lock() {
             Ok(mut writer) => {
                 if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("delete_term")?;
                     Ok(writer.delete_term(term))
                 } else {
                     Err("IndexWriterBridge is not available for delete_term".to_string())
@@ -53,11 +234,27 @@ impl IndexWriterBridge {
         }
     }
 
+    // Wrapper for IndexWriter.delete_query(), deletes all documents matching `query`.
+    pub fn delete_query(&self, query: Box<dyn Query>) -> Result<Opstamp, String> {
+        match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("delete_query")?;
+                    writer.delete_query(query).map_err(|e| e.to_string())
+                } else {
+                    Err("IndexWriterBridge is not available for delete_query".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
     // Delete a group of terms.
     pub fn delete_terms(&self, terms: Vec<Term>) -> Result<Opstamp, String> {
         match self.writer.lock() {
             Ok(mut writer) => {
                 if let Some(writer) = writer.as_mut() {
+                    self.ensure_not_prepared("delete_terms")?;
                     let mut opstamp: Opstamp = 0;
                     for term in terms {
                         opstamp = writer.delete_term(term)
@@ -71,6 +268,54 @@ impl IndexWriterBridge {
         }
     }
 
+    // Wrapper for IndexWriter.set_merge_policy(), affects merges scheduled after this call.
+    pub fn set_merge_policy(&self, merge_policy: Box<dyn MergePolicy>) -> Result<(), String> {
+        match self.writer.lock() {
+            Ok(writer) => {
+                if let Some(writer) = writer.as_ref() {
+                    writer.set_merge_policy(merge_policy);
+                    Ok(())
+                } else {
+                    Err("IndexWriterBridge is not available for set_merge_policy".to_string())
+                }
+            }
+            Err(e) => Err(format!("Lock error: {}", e)),
+        }
+    }
+
+    // Wrapper for IndexWriter.merge(), waits until merge finished.
+    // The writer lock is released before waiting, other operations won't be blocked.
+    pub fn merge(&self, segment_ids: &[SegmentId]) -> Result<Option<SegmentMeta>, String> {
+        let future = match self.writer.lock() {
+            Ok(mut writer) => {
+                if let Some(writer) = writer.as_mut() {
+                    writer.merge(segment_ids)
+                } else {
+                    return Err("IndexWriterBridge is not available for merge".to_string());
+                }
+            }
+            Err(e) => return Err(format!("Lock error: {}", e)),
+        };
+        future.wait().map_err(|e| e.to_string())
+    }
+
+    // Wrapper for IndexWriter.garbage_collect_files(), removes files no longer used.
+    pub fn garbage_collect_files(&self) -> Result<(), String> {
+        let future = match self.writer.lock() {
+            Ok(writer) => {
+                if let Some(writer) = writer.as_ref() {
+                    writer.garbage_collect_files()
+                } else {
+                    return Err(
+                        "IndexWriterBridge is not available for garbage_collect_files".to_string(),
+                    );
+                }
+            }
+            Err(e) => return Err(format!("Lock error: {}", e)),
+        };
+        future.wait().map(|_| ()).map_err(|e| e.to_string())
+    }
+
     // Wrapper for IndexWriter.wait_merging_threads().
     pub fn wait_merging_threads(&self) -> Result<(), String> {
         // use Interior Mutability
@@ -125,6 +370,7 @@ mod tests {
             index,
             path: index_directory_str.to_string(),
             writer: Mutex::new(Some(writer)),
+            prepared_payload: Mutex::new(None),
         };
         index_writer_bridge
     }
@@ -204,6 +450,171 @@ mod tests {
         assert_eq!(searched_bitmap_2.len(), 2);
     }
 
+    #[test]
+    pub fn test_add_documents() {
+        // Create a temp directory for test.
+        let directory = TempDir::new().expect("Can't create temp directory");
+        let directory_str = directory.path().to_str().unwrap();
+
+        // Initialize a temp `IndexWriterBridge` for test.
+        let index_writer_bridge = create_index_in_temp_directory(directory_str);
+        let row_id_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("row_id")
+            .expect("Can't get row_id filed");
+
+        // Add a group of documents under a single lock.
+        let docs: Vec<TantivyDocument> = (0..3)
+            .map(|row_id| {
+                let mut doc = TantivyDocument::default();
+                doc.add_u64(row_id_field, row_id);
+                doc
+            })
+            .collect();
+        let results = index_writer_bridge
+            .add_documents(docs)
+            .expect("Can't add documents");
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(index_writer_bridge.commit().is_ok());
+
+        let searcher = index_writer_bridge
+            .index
+            .reader()
+            .expect("Can't get reader from index")
+            .searcher();
+        assert_eq!(searcher.num_docs(), 3);
+
+        // Writer is unavailable after merging threads.
+        assert!(index_writer_bridge.wait_merging_threads().is_ok());
+        assert!(index_writer_bridge.add_documents(vec![]).is_err());
+    }
+
+    #[test]
+    pub fn test_upsert_document() {
+        // Create a temp directory for test.
+        let directory = TempDir::new().expect("Can't create temp directory");
+        let directory_str = directory.path().to_str().unwrap();
+
+        // Initialize a temp `IndexWriterBridge` for test.
+        let index_writer_bridge = create_index_in_temp_directory(directory_str);
+
+        // Prepare some docs for search.
+        let query_parser = index_some_docs_for_test(&index_writer_bridge);
+        assert!(index_writer_bridge.commit().is_ok());
+
+        // Replace row_id 0, it no longer contains `Ancient`.
+        let schema = index_writer_bridge.index.schema();
+        let row_id_field = schema.get_field("row_id").expect("Can't get row_id filed");
+        let text_field = schema.get_field("text").expect("Can't get text filed");
+        let mut doc = TantivyDocument::default();
+        doc.add_u64(row_id_field, 0);
+        doc.add_text(text_field, "Modern empires rise and fall.");
+        assert!(index_writer_bridge
+            .upsert_document(Term::from_field_u64(row_id_field, 0), doc)
+            .is_ok());
+
+        let reader = index_writer_bridge
+            .index
+            .reader()
+            .expect("Can't get reader from index");
+        let text_query = query_parser
+            .parse_query("Ancient")
+            .expect("Can't parse query");
+        let row_id_collector = RowIdRoaringCollector::with_field("row_id".to_string());
+
+        // Nothing changes before commit.
+        let searched_bitmap = reader
+            .searcher()
+            .search(&text_query, &row_id_collector)
+            .expect("Can't execute search.");
+        assert_eq!(searched_bitmap.len(), 2);
+
+        // Both delete and add are visible after commit.
+        assert!(index_writer_bridge.commit().is_ok());
+        assert!(reader.reload().is_ok());
+        let searched_bitmap = reader
+            .searcher()
+            .search(&text_query, &row_id_collector)
+            .expect("Can't execute search.");
+        assert_eq!(searched_bitmap.len(), 1);
+        let modern_query = query_parser
+            .parse_query("Modern")
+            .expect("Can't parse query");
+        let searched_bitmap = reader
+            .searcher()
+            .search(&modern_query, &row_id_collector)
+            .expect("Can't execute search.");
+        assert_eq!(searched_bitmap.iter().collect::<Vec<u32>>(), vec![0]);
+        assert_eq!(reader.searcher().num_docs(), 5);
+    }
+
+    #[test]
+    pub fn test_merge_and_garbage_collect_files() {
+        // Create a temp directory for test.
+        let directory = TempDir::new().expect("Can't create temp directory");
+        let directory_str = directory.path().to_str().unwrap();
+
+        // Initialize a temp `IndexWriterBridge` for test, each commit generates a segment.
+        let index_writer_bridge = create_index_in_temp_directory(directory_str);
+        for _ in 0..3 {
+            index_some_docs_for_test(&index_writer_bridge);
+            assert!(index_writer_bridge.commit().is_ok());
+        }
+        let segment_ids = index_writer_bridge
+            .index
+            .searchable_segment_ids()
+            .expect("Can't get segment ids");
+        assert_eq!(segment_ids.len(), 3);
+
+        let merged = index_writer_bridge
+            .merge(&segment_ids)
+            .expect("Can't merge segments");
+        assert_eq!(merged.map(|meta| meta.max_doc()), Some(15));
+        assert!(index_writer_bridge.garbage_collect_files().is_ok());
+        assert_eq!(
+            index_writer_bridge
+                .index
+                .searchable_segment_ids()
+                .expect("Can't get segment ids")
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    pub fn test_commit_with_payload_and_rollback() {
+        // Create a temp directory for test.
+        let directory = TempDir::new().expect("Can't create temp directory");
+        let directory_str = directory.path().to_str().unwrap();
+
+        // Initialize a temp `IndexWriterBridge` for test.
+        let index_writer_bridge = create_index_in_temp_directory(directory_str);
+        index_some_docs_for_test(&index_writer_bridge);
+        assert!(index_writer_bridge
+            .commit_with_payload("raft_index:10")
+            .is_ok());
+        let metas = index_writer_bridge
+            .index
+            .load_metas()
+            .expect("Can't load metas");
+        assert_eq!(metas.payload, Some("raft_index:10".to_string()));
+
+        // Uncommitted documents are discarded after rollback.
+        index_some_docs_for_test(&index_writer_bridge);
+        assert!(index_writer_bridge
+            .rollback(Box::new(LogMergePolicy::default()))
+            .is_ok());
+        assert!(index_writer_bridge.commit().is_ok());
+        let searcher = index_writer_bridge
+            .index
+            .reader()
+            .expect("Can't get reader from index")
+            .searcher();
+        assert_eq!(searcher.num_docs(), 5);
+    }
+
     #[test]
     pub fn test_wait_merging_threads() {
         // Create a temp directory for test.
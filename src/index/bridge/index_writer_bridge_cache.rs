@@ -96,6 +96,7 @@ mod tests {
             index,
             path: index_directory_str.to_string(),
             writer: Mutex::new(Some(writer)),
+            prepared_payload: Mutex::new(None),
         };
         index_w
     }
@@ -1,78 +1,155 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::net::Ipv6Addr;
+use std::ops::{Bound, Range};
+use std::str::FromStr;
 use std::sync::Mutex;
-use std::{path::Path, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use tantivy::schema::BytesOptions;
 use tantivy::schema::DateOptions;
+use tantivy::schema::Field;
+use tantivy::schema::FieldEntry;
 use tantivy::schema::IndexRecordOption;
+use tantivy::schema::IpAddrOptions;
+use tantivy::schema::JsonObjectOptions;
 use tantivy::schema::NumericOptions;
 use tantivy::schema::TextFieldIndexing;
 use tantivy::schema::TextOptions;
+use tantivy::schema::Type;
 use tantivy::schema::FAST;
-use tantivy::schema::{Schema, TEXT};
+use tantivy::schema::{Schema, SchemaBuilder, TEXT};
 use tantivy::schema::{INDEXED, STORED};
 
-use crate::common::errors::TantivySearchError;
+use crate::common::constants::{
+    FORCE_MERGE_MAX_ATTEMPTS, INDEX_INFO_FILE_NAME, META_FILE_NAME, REINDEX_PROGRESS,
+    REPLICATION_PINS, SNAPSHOT_PINS,
+};
+use crate::common::converter::{parse_bool, parse_datetime, parse_ip};
+use crate::common::errors::{IndexUtilsError, TantivySearchError};
 use crate::index::bridge::index_writer_bridge::IndexWriterBridge;
 use crate::logger::logger_bridge::TantivySearchLogger;
-use crate::search::implements::api_common_impl::free_index_reader;
+use crate::search::implements::api_common_impl::{
+    free_index_reader, load_index_reader, resolve_index,
+};
+use crate::search::implements::strategy::query_strategy::{column_range_query, exact_term_query};
 use crate::tokenizer::dto::index_parameter_dto::IndexParameterDTO;
 use crate::tokenizer::tokenizer_utils::TokenizerUtils;
+use crate::tokenizer::vo::index_settings_json_vo::{
+    DocstoreCompressorConfig, IndexSettingsConfig, MergePolicyConfig,
+};
+use crate::tokenizer::vo::tokenizer_json_vo::SynonymExpand;
 use crate::tokenizer::vo::tokenizer_types::TokenizerType;
 use crate::tokenizer::vo::tokenizers_vo::TokenizerConfig;
 use crate::utils::index_utils::IndexUtils;
 use crate::{common::constants::LOG_CALLBACK, DEBUG, ERROR, INFO, WARNING};
-use crate::{FFI_INDEX_SEARCHER_CACHE, FFI_INDEX_WRITER_CACHE};
-
+use crate::{RowIdWithError, FFI_INDEX_SEARCHER_CACHE, FFI_INDEX_WRITER_CACHE};
+
+use tantivy::directory::error::OpenReadError;
+use tantivy::directory::{MmapDirectory, RamDirectory, TerminatingWrite};
+use tantivy::fieldnorm::{FieldNormsSerializer, FieldNormsWriter};
+use tantivy::merge_policy::{LogMergePolicy, MergePolicy, NoMergePolicy};
+use tantivy::query::{Query, RangeQuery};
+use tantivy::store::{Compressor, ZstdCompressor};
 use tantivy::DateTime;
-use tantivy::{Index, TantivyDocument, Term};
-
-pub fn create_index_with_parameter(
-    index_path: &str,
-    column_names: &Vec<String>,
-    index_json_parameter: &str,
-) -> Result<bool, TantivySearchError> {
-    // If the `index_path` already exists, it will be recreated,
-    // it's necessary to free any `index_reader` associated with this directory.
-    free_index_reader(index_path).map_err(|e| {
-        ERROR!("{}", e);
-        e
-    })?;
-
-    // If the `index_path` already exists, it will be recreated,
-    // it's necessary to free any `index_writer` associated with this directory.
-    free_index_writer(index_path).map_err(|e| {
-        ERROR!("{}", e);
-        e
-    })?;
-
-    // Initialize the index directory, it will store tantivy index files.
-    let index_files_directory: &Path = Path::new(index_path);
-    IndexUtils::initialize_index_directory(index_files_directory)?;
+use tantivy::{
+    DocId, Directory, Index, IndexMeta, IndexSettings, IndexSortByField, IndexWriter, Opstamp,
+    Order, ReloadPolicy, SegmentComponent, SegmentId, SegmentMeta, TantivyDocument, Term,
+};
+
+// Build tantivy merge policy from `merge_policy_config`.
+fn build_merge_policy(merge_policy_config: &MergePolicyConfig) -> Box<dyn MergePolicy> {
+    match merge_policy_config {
+        MergePolicyConfig::Log {
+            min_num_segments,
+            max_docs_before_merge,
+            min_layer_size,
+            level_log_size,
+            del_docs_ratio_before_merge,
+        } => {
+            let mut merge_policy = LogMergePolicy::default();
+            merge_policy.set_min_num_segments(*min_num_segments);
+            merge_policy.set_max_docs_before_merge(*max_docs_before_merge);
+            merge_policy.set_min_layer_size(*min_layer_size);
+            merge_policy.set_level_log_size(*level_log_size);
+            merge_policy.set_del_docs_ratio_before_merge(*del_docs_ratio_before_merge);
+            Box::new(merge_policy)
+        }
+        MergePolicyConfig::NoMerge => Box::new(NoMergePolicy),
+    }
+}
 
-    // Save custom index json parameter DTO to index directory.
-    let index_parameter_dto = IndexParameterDTO {
-        tokenizers_json_parameter: index_json_parameter.to_string(),
+// Build tantivy index settings from docstore configs in `index_settings`.
+fn build_index_settings(index_settings: &IndexSettingsConfig) -> IndexSettings {
+    let docstore_compression: Compressor = match index_settings.docstore_compressor {
+        DocstoreCompressorConfig::None => Compressor::None,
+        DocstoreCompressorConfig::Lz4 => Compressor::Lz4,
+        DocstoreCompressorConfig::Zstd { level } => Compressor::Zstd(ZstdCompressor {
+            compression_level: level,
+        }),
     };
+    // Keep docs in each segment ordered by row_id, so that row_id range queries can stop early.
+    let sort_by_field: Option<IndexSortByField> = match index_settings.sort_by_row_id {
+        true => Some(IndexSortByField {
+            field: "row_id".to_string(),
+            order: Order::Asc,
+        }),
+        false => None,
+    };
+    IndexSettings {
+        sort_by_field,
+        docstore_compression,
+        docstore_blocksize: index_settings.docstore_block_size,
+        ..IndexSettings::default()
+    }
+}
 
-    DEBUG!(function:"create_index_with_parameter", "parameter DTO:{:?}", index_parameter_dto);
-
-    IndexUtils::save_custom_index_setting(index_files_directory, &index_parameter_dto)?;
-
-    // Parse tokenizer map from local index parameter DTO.
-    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
-        TokenizerUtils::parse_tokenizer_json_to_config_map(
-            &index_parameter_dto.tokenizers_json_parameter,
+// Create index writer with writer threads, memory budget and merge policy in `index_settings`.
+fn create_index_writer(
+    function_name: &str,
+    index: &Index,
+    index_settings: &IndexSettingsConfig,
+) -> Result<IndexWriter, TantivySearchError> {
+    let writer = index
+        .writer_with_num_threads(
+            index_settings.writer_num_threads,
+            index_settings.writer_memory_budget,
         )
         .map_err(|e| {
-            ERROR!("{}", e.to_string());
-            TantivySearchError::TokenizerUtilsError(e)
+            let error_info = format!("Failed to create tantivy writer: {}", e);
+            ERROR!(function: function_name, "{}", error_info);
+            TantivySearchError::TantivyError(e)
         })?;
 
+    // Configure and set the merge policy.
+    writer.set_merge_policy(build_merge_policy(&index_settings.merge_policy));
+    DEBUG!(function: function_name, "index writer created with settings: {:?}", index_settings);
+
+    Ok(writer)
+}
+
+// Build index schema with `column_names`, column options are decided by `col_tokenizer_map`.
+fn build_index_schema(
+    column_names: &Vec<String>,
+    col_tokenizer_map: &HashMap<String, TokenizerConfig>,
+) -> Schema {
     // Construct the schema for the index.
     let mut schema_builder = Schema::builder();
     schema_builder.add_u64_field("row_id", FAST | INDEXED);
+    add_column_fields(&mut schema_builder, column_names, col_tokenizer_map);
+    schema_builder.build()
+}
 
+// Add fields of `column_names` to `schema_builder`, options are decided by `col_tokenizer_map`.
+fn add_column_fields(
+    schema_builder: &mut SchemaBuilder,
+    column_names: &Vec<String>,
+    col_tokenizer_map: &HashMap<String, TokenizerConfig>,
+) {
     for column_name in column_names {
         if let Some(tokenizer_config) = col_tokenizer_map.get(column_name) {
             if !tokenizer_config.is_text_field {
@@ -93,7 +170,26 @@ pub fn create_index_with_parameter(
                             numeric_options = numeric_options.set_coerce();
                         }
                         schema_builder.add_i64_field(&column_name, numeric_options);
-                        INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, "I64");
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "I64");
+                        continue;
+                    }
+                    TokenizerType::U64(_) => {
+                        let mut numeric_options = NumericOptions::default();
+                        if tokenizer_config.doc_store {
+                            numeric_options = numeric_options.set_stored();
+                        }
+                        if tokenizer_config.doc_index {
+                            numeric_options = numeric_options.set_fieldnorm();
+                            numeric_options = numeric_options.set_indexed();
+                        }
+                        if tokenizer_config.doc_fast {
+                            numeric_options = numeric_options.set_fast();
+                        }
+                        if tokenizer_config.doc_coerce {
+                            numeric_options = numeric_options.set_coerce();
+                        }
+                        schema_builder.add_u64_field(&column_name, numeric_options);
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "U64");
                         continue;
                     }
                     TokenizerType::F64(_) => {
@@ -112,7 +208,7 @@ pub fn create_index_with_parameter(
                             numeric_options = numeric_options.set_coerce();
                         }
                         schema_builder.add_f64_field(&column_name, numeric_options);
-                        INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, "F64");
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "F64");
                         continue;
                     }
                     TokenizerType::Bytes(_) => {
@@ -129,7 +225,7 @@ pub fn create_index_with_parameter(
                         }
 
                         schema_builder.add_bytes_field(&column_name, bytes_options);
-                        INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, "Bytes");
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "Bytes");
                         continue;
                     }
                     TokenizerType::DateTime(_) => {
@@ -145,7 +241,7 @@ pub fn create_index_with_parameter(
                             date_options = date_options.set_fast();
                         }
                         schema_builder.add_date_field(&column_name, date_options);
-                        INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, "Datetime");
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "Datetime");
                         continue;
                     }
                     TokenizerType::Bool(_) => {
@@ -164,11 +260,45 @@ pub fn create_index_with_parameter(
                             numeric_options = numeric_options.set_coerce();
                         }
                         schema_builder.add_bool_field(&column_name, numeric_options);
-                        INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, "Bool");
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "Bool");
+                        continue;
+                    }
+                    TokenizerType::Ip(_) => {
+                        let mut ip_options = IpAddrOptions::default();
+                        if tokenizer_config.doc_store {
+                            ip_options = ip_options.set_stored();
+                        }
+                        if tokenizer_config.doc_index {
+                            ip_options = ip_options.set_fieldnorms();
+                            ip_options = ip_options.set_indexed();
+                        }
+                        if tokenizer_config.doc_fast {
+                            ip_options = ip_options.set_fast();
+                        }
+                        schema_builder.add_ip_addr_field(&column_name, ip_options);
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, "Ip");
+                        continue;
+                    }
+                    TokenizerType::Json(_) => {
+                        let tokenizer_name =
+                            format!("{}_{}", column_name, tokenizer_config.tokenizer_type.name());
+                        let mut json_options = JsonObjectOptions::default().set_indexing_options(
+                            TextFieldIndexing::default()
+                                .set_tokenizer(&tokenizer_name)
+                                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+                        );
+                        if tokenizer_config.doc_store {
+                            json_options = json_options.set_stored();
+                        }
+                        if tokenizer_config.doc_expand_dots {
+                            json_options = json_options.set_expand_dots_enabled();
+                        }
+                        schema_builder.add_json_field(&column_name, json_options);
+                        INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, tokenizer_name);
                         continue;
                     }
                     _ => {
-                        WARNING!(function:"create_index_with_parameter", "column_name:{}, tokenizer_type:{}, is_text_field:{}",
+                        WARNING!(function:"build_index_schema", "column_name:{}, tokenizer_type:{}, is_text_field:{}",
                             column_name, tokenizer_config.tokenizer_type.name(), tokenizer_config.is_text_field);
                         continue;
                     }
@@ -187,34 +317,153 @@ pub fn create_index_with_parameter(
                 text_options = text_options.set_stored();
             }
 
-            INFO!(function:"create_index_with_parameter", "column_name:{}, field_options name: {}", column_name, tokenizer_name);
+            INFO!(function:"build_index_schema", "column_name:{}, field_options name: {}", column_name, tokenizer_name);
             schema_builder.add_text_field(&column_name, text_options);
         } else {
-            WARNING!(function:"create_index_with_parameter with no_json_schema", "column_name:{}, field_options name: {}", column_name, "TEXT");
+            WARNING!(function:"build_index_schema with no_json_schema", "column_name:{}, field_options name: {}", column_name, "TEXT");
             schema_builder.add_text_field(&column_name, TEXT);
         }
     }
+}
 
-    let schema = schema_builder.build();
+pub fn create_index_with_parameter(
+    index_path: &str,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+) -> Result<bool, TantivySearchError> {
+    // If the `index_path` already exists, it will be recreated,
+    // it's necessary to free any `index_reader` associated with this directory.
+    free_index_reader(index_path).map_err(|e| {
+        ERROR!("{}", e);
+        e
+    })?;
 
-    INFO!(function:"create_index_with_parameter",
-        "index_path:{}, index_json_parameter:{}, col_tokenizer_map size:{}",
+    // If the `index_path` already exists, it will be recreated,
+    // it's necessary to free any `index_writer` associated with this directory.
+    free_index_writer(index_path).map_err(|e| {
+        ERROR!("{}", e);
+        e
+    })?;
+
+    let (index, writer) = create_index_in_directory(
+        "create_index_with_parameter",
         index_path,
+        column_names,
         index_json_parameter,
-        col_tokenizer_map.len()
-    );
+    )?;
+
+    // Save index_writer_bridge to cache.
+    let index_writer_bridge: IndexWriterBridge = IndexWriterBridge {
+        index,
+        path: index_path.trim_end_matches('/').to_string(),
+        writer: Mutex::new(Some(writer)),
+        prepared_payload: Mutex::new(None),
+    };
+
+    FFI_INDEX_WRITER_CACHE
+        .set_index_writer_bridge(index_path.to_string(), Arc::new(index_writer_bridge))
+        .map_err(|e| {
+            ERROR!(function:"create_index_with_parameter", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    Ok(true)
+}
+
+// Create a new tantivy index in `index_path` with json parameter, existing files will be removed.
+// The json parameter is persisted, the returned writer is configured with index level settings.
+fn create_index_in_directory(
+    function_name: &str,
+    index_path: &str,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+) -> Result<(Index, IndexWriter), TantivySearchError> {
+    // Initialize the index directory, it will store tantivy index files.
+    let index_files_directory: &Path = Path::new(index_path);
+    IndexUtils::initialize_index_directory(index_files_directory)?;
+
+    // Save custom index json parameter DTO to index directory,
+    // stop-word and synonyms files are inlined.
+    let index_parameter_dto = IndexParameterDTO {
+        tokenizers_json_parameter: TokenizerUtils::inline_tokenizer_files(index_json_parameter)
+            .map_err(|e| {
+                ERROR!(function: function_name, "{}", e);
+                TantivySearchError::TokenizerUtilsError(e)
+            })?,
+    };
+
+    DEBUG!(function: function_name, "parameter DTO:{:?}", index_parameter_dto);
 
-    // Create the index in the specified directory.
-    let mut index = Index::create_in_dir(index_files_directory, schema).map_err(|e| {
+    IndexUtils::save_custom_index_setting(index_files_directory, &index_parameter_dto)?;
+
+    let directory: MmapDirectory = MmapDirectory::open(index_files_directory).map_err(|e| {
         let error_info = format!(
-            "Failed to create index in directory:{}; exception:{}",
+            "Failed to open index directory:{}; exception:{}",
             index_path,
             e.to_string()
         );
-        ERROR!(function:"create_index_with_parameter", "{}", error_info);
-        TantivySearchError::TantivyError(e)
+        ERROR!(function: function_name, "{}", error_info);
+        TantivySearchError::TantivyError(e.into())
     })?;
 
+    create_index_with_directory(
+        function_name,
+        index_path,
+        Box::new(directory),
+        column_names,
+        &index_parameter_dto,
+    )
+}
+
+// Create a new tantivy index in `directory` with json parameter of `index_parameter_dto`,
+// tokenizers are registered and the returned writer is configured with index level settings.
+fn create_index_with_directory(
+    function_name: &str,
+    index_path: &str,
+    directory: Box<dyn Directory>,
+    column_names: &Vec<String>,
+    index_parameter_dto: &IndexParameterDTO,
+) -> Result<(Index, IndexWriter), TantivySearchError> {
+    // Parse tokenizer map from local index parameter DTO.
+    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
+        TokenizerUtils::parse_tokenizer_json_to_config_map(
+            &index_parameter_dto.tokenizers_json_parameter,
+        )
+        .map_err(|e| {
+            ERROR!(function: function_name, "{}", e.to_string());
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+
+    // Parse index level settings, such as writer threads and merge policy.
+    let index_settings: IndexSettingsConfig =
+        TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)
+            .map_err(|e| {
+            ERROR!(function: function_name, "{}", e.to_string());
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+
+    // Construct the schema for the index.
+    let schema = build_index_schema(column_names, &col_tokenizer_map);
+
+    INFO!(function: function_name,
+        "index_path:{}, index_json_parameter:{}, col_tokenizer_map size:{}",
+        index_path,
+        index_parameter_dto.tokenizers_json_parameter,
+        col_tokenizer_map.len()
+    );
+
+    // Create the index in the given directory.
+    let mut index = Index::create(directory, schema, build_index_settings(&index_settings))
+        .map_err(|e| {
+            let error_info = format!(
+                "Failed to create index in directory:{}; exception:{}",
+                index_path,
+                e.to_string()
+            );
+            ERROR!(function: function_name, "{}", error_info);
+            TantivySearchError::TantivyError(e)
+        })?;
+
     // Register the tokenizer with the index.
     for (col_name, tokenizer_config) in col_tokenizer_map.iter() {
         TokenizerUtils::register_tokenizer_to_index(
@@ -224,110 +473,1743 @@ pub fn create_index_with_parameter(
             tokenizer_config.text_analyzer.clone(),
         )
         .map_err(|e| {
-            ERROR!(function:"create_index_with_parameter", "{}", e.to_string());
+            ERROR!(function: function_name, "{}", e.to_string());
             TantivySearchError::TokenizerUtilsError(e)
         })?;
+        TokenizerUtils::register_query_synonyms_marker(&mut index, &col_name, tokenizer_config);
     }
 
-    // Create the writer with a specified buffer size (e.g., 64 MB).
-    let writer = index
-        .writer_with_num_threads(2, 1024 * 1024 * 64)
+    // Create the writer with index level settings.
+    let writer = create_index_writer(function_name, &index, &index_settings)?;
+
+    Ok((index, writer))
+}
+
+/// Create an in-memory index keyed by `index_name` in writer cache, no file will be written.
+/// It's written, committed and searched like a disk index, its committed segments can be saved
+/// by `persist_ram_index`. The index is dropped once its writer and reader are freed.
+pub fn create_ram_index_with_parameter(
+    index_name: &str,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+) -> Result<bool, TantivySearchError> {
+    // Reader of an in-memory index is found by a missing directory.
+    if Path::new(index_name).exists() {
+        let error_info: String = format!("index_name can't be an existing path: {:?}", index_name);
+        ERROR!(function: "create_ram_index_with_parameter", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    // An in-memory index is searched through its writer with the indexing analyzer,
+    // so synonyms can't be expanded at only one side.
+    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
+        TokenizerUtils::parse_tokenizer_json_to_config_map(index_json_parameter)?;
+    for (column_name, tokenizer_config) in col_tokenizer_map.iter() {
+        if matches!(tokenizer_config.synonym_expand, Some(expand) if expand != SynonymExpand::Both)
+        {
+            let error_info: String = format!(
+                "synonyms of column `{}` must be expanded at both sides in an in-memory index",
+                column_name
+            );
+            ERROR!(function: "create_ram_index_with_parameter", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+    }
+    free_index_reader(index_name)?;
+    free_index_writer(index_name)?;
+
+    let index_parameter_dto = IndexParameterDTO {
+        tokenizers_json_parameter: TokenizerUtils::inline_tokenizer_files(index_json_parameter)
+            .map_err(|e| {
+                ERROR!(function: "create_ram_index_with_parameter", "{}", e);
+                TantivySearchError::TokenizerUtilsError(e)
+            })?,
+    };
+    let (index, writer) = create_index_with_directory(
+        "create_ram_index_with_parameter",
+        index_name,
+        Box::new(RamDirectory::create()),
+        column_names,
+        &index_parameter_dto,
+    )?;
+
+    // Json parameter is kept in RAM directory, it's persisted together with segments.
+    let index_parameter_json = serde_json::to_string(&index_parameter_dto)
+        .map_err(|e| IndexUtilsError::JsonSerializeError(e.to_string()))?;
+    index
+        .directory()
+        .atomic_write(
+            Path::new(INDEX_INFO_FILE_NAME),
+            index_parameter_json.as_bytes(),
+        )
         .map_err(|e| {
-            let error_info = format!("Failed to create tantivy writer: {}", e);
-            ERROR!(function:"create_index_with_parameter", "{}", error_info);
-            TantivySearchError::TantivyError(e)
+            IndexUtilsError::WriteFileError(format!(
+                "file: {}, message: {}",
+                INDEX_INFO_FILE_NAME, e
+            ))
         })?;
 
-    // Configure and set the merge policy.
-    let mut merge_policy = tantivy::merge_policy::LogMergePolicy::default();
-    merge_policy.set_min_num_segments(5);
-    writer.set_merge_policy(Box::new(merge_policy));
-
     // Save index_writer_bridge to cache.
     let index_writer_bridge: IndexWriterBridge = IndexWriterBridge {
         index,
-        path: index_path.trim_end_matches('/').to_string(),
+        path: index_name.trim_end_matches('/').to_string(),
         writer: Mutex::new(Some(writer)),
+        prepared_payload: Mutex::new(None),
     };
 
     FFI_INDEX_WRITER_CACHE
-        .set_index_writer_bridge(index_path.to_string(), Arc::new(index_writer_bridge))
+        .set_index_writer_bridge(index_name.to_string(), Arc::new(index_writer_bridge))
         .map_err(|e| {
-            ERROR!(function:"create_index_with_parameter", "{}", e);
+            ERROR!(function:"create_ram_index_with_parameter", "{}", e);
             TantivySearchError::InternalError(e)
         })?;
 
     Ok(true)
 }
 
-pub fn create_index(
-    index_path: &str,
-    column_names: &Vec<String>,
-) -> Result<bool, TantivySearchError> {
-    create_index_with_parameter(index_path, column_names, "{}")
-}
-
-pub fn index_multi_column_docs(
-    index_path: &str,
-    row_id: u64,
-    column_names: &Vec<String>,
-    column_docs: &Vec<String>,
-) -> Result<bool, TantivySearchError> {
-    // Get index writer from CACHE
-    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
-        .get_index_writer_bridge(index_path.to_string())
+/// Save committed segments of the in-memory index `index_name` as a disk index in `index_path`,
+/// existing files of `index_path` will be removed. The in-memory index is kept.
+pub fn persist_ram_index(index_name: &str, index_path: &str) -> Result<bool, TantivySearchError> {
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_name.to_string())
         .map_err(|e| {
-            ERROR!(function: "index_multi_column_docs", "{}", e);
+            ERROR!(function: "persist_ram_index", "{}", e);
             TantivySearchError::InternalError(e)
         })?;
+    if Path::new(index_name).exists()
+        || index_name.trim_end_matches('/') == index_path.trim_end_matches('/')
+    {
+        let error_info: String = format!(
+            "index_name should be an in-memory index other than index_path: {:?}",
+            index_name
+        );
+        ERROR!(function: "persist_ram_index", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
 
-    // Get schema from index writer.
-    let schema = index_writer_bridge.index.schema();
-    let row_id_field = schema.get_field("row_id").map_err(|e| {
-        ERROR!(function: "index_multi_column_docs", "Failed to get row_id field: {}", e.to_string());
-        TantivySearchError::TantivyError(e)
+    free_index_reader(index_path)?;
+    free_index_writer(index_path)?;
+    let index_meta: IndexMeta = index_writer_bridge.index.load_metas()?;
+    install_index_files(&index_writer_bridge.index, &index_meta, index_path).map_err(|e| {
+        ERROR!(function: "persist_ram_index", "Failed to persist index: {}", e);
+        e
     })?;
+    INFO!(function: "persist_ram_index", "index_name:{}, index_path:{}, opstamp:{}", index_name, index_path, index_meta.opstamp);
 
-    let mut doc = TantivyDocument::default();
-    doc.add_u64(row_id_field, row_id);
+    Ok(true)
+}
 
-    let mut column_idx = 0;
-    for column_name in column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-            ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
-            TantivySearchError::TantivyError(e)
+// Column configs of the json parameter, index level settings are excluded.
+fn column_configs_of_json_parameter(
+    index_json_parameter: &str,
+) -> Result<serde_json::Value, TantivySearchError> {
+    let mut json_value: serde_json::Value =
+        serde_json::from_str(index_json_parameter).map_err(|e| {
+            TantivySearchError::IndexUtilsError(IndexUtilsError::JsonParseError(e.to_string()))
         })?;
-        doc.add_text(column_field, column_docs[column_idx].clone());
-        column_idx += 1;
+    if let Some(json_object) = json_value.as_object_mut() {
+        json_object.remove("index_settings");
+    }
+    Ok(json_value)
+}
+
+// Check whether the existing index is compatible with given columns and json parameter.
+fn check_index_compatible(
+    index_files_directory: &Path,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+) -> Result<(), TantivySearchError> {
+    // Compare column configs with the persisted index parameter.
+    let index_parameter_dto: IndexParameterDTO =
+        IndexUtils::load_custom_index_setting(index_files_directory)?;
+    let stored_column_configs =
+        column_configs_of_json_parameter(&index_parameter_dto.tokenizers_json_parameter)?;
+    let given_column_configs = column_configs_of_json_parameter(
+        &TokenizerUtils::inline_tokenizer_files(index_json_parameter)?,
+    )?;
+    if stored_column_configs != given_column_configs {
+        return Err(TantivySearchError::IncompatibleIndex(format!(
+            "column configs mismatch, stored: {}, given: {}",
+            stored_column_configs, given_column_configs
+        )));
+    }
+    if TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)?
+        != TokenizerUtils::parse_index_settings_json(index_json_parameter)?
+    {
+        WARNING!(function: "check_index_compatible", "index_settings mismatch, the stored one will be used.");
     }
 
-    match index_writer_bridge.add_document(doc) {
-        Ok(_) => Ok(true),
-        Err(e) => {
-            let error_info = format!("Failed to index doc:{}", e);
-            ERROR!(function: "index_multi_column_docs", "{}", error_info);
-            Err(TantivySearchError::InternalError(e))
-        }
+    // Compare schema with the one built from given parameter.
+    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
+        TokenizerUtils::parse_tokenizer_json_to_config_map(index_json_parameter)?;
+    let expected_schema = build_index_schema(column_names, &col_tokenizer_map);
+    let stored_schema = Index::open_in_dir(index_files_directory)?.schema();
+    let expected_fields: Vec<&FieldEntry> =
+        expected_schema.fields().map(|(_, entry)| entry).collect();
+    let stored_fields: Vec<&FieldEntry> = stored_schema.fields().map(|(_, entry)| entry).collect();
+    if expected_fields != stored_fields {
+        return Err(TantivySearchError::IncompatibleIndex(format!(
+            "schema mismatch, stored columns: {:?}, given columns: {:?}",
+            stored_fields
+                .iter()
+                .map(|entry| entry.name())
+                .collect::<Vec<&str>>(),
+            expected_fields
+                .iter()
+                .map(|entry| entry.name())
+                .collect::<Vec<&str>>()
+        )));
     }
+    Ok(())
 }
 
-pub fn index_multi_type_column_docs(
+pub fn open_or_create_index_with_parameter(
     index_path: &str,
-    row_id: u64,
-    text_column_names: &Vec<String>,
-    text_column_docs: &Vec<String>,
-    i64_column_names: &Vec<String>,
-    i64_column_docs: &Vec<i64>,
-    f64_column_names: &Vec<String>,
-    f64_column_docs: &Vec<f64>,
-    bytes_column_names: &Vec<String>,
-    bytes_column_docs: &Vec<Vec<u8>>,
-    date_column_names: &Vec<String>,
-    date_column_docs: &Vec<DateTime>,
-    bool_column_names: &Vec<String>,
-    bool_column_docs: &Vec<bool>,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+    wipe_existing: bool,
 ) -> Result<bool, TantivySearchError> {
-    // Get index writer from CACHE
+    // Only wipe existing index directory when explicitly asked.
+    let index_files_directory: &Path = Path::new(index_path);
+    if wipe_existing || !index_files_directory.join(META_FILE_NAME).exists() {
+        return create_index_with_parameter(index_path, column_names, index_json_parameter);
+    }
+
+    check_index_compatible(index_files_directory, column_names, index_json_parameter).map_err(
+        |e| {
+            ERROR!(function: "open_or_create_index_with_parameter", "index_path:{}, {}", index_path, e);
+            e
+        },
+    )?;
+
+    // Writer may be cached by a previous attempt, free it before reopen.
+    free_index_writer(index_path)?;
+    INFO!(function: "open_or_create_index_with_parameter", "open existing index, index_path:{}", index_path);
+    load_index_writer(index_path)
+}
+
+// Merge column configs of `index_json_parameter` into the stored json parameter.
+fn merge_column_configs(
+    stored_json_parameter: &str,
+    index_json_parameter: &str,
+) -> Result<String, TantivySearchError> {
+    let parse_json_object =
+        |json_parameter: &str| match serde_json::from_str::<serde_json::Value>(json_parameter) {
+            Ok(serde_json::Value::Object(json_object)) => Ok(json_object),
+            Ok(_) => Err(TantivySearchError::InvalidArgument(format!(
+                "index json parameter should be a json object: {}",
+                json_parameter
+            ))),
+            Err(e) => Err(TantivySearchError::IndexUtilsError(
+                IndexUtilsError::JsonParseError(e.to_string()),
+            )),
+        };
+    let mut stored_json_object = parse_json_object(stored_json_parameter)?;
+    for (column_name, column_config) in parse_json_object(index_json_parameter)? {
+        if column_name == "index_settings" {
+            return Err(TantivySearchError::InvalidArgument(
+                "index_settings can't be changed when adding columns".to_string(),
+            ));
+        }
+        if stored_json_object.contains_key(&column_name) {
+            return Err(TantivySearchError::InvalidArgument(format!(
+                "column `{}` already has a tokenizer config",
+                column_name
+            )));
+        }
+        stored_json_object.insert(column_name, column_config);
+    }
+    Ok(serde_json::Value::Object(stored_json_object).to_string())
+}
+
+// Copy `source_file` of `index` to `target_file` through the managed directory, so that the copy
+// can be garbage collected later. Managed directory strips the footer on read and appends it on
+// write, so only the file body is copied.
+fn copy_index_file(
+    index: &Index,
+    source_file: &Path,
+    target_file: &Path,
+) -> Result<(), TantivySearchError> {
+    let content = index
+        .directory()
+        .open_read(source_file)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+        .read_bytes()
+        .map_err(|e| {
+            IndexUtilsError::ReadFileError(format!("file: {:?}, message: {}", source_file, e))
+        })?;
+    let mut writer = index
+        .directory()
+        .open_write(target_file)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?;
+    writer
+        .write_all(content.as_slice())
+        .and_then(|_| writer.terminate())
+        .map_err(|e| {
+            IndexUtilsError::WriteFileError(format!("file: {:?}, message: {}", target_file, e))
+        })?;
+    Ok(())
+}
+
+// Rewrite existing segments with `new_schema` under new segment ids, new fields get zero
+// fieldnorms. Tantivy requires fieldnorm data of every indexed field when merging segments.
+// Files of existing segments aren't changed, they are replaced once meta.json is written with
+// the returned segments, and garbage collected by the writer later.
+fn rewrite_segments_with_fieldnorms(
+    index: &Index,
+    index_metas: &IndexMeta,
+    new_schema: &Schema,
+) -> Result<Vec<SegmentMeta>, TantivySearchError> {
+    let searcher = index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::Manual)
+        .try_into()?
+        .searcher();
+    let mut rewritten_segments: Vec<SegmentMeta> = Vec::new();
+    for segment_meta in index_metas.segments.iter() {
+        let segment_reader = match searcher
+            .segment_readers()
+            .iter()
+            .find(|segment_reader| segment_reader.segment_id() == segment_meta.id())
+        {
+            Some(segment_reader) => segment_reader,
+            None => {
+                rewritten_segments.push(segment_meta.clone());
+                continue;
+            }
+        };
+        let max_doc: DocId = segment_reader.max_doc();
+        let mut rewritten_segment: SegmentMeta =
+            index.new_segment_meta(SegmentId::generate_random(), max_doc);
+        if let Some(delete_opstamp) = segment_meta.delete_opstamp() {
+            rewritten_segment =
+                rewritten_segment.with_delete_meta(segment_meta.num_deleted_docs(), delete_opstamp);
+        }
+
+        // Other components are copied as they are.
+        for component in SegmentComponent::iterator() {
+            let source_file: PathBuf = segment_meta.relative_path(*component);
+            if *component == SegmentComponent::FieldNorms
+                || !index
+                    .directory()
+                    .exists(&source_file)
+                    .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+            {
+                continue;
+            }
+            copy_index_file(index, &source_file, &rewritten_segment.relative_path(*component))?;
+        }
+
+        let mut fieldnorms_writer = FieldNormsWriter::for_schema(new_schema);
+        for (field, _) in new_schema.fields() {
+            if let Some(fieldnorm_reader) = segment_reader.fieldnorms_readers().get_field(field)? {
+                for doc_id in 0..max_doc {
+                    fieldnorms_writer.record(doc_id, field, fieldnorm_reader.fieldnorm(doc_id));
+                }
+            }
+        }
+        fieldnorms_writer.fill_up_to_max_doc(max_doc);
+        let fieldnorm_path: PathBuf = rewritten_segment.relative_path(SegmentComponent::FieldNorms);
+        let fieldnorm_write = index
+            .directory()
+            .open_write(&fieldnorm_path)
+            .map_err(|e| TantivySearchError::TantivyError(e.into()))?;
+        FieldNormsSerializer::from_write(fieldnorm_write)
+            .and_then(|fieldnorms_serializer| {
+                fieldnorms_writer.serialize(fieldnorms_serializer, None)
+            })
+            .map_err(|e| {
+                TantivySearchError::IndexUtilsError(IndexUtilsError::WriteFileError(format!(
+                    "file: {:?}, message: {}",
+                    fieldnorm_path, e
+                )))
+            })?;
+        rewritten_segments.push(rewritten_segment);
+    }
+    Ok(rewritten_segments)
+}
+
+/// Add new columns to an existing index, existing documents don't have these columns.
+/// Pending documents are committed first, the writer (and reader if loaded) will be reopened.
+pub fn add_columns_to_index(
+    index_path: &str,
+    column_names: &Vec<String>,
+    index_json_parameter: &str,
+) -> Result<bool, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "add_columns_to_index", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    if column_names.is_empty() {
+        return Err(TantivySearchError::InvalidArgument(
+            "column_names can't be empty".to_string(),
+        ));
+    }
+
+    // Validate and merge tokenizer configs of new columns.
+    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
+        TokenizerUtils::parse_tokenizer_json_to_config_map(index_json_parameter).map_err(|e| {
+            ERROR!(function: "add_columns_to_index", "{}", e);
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+    let mut index_parameter_dto: IndexParameterDTO =
+        IndexUtils::load_custom_index_setting(index_files_directory)?;
+    index_parameter_dto.tokenizers_json_parameter = merge_column_configs(
+        &index_parameter_dto.tokenizers_json_parameter,
+        &TokenizerUtils::inline_tokenizer_files(index_json_parameter)?,
+    )
+    .map_err(|e| {
+        ERROR!(function: "add_columns_to_index", "{}", e);
+        e
+    })?;
+
+    let stored_schema: Schema = Index::open_in_dir(index_files_directory)?.schema();
+    for column_name in column_names {
+        if stored_schema.get_field(column_name).is_ok()
+            || column_names
+                .iter()
+                .filter(|name| *name == column_name)
+                .count()
+                > 1
+        {
+            let error_info: String = format!("column `{}` already exists", column_name);
+            ERROR!(function: "add_columns_to_index", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+    }
+
+    // Commit pending documents, then release writer and reader of the old schema.
+    if FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .is_ok()
+    {
+        commit_index(index_path)?;
+    }
+    free_index_writer(index_path)?;
+    let reader_loaded = free_index_reader(index_path)?;
+
+    // New fields are appended, field ids of existing columns stay the same.
+    let index: Index = Index::open_in_dir(index_files_directory)?;
+    let mut index_metas = index.load_metas()?;
+    let mut schema_builder = Schema::builder();
+    for (_, field_entry) in index_metas.schema.fields() {
+        schema_builder.add_field(field_entry.clone());
+    }
+    add_column_fields(&mut schema_builder, column_names, &col_tokenizer_map);
+    let new_schema: Schema = schema_builder.build();
+
+    // Existing segments are rewritten with fieldnorms of new columns under new segment ids,
+    // they replace the old ones together with the new schema by the atomic meta.json write.
+    index_metas.segments = rewrite_segments_with_fieldnorms(&index, &index_metas, &new_schema)
+        .map_err(|e| {
+            ERROR!(function: "add_columns_to_index", "Failed to rewrite segment fieldnorms: {}", e);
+            e
+        })?;
+    index_metas.schema = new_schema;
+
+    // Tokenizers of new columns must be persisted before they appear in schema,
+    // the stored setting is rolled back if meta.json can't be written.
+    let stored_index_parameter_dto: IndexParameterDTO =
+        IndexUtils::load_custom_index_setting(index_files_directory)?;
+    IndexUtils::save_custom_index_setting(index_files_directory, &index_parameter_dto)?;
+    let write_index_metas = || -> Result<(), TantivySearchError> {
+        let index_metas_json = serde_json::to_string_pretty(&index_metas).map_err(|e| {
+            TantivySearchError::IndexUtilsError(IndexUtilsError::JsonSerializeError(e.to_string()))
+        })?;
+        index
+            .directory()
+            .atomic_write(Path::new(META_FILE_NAME), index_metas_json.as_bytes())
+            .map_err(|e| {
+                let error_info: String = format!("Failed to write {}: {}", META_FILE_NAME, e);
+                TantivySearchError::IndexUtilsError(IndexUtilsError::WriteFileError(error_info))
+            })
+    };
+    if let Err(e) = write_index_metas() {
+        ERROR!(function: "add_columns_to_index", "{}", e);
+        if let Err(rollback_error) =
+            IndexUtils::save_custom_index_setting(index_files_directory, &stored_index_parameter_dto)
+        {
+            ERROR!(function: "add_columns_to_index", "Failed to roll back index setting: {}", rollback_error);
+        }
+        return Err(e);
+    }
+    INFO!(function: "add_columns_to_index", "index_path:{}, added columns:{:?}", index_path, column_names);
+
+    load_index_writer(index_path)?;
+    if reader_loaded {
+        load_index_reader(index_path)?;
+    }
+    Ok(true)
+}
+
+// Update reindex progress of `index_path`.
+fn set_reindex_progress(index_path: &str, reindexed_docs: u64, total_docs: u64) {
+    match REINDEX_PROGRESS.lock() {
+        Ok(mut progress) => {
+            progress.insert(index_path.to_string(), (reindexed_docs, total_docs, false));
+        }
+        Err(e) => {
+            WARNING!(function: "set_reindex_progress", "Lock error: {}", e);
+        }
+    }
+}
+
+// Mark reindex progress of `index_path` as finished, no matter succeeded or failed.
+fn finish_reindex_progress(index_path: &str) {
+    match REINDEX_PROGRESS.lock() {
+        Ok(mut progress) => {
+            if let Some((_, _, finished)) = progress.get_mut(index_path) {
+                *finished = true;
+            }
+        }
+        Err(e) => {
+            WARNING!(function: "finish_reindex_progress", "Lock error: {}", e);
+        }
+    }
+}
+
+// Copy alive stored documents of `source_index` into `target_writer`, row_ids are kept.
+fn copy_stored_documents(
+    function_name: &str,
+    index_path: &str,
+    source_index: &Index,
+    target_schema: &Schema,
+    target_writer: &IndexWriter,
+) -> Result<u64, TantivySearchError> {
+    let source_schema: Schema = source_index.schema();
+    let searcher = source_index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::Manual)
+        .try_into()?
+        .searcher();
+    let total_docs: u64 = searcher.num_docs();
+    let target_row_id_field: Field = target_schema.get_field("row_id")?;
+    let mut reindexed_docs: u64 = 0;
+    set_reindex_progress(index_path, reindexed_docs, total_docs);
+
+    for segment_reader in searcher.segment_readers() {
+        let row_id_column = segment_reader.fast_fields().u64("row_id")?;
+        let store_reader = segment_reader.get_store_reader(0)?;
+        for doc_id in segment_reader.doc_ids_alive() {
+            let row_id: u64 = row_id_column.first(doc_id).ok_or_else(|| {
+                let error_info: String = format!("row_id of doc {} not found", doc_id);
+                ERROR!(function: function_name, "{}", error_info);
+                TantivySearchError::InternalError(error_info)
+            })?;
+            let source_doc: TantivyDocument = store_reader.get(doc_id)?;
+            let mut target_doc = TantivyDocument::default();
+            target_doc.add_u64(target_row_id_field, row_id);
+            for field_value in source_doc.field_values() {
+                let column_name: &str = source_schema.get_field_name(field_value.field());
+                target_doc.add_field_value(
+                    target_schema.get_field(column_name)?,
+                    field_value.value().clone(),
+                );
+            }
+            target_writer.add_document(target_doc)?;
+
+            reindexed_docs += 1;
+            if reindexed_docs % 10000 == 0 {
+                set_reindex_progress(index_path, reindexed_docs, total_docs);
+            }
+        }
+    }
+    set_reindex_progress(index_path, reindexed_docs, total_docs);
+    Ok(reindexed_docs)
+}
+
+/// Rebuild an index with a new json parameter from its stored documents, row_ids are kept.
+/// All columns must be stored, and column types can't be changed. The new index is built in
+/// a sibling directory `{index_path}.reindex`, then swapped with the old one.
+/// Writer and reader are released during reindex, and reloaded afterwards even if failed.
+pub fn reindex_with_parameter(
+    index_path: &str,
+    index_json_parameter: &str,
+) -> Result<u64, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "reindex_with_parameter", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+
+    // Stored documents are the source of reindex, commit pending documents first.
+    // The writer is released, so documents can't be added while they are copied.
+    let writer_loaded: bool = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .is_ok();
+    if writer_loaded {
+        commit_index(index_path)?;
+    }
+    free_index_writer(index_path)?;
+    let reader_loaded: bool = free_index_reader(index_path)?;
+
+    let reindex_result = rebuild_index_with_parameter(index_path, index_json_parameter);
+    finish_reindex_progress(index_path);
+
+    // Either the old or the new index is in place, reload what has been released.
+    let mut reload_result: Result<bool, TantivySearchError> = Ok(true);
+    if writer_loaded {
+        reload_result = load_index_writer(index_path);
+    }
+    if reader_loaded && reload_result.is_ok() {
+        reload_result = load_index_reader(index_path);
+    }
+    let reindexed_docs: u64 = reindex_result?;
+    reload_result?;
+    Ok(reindexed_docs)
+}
+
+// Build the reindexed index in a sibling directory and swap it with `index_path`,
+// writer and reader of `index_path` must have been released.
+fn rebuild_index_with_parameter(
+    index_path: &str,
+    index_json_parameter: &str,
+) -> Result<u64, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    let source_index: Index = Index::open_in_dir(index_files_directory)?;
+    let source_schema: Schema = source_index.schema();
+    let mut column_names: Vec<String> = Vec::new();
+    for (_, field_entry) in source_schema.fields() {
+        if field_entry.name() == "row_id" {
+            continue;
+        }
+        if !field_entry.is_stored() {
+            let error_info: String = format!(
+                "column `{}` isn't stored, can't be reindexed",
+                field_entry.name()
+            );
+            ERROR!(function: "reindex_with_parameter", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+        column_names.push(field_entry.name().to_string());
+    }
+
+    // Stored values can only be indexed again with the same column type.
+    let col_tokenizer_map: HashMap<String, TokenizerConfig> =
+        TokenizerUtils::parse_tokenizer_json_to_config_map(index_json_parameter)?;
+    let target_schema: Schema = build_index_schema(&column_names, &col_tokenizer_map);
+    for column_name in column_names.iter() {
+        let source_type = source_schema
+            .get_field_entry(source_schema.get_field(column_name)?)
+            .field_type()
+            .value_type();
+        let target_type = target_schema
+            .get_field_entry(target_schema.get_field(column_name)?)
+            .field_type()
+            .value_type();
+        if source_type != target_type {
+            let error_info: String = format!(
+                "column `{}` type can't be changed from {:?} to {:?}",
+                column_name, source_type, target_type
+            );
+            ERROR!(function: "reindex_with_parameter", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+    }
+
+    // Build the new index in a sibling directory.
+    let trimmed_index_path: &str = index_path.trim_end_matches('/');
+    let reindex_path: String = format!("{}.reindex", trimmed_index_path);
+    let build_result = create_index_in_directory(
+        "reindex_with_parameter",
+        &reindex_path,
+        &column_names,
+        index_json_parameter,
+    )
+    .and_then(|(target_index, mut target_writer)| {
+        let reindexed_docs = copy_stored_documents(
+            "reindex_with_parameter",
+            index_path,
+            &source_index,
+            &target_index.schema(),
+            &target_writer,
+        )?;
+        target_writer.commit()?;
+        target_writer.wait_merging_threads()?;
+        Ok(reindexed_docs)
+    });
+    let reindexed_docs: u64 = match build_result {
+        Ok(reindexed_docs) => reindexed_docs,
+        Err(e) => {
+            ERROR!(function: "reindex_with_parameter", "Failed to build reindex directory: {}", e);
+            let _ = fs::remove_dir_all(&reindex_path);
+            return Err(e);
+        }
+    };
+
+    // Swap the new index with the old one, old one is restored if failed.
+    drop(source_index);
+    swap_index_directory("reindex_with_parameter", index_path, &reindex_path)?;
+    INFO!(function: "reindex_with_parameter", "index_path:{}, reindexed docs:{}", index_path, reindexed_docs);
+    Ok(reindexed_docs)
+}
+
+// Swap the index built in sibling directory `new_path` with `index_path`, the old index is
+// restored if failed. `new_path` is removed if it can't be swapped in.
+fn swap_index_directory(
+    function_name: &str,
+    index_path: &str,
+    new_path: &str,
+) -> Result<(), TantivySearchError> {
+    let trimmed_index_path: &str = index_path.trim_end_matches('/');
+    if !Path::new(trimmed_index_path).exists() {
+        return fs::rename(new_path, trimmed_index_path).map_err(|e| {
+            let error_info: String = format!("Failed to rename {}: {}", new_path, e);
+            ERROR!(function: function_name, "{}", error_info);
+            let _ = fs::remove_dir_all(new_path);
+            TantivySearchError::IndexUtilsError(IndexUtilsError::DirectoryIOError(error_info))
+        });
+    }
+    let backup_path: String = format!("{}_backup", new_path);
+    fs::rename(trimmed_index_path, &backup_path).map_err(|e| {
+        let error_info: String = format!("Failed to backup {}: {}", index_path, e);
+        ERROR!(function: function_name, "{}", error_info);
+        let _ = fs::remove_dir_all(new_path);
+        TantivySearchError::IndexUtilsError(IndexUtilsError::DirectoryIOError(error_info))
+    })?;
+    if let Err(e) = fs::rename(new_path, trimmed_index_path) {
+        let _ = fs::rename(&backup_path, trimmed_index_path);
+        let _ = fs::remove_dir_all(new_path);
+        let error_info: String = format!("Failed to swap {}: {}", new_path, e);
+        ERROR!(function: function_name, "{}", error_info);
+        return Err(TantivySearchError::IndexUtilsError(
+            IndexUtilsError::DirectoryIOError(error_info),
+        ));
+    }
+    if let Err(e) = fs::remove_dir_all(&backup_path) {
+        WARNING!(function: function_name, "Failed to remove {}: {}", backup_path, e);
+    }
+    Ok(())
+}
+
+/// Get reindex progress of `index_path`, returns (reindexed docs, total docs, finished).
+pub fn get_reindex_progress(index_path: &str) -> Result<(u64, u64, bool), TantivySearchError> {
+    let progress = REINDEX_PROGRESS.lock().map_err(|e| {
+        let error_info: String = format!("Lock error: {}", e);
+        ERROR!(function: "get_reindex_progress", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    progress.get(index_path).copied().ok_or_else(|| {
+        let error_info: String = format!("No reindex progress of index_path: {}", index_path);
+        ERROR!(function: "get_reindex_progress", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })
+}
+
+// Load committed index meta of `index_path`. Segment metas loaded from the writer's index are
+// tracked by its inventory, files of alive segment metas won't be garbage collected.
+fn load_pinnable_index_meta(index_path: &str) -> Result<IndexMeta, TantivySearchError> {
+    let index: Index = match FFI_INDEX_WRITER_CACHE.get_index_writer_bridge(index_path.to_string())
+    {
+        Ok(index_writer_bridge) => index_writer_bridge.index.clone(),
+        Err(_) => Index::open_in_dir(Path::new(index_path))?,
+    };
+    Ok(index.load_metas()?)
+}
+
+// Existing segment files of `index_meta` in `index_files_directory`, sorted by file name.
+fn list_segment_files(index_files_directory: &Path, index_meta: &IndexMeta) -> Vec<String> {
+    let mut segment_files: Vec<String> = index_meta
+        .segments
+        .iter()
+        .flat_map(|segment_meta| segment_meta.list_files())
+        .filter(|file| index_files_directory.join(file).exists())
+        .map(|file| file.to_string_lossy().to_string())
+        .collect();
+    segment_files.sort();
+    segment_files
+}
+
+// Hard link `source` to `target`, fall back to copy if hard link isn't supported.
+fn link_or_copy_file(source: &Path, target: &Path) -> std::io::Result<()> {
+    fs::hard_link(source, target).or_else(|_| fs::copy(source, target).map(|_| ()))
+}
+
+// Copy `file_name` of `source_index` into managed directory of `index`, so it can be garbage
+// collected later. Managed directory strips the footer on read and appends it on write, so only
+// the file body is copied.
+fn copy_file_into_index(
+    source_index: &Index,
+    index: &Index,
+    file_name: &str,
+) -> Result<(), TantivySearchError> {
+    let file_path: &Path = Path::new(file_name);
+    let content = source_index
+        .directory()
+        .open_read(file_path)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+        .read_bytes()
+        .map_err(|e| {
+            IndexUtilsError::ReadFileError(format!("file: {:?}, message: {}", file_name, e))
+        })?;
+    let mut writer = index
+        .directory()
+        .open_write(file_path)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?;
+    writer
+        .write_all(content.as_slice())
+        .and_then(|_| writer.terminate())
+        .map_err(|e| {
+            IndexUtilsError::WriteFileError(format!("file: {:?}, message: {}", file_name, e))
+        })?;
+    Ok(())
+}
+
+// Write segment files of `index_meta`, meta.json and custom index setting into `snapshot_dir`.
+fn write_snapshot_files(
+    index_path: &str,
+    snapshot_dir: &str,
+    index_meta: &IndexMeta,
+) -> Result<(), TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    let snapshot_directory: &Path = Path::new(snapshot_dir);
+    fs::create_dir_all(snapshot_directory).map_err(|e| {
+        IndexUtilsError::CreateDirectoryError(format!(
+            "path: {:?}, message: {}",
+            snapshot_directory, e
+        ))
+    })?;
+
+    for file in list_segment_files(index_files_directory, index_meta) {
+        link_or_copy_file(
+            &index_files_directory.join(&file),
+            &snapshot_directory.join(&file),
+        )
+        .map_err(|e| {
+            IndexUtilsError::WriteFileError(format!("file: {:?}, message: {}", file, e))
+        })?;
+    }
+
+    // meta.json only contains pinned segments, later commits are excluded.
+    let index_meta_json = serde_json::to_string_pretty(index_meta)
+        .map_err(|e| IndexUtilsError::JsonSerializeError(e.to_string()))?;
+    fs::write(snapshot_directory.join(META_FILE_NAME), index_meta_json).map_err(|e| {
+        IndexUtilsError::WriteFileError(format!("file: {}, message: {}", META_FILE_NAME, e))
+    })?;
+    fs::copy(
+        index_files_directory.join(INDEX_INFO_FILE_NAME),
+        snapshot_directory.join(INDEX_INFO_FILE_NAME),
+    )
+    .map_err(|e| {
+        IndexUtilsError::WriteFileError(format!("file: {}, message: {}", INDEX_INFO_FILE_NAME, e))
+    })?;
+    Ok(())
+}
+
+/// Create a point-in-time snapshot of committed segments into `snapshot_dir`, returns its opstamp.
+/// Segment files are pinned until `release_snapshot`, merges of the loaded writer can't
+/// garbage collect them. `snapshot_dir` can't exist before.
+pub fn create_snapshot(index_path: &str, snapshot_dir: &str) -> Result<u64, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "create_snapshot", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    let snapshot_key: String = snapshot_dir.trim_end_matches('/').to_string();
+    if Path::new(snapshot_dir).exists() {
+        let error_info: String = format!("snapshot_dir already exists: {:?}", snapshot_dir);
+        ERROR!(function: "create_snapshot", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+
+    let index_meta: IndexMeta = load_pinnable_index_meta(index_path)?;
+    let opstamp: Opstamp = index_meta.opstamp;
+
+    let mut snapshot_pins = SNAPSHOT_PINS.lock().map_err(|e| {
+        let error_info: String = format!("Lock error: {}", e);
+        ERROR!(function: "create_snapshot", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    if snapshot_pins.contains_key(&snapshot_key) {
+        let error_info: String = format!("snapshot_dir already pinned: {:?}", snapshot_dir);
+        ERROR!(function: "create_snapshot", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    if let Err(e) = write_snapshot_files(index_path, snapshot_dir, &index_meta) {
+        ERROR!(function: "create_snapshot", "Failed to write snapshot files: {}", e);
+        let _ = fs::remove_dir_all(snapshot_dir);
+        return Err(e);
+    }
+    snapshot_pins.insert(snapshot_key, index_meta);
+    INFO!(function: "create_snapshot", "index_path:{}, snapshot_dir:{}, opstamp:{}", index_path, snapshot_dir, opstamp);
+
+    Ok(opstamp)
+}
+
+/// Unpin segment files of the snapshot in `snapshot_dir`, files in `snapshot_dir` are kept.
+/// Returns false if the snapshot isn't pinned.
+pub fn release_snapshot(snapshot_dir: &str) -> Result<bool, TantivySearchError> {
+    let mut snapshot_pins = SNAPSHOT_PINS.lock().map_err(|e| {
+        let error_info: String = format!("Lock error: {}", e);
+        ERROR!(function: "release_snapshot", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    if snapshot_pins
+        .remove(snapshot_dir.trim_end_matches('/'))
+        .is_none()
+    {
+        DEBUG!(function: "release_snapshot", "Snapshot not pinned: {}", snapshot_dir);
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+// Install committed `index_meta` of `source_index` as a new index in `target_path`, existing
+// files of `target_path` will be removed. Segment files are registered in managed directory
+// of the new index, so they can be garbage collected later.
+fn install_index_files(
+    source_index: &Index,
+    index_meta: &IndexMeta,
+    target_path: &str,
+) -> Result<Index, TantivySearchError> {
+    let target_directory: &Path = Path::new(target_path);
+    IndexUtils::initialize_index_directory(target_directory)?;
+
+    let index_meta_json = serde_json::to_string_pretty(index_meta)
+        .map_err(|e| IndexUtilsError::JsonSerializeError(e.to_string()))?;
+    fs::write(target_directory.join(META_FILE_NAME), index_meta_json).map_err(|e| {
+        IndexUtilsError::WriteFileError(format!("file: {}, message: {}", META_FILE_NAME, e))
+    })?;
+    let index_parameter_json: Vec<u8> = source_index
+        .directory()
+        .atomic_read(Path::new(INDEX_INFO_FILE_NAME))
+        .map_err(|e| {
+            IndexUtilsError::ReadFileError(format!(
+                "file: {}, message: {}",
+                INDEX_INFO_FILE_NAME, e
+            ))
+        })?;
+    fs::write(
+        target_directory.join(INDEX_INFO_FILE_NAME),
+        index_parameter_json,
+    )
+    .map_err(|e| {
+        IndexUtilsError::WriteFileError(format!("file: {}, message: {}", INDEX_INFO_FILE_NAME, e))
+    })?;
+
+    let index: Index = Index::open_in_dir(target_directory)?;
+    let mut segment_files: Vec<PathBuf> = index_meta
+        .segments
+        .iter()
+        .flat_map(|segment_meta| segment_meta.list_files())
+        .collect();
+    segment_files.sort();
+    for file in segment_files {
+        if source_index
+            .directory()
+            .exists(&file)
+            .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+        {
+            copy_file_into_index(source_index, &index, &file.to_string_lossy())?;
+        }
+    }
+    Ok(index)
+}
+
+/// Install the snapshot in `snapshot_dir` as the index in `index_path`, existing files of
+/// `index_path` are replaced only if the snapshot is installed. The writer (and reader if
+/// loaded) will be reopened, even if restoring fails.
+pub fn restore_snapshot(snapshot_dir: &str, index_path: &str) -> Result<bool, TantivySearchError> {
+    let snapshot_directory: &Path = Path::new(snapshot_dir);
+    if !snapshot_directory.join(META_FILE_NAME).exists()
+        || !snapshot_directory.join(INDEX_INFO_FILE_NAME).exists()
+    {
+        let error_info: String = format!("snapshot_dir isn't a valid snapshot: {:?}", snapshot_dir);
+        ERROR!(function: "restore_snapshot", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    if snapshot_dir.trim_end_matches('/') == index_path.trim_end_matches('/') {
+        let error_info: String = format!("snapshot_dir can't be index_path: {:?}", index_path);
+        ERROR!(function: "restore_snapshot", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+
+    let writer_loaded: bool = free_index_writer(index_path)?;
+    let reader_loaded: bool = free_index_reader(index_path)?;
+
+    let restore_result = install_snapshot(snapshot_directory, index_path);
+
+    // Either the old index or the snapshot is in place, reload what has been released.
+    let mut reload_result: Result<bool, TantivySearchError> = Ok(true);
+    if writer_loaded {
+        reload_result = load_index_writer(index_path);
+    }
+    if reader_loaded && reload_result.is_ok() {
+        reload_result = load_index_reader(index_path);
+    }
+    restore_result?;
+    reload_result?;
+    INFO!(function: "restore_snapshot", "snapshot_dir:{}, index_path:{}", snapshot_dir, index_path);
+    Ok(true)
+}
+
+// Install the snapshot in a sibling directory and swap it with `index_path`,
+// writer and reader of `index_path` must have been released.
+fn install_snapshot(snapshot_directory: &Path, index_path: &str) -> Result<(), TantivySearchError> {
+    let snapshot_index: Index = Index::open_in_dir(snapshot_directory)?;
+    let snapshot_meta: IndexMeta = snapshot_index.load_metas()?;
+    let restore_path: String = format!("{}.restore", index_path.trim_end_matches('/'));
+    if let Err(e) = install_index_files(&snapshot_index, &snapshot_meta, &restore_path) {
+        ERROR!(function: "restore_snapshot", "Failed to install snapshot: {}", e);
+        let _ = fs::remove_dir_all(&restore_path);
+        return Err(e);
+    }
+    swap_index_directory("restore_snapshot", index_path, &restore_path)
+}
+
+/// Segment files changed since a follower's file set, and meta.json of the latest commit.
+pub struct SegmentChanges {
+    pub opstamp: Opstamp,
+    pub added_files: Vec<String>,
+    pub removed_files: Vec<String>,
+    pub meta_json: String,
+}
+
+// Key of `REPLICATION_PINS` for `follower_id` of `index_path`.
+fn replication_pin_key(index_path: &str, follower_id: &str) -> (String, String) {
+    (
+        index_path.trim_end_matches('/').to_string(),
+        follower_id.to_string(),
+    )
+}
+
+// Check `file_name` is a plain segment file name, meta and setting files are excluded.
+fn check_segment_file_name(function_name: &str, file_name: &str) -> Result<(), TantivySearchError> {
+    let is_plain_name: bool = Path::new(file_name)
+        .file_name()
+        .and_then(|name| name.to_str())
+        == Some(file_name);
+    if !is_plain_name
+        || file_name == META_FILE_NAME
+        || file_name == INDEX_INFO_FILE_NAME
+        || file_name.starts_with('.')
+    {
+        let error_info: String = format!("invalid segment file name: {:?}", file_name);
+        ERROR!(function: function_name, "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    Ok(())
+}
+
+/// List segment files added and removed since `known_files` of `follower_id`, which can be the
+/// segment files of a follower index listed with empty `known_files`.
+/// Files of the listed commit are pinned for `follower_id` until its next listing or
+/// `release_segment_changes`, pins of other followers are kept.
+///
+/// Pinned segment metas are tracked by the inventory of the index they're loaded from, so
+/// files are only protected from garbage collection of the writer loaded during listing.
+/// If the writer isn't loaded, or it's freed and loaded again before files are read,
+/// the listing should be done again.
+pub fn list_segment_changes(
+    index_path: &str,
+    follower_id: &str,
+    known_files: &Vec<String>,
+) -> Result<SegmentChanges, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "list_segment_changes", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+
+    let index_meta: IndexMeta = load_pinnable_index_meta(index_path)?;
+    let segment_files: Vec<String> = list_segment_files(index_files_directory, &index_meta);
+    let added_files: Vec<String> = segment_files
+        .iter()
+        .filter(|file| !known_files.contains(file))
+        .cloned()
+        .collect();
+    let removed_files: Vec<String> = known_files
+        .iter()
+        .filter(|file| !segment_files.contains(file))
+        .cloned()
+        .collect();
+    let meta_json: String = serde_json::to_string_pretty(&index_meta).map_err(|e| {
+        TantivySearchError::IndexUtilsError(IndexUtilsError::JsonSerializeError(e.to_string()))
+    })?;
+    let opstamp: Opstamp = index_meta.opstamp;
+
+    REPLICATION_PINS
+        .lock()
+        .map_err(|e| {
+            let error_info: String = format!("Lock error: {}", e);
+            ERROR!(function: "list_segment_changes", "{}", error_info);
+            TantivySearchError::InternalError(error_info)
+        })?
+        .insert(replication_pin_key(index_path, follower_id), index_meta);
+    DEBUG!(function: "list_segment_changes", "index_path:{}, follower_id:{}, opstamp:{}, added:{:?}, removed:{:?}", index_path, follower_id, opstamp, added_files, removed_files);
+
+    Ok(SegmentChanges {
+        opstamp,
+        added_files,
+        removed_files,
+        meta_json,
+    })
+}
+
+/// Unpin segment files listed for `follower_id` by `list_segment_changes`.
+/// Returns false if nothing is pinned for `follower_id`.
+pub fn release_segment_changes(
+    index_path: &str,
+    follower_id: &str,
+) -> Result<bool, TantivySearchError> {
+    let mut replication_pins = REPLICATION_PINS.lock().map_err(|e| {
+        let error_info: String = format!("Lock error: {}", e);
+        ERROR!(function: "release_segment_changes", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    if replication_pins
+        .remove(&replication_pin_key(index_path, follower_id))
+        .is_none()
+    {
+        DEBUG!(function: "release_segment_changes", "Nothing pinned, index_path:{}, follower_id:{}", index_path, follower_id);
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Read body of a segment file without footer, it must be listed by the last
+/// `list_segment_changes` of `follower_id`.
+pub fn read_segment_file(
+    index_path: &str,
+    follower_id: &str,
+    file_name: &str,
+) -> Result<Vec<u8>, TantivySearchError> {
+    check_segment_file_name("read_segment_file", file_name)?;
+    let index_files_directory: &Path = Path::new(index_path);
+    let is_listed: bool = match REPLICATION_PINS.lock() {
+        Ok(replication_pins) => replication_pins
+            .get(&replication_pin_key(index_path, follower_id))
+            .map(|index_meta| {
+                list_segment_files(index_files_directory, index_meta)
+                    .iter()
+                    .any(|file| file == file_name)
+            })
+            .unwrap_or(false),
+        Err(e) => {
+            let error_info: String = format!("Lock error: {}", e);
+            ERROR!(function: "read_segment_file", "{}", error_info);
+            return Err(TantivySearchError::InternalError(error_info));
+        }
+    };
+    if !is_listed {
+        let error_info: String = format!("segment file isn't listed: {:?}", file_name);
+        ERROR!(function: "read_segment_file", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+
+    // Footer is stripped by managed directory, follower appends it again when writing.
+    let index: Index = Index::open_in_dir(index_files_directory)?;
+    let content = index
+        .directory()
+        .open_read(Path::new(file_name))
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+        .read_bytes()
+        .map_err(|e| {
+            let error_info: String = format!("file: {:?}, message: {}", file_name, e);
+            ERROR!(function: "read_segment_file", "{}", error_info);
+            TantivySearchError::IndexUtilsError(IndexUtilsError::ReadFileError(error_info))
+        })?;
+    Ok(content.as_slice().to_vec())
+}
+
+/// Write a segment file shipped from the leader into a follower index,
+/// it becomes visible after `apply_segment_changes`.
+pub fn write_segment_file(
+    index_path: &str,
+    file_name: &str,
+    content: &[u8],
+) -> Result<bool, TantivySearchError> {
+    check_segment_file_name("write_segment_file", file_name)?;
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "write_segment_file", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+
+    // Segment files are registered in managed directory, they can be garbage collected later.
+    // A partially shipped file is replaced.
+    let index: Index = Index::open_in_dir(index_files_directory)?;
+    let file_path: &Path = Path::new(file_name);
+    if index
+        .directory()
+        .exists(file_path)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?
+    {
+        index.directory().delete(file_path).map_err(|e| {
+            IndexUtilsError::WriteFileError(format!("file: {:?}, message: {}", file_name, e))
+        })?;
+    }
+    let mut writer = index
+        .directory()
+        .open_write(file_path)
+        .map_err(|e| TantivySearchError::TantivyError(e.into()))?;
+    writer
+        .write_all(content)
+        .and_then(|_| writer.terminate())
+        .map_err(|e| {
+            let error_info: String = format!("file: {:?}, message: {}", file_name, e);
+            ERROR!(function: "write_segment_file", "{}", error_info);
+            TantivySearchError::IndexUtilsError(IndexUtilsError::WriteFileError(error_info))
+        })?;
+    Ok(true)
+}
+
+/// Install `meta_json` of the leader into a follower index, then remove `removed_files`.
+/// Segment files referenced by `meta_json` must be written before, the follower can't have
+/// a loaded writer. The reader will be reopened if loaded.
+pub fn apply_segment_changes(
+    index_path: &str,
+    meta_json: &str,
+    removed_files: &Vec<String>,
+) -> Result<bool, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    let meta_file: PathBuf = index_files_directory.join(META_FILE_NAME);
+    if !meta_file.exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "apply_segment_changes", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    if FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .is_ok()
+    {
+        let error_info: String = format!("follower index has a loaded writer: {:?}", index_path);
+        ERROR!(function: "apply_segment_changes", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    for file_name in removed_files.iter() {
+        check_segment_file_name("apply_segment_changes", file_name)?;
+    }
+
+    let old_meta_json: Vec<u8> = fs::read(&meta_file).map_err(|e| {
+        IndexUtilsError::ReadFileError(format!("file: {}, message: {}", META_FILE_NAME, e))
+    })?;
+    let index: Index = Index::open_in_dir(index_files_directory)?;
+    let write_meta = |content: &[u8]| {
+        index
+            .directory()
+            .atomic_write(Path::new(META_FILE_NAME), content)
+            .map_err(|e| {
+                let error_info: String = format!("Failed to write {}: {}", META_FILE_NAME, e);
+                ERROR!(function: "apply_segment_changes", "{}", error_info);
+                TantivySearchError::IndexUtilsError(IndexUtilsError::WriteFileError(error_info))
+            })
+    };
+    write_meta(meta_json.as_bytes())?;
+
+    // All segment files of the new commit must exist, old meta.json is restored otherwise.
+    let check_result = Index::open_in_dir(index_files_directory)
+        .and_then(|new_index| new_index.load_metas())
+        .map_err(TantivySearchError::TantivyError)
+        .and_then(|new_index_meta| {
+            let missing_files: Vec<PathBuf> = new_index_meta
+                .segments
+                .iter()
+                .flat_map(|segment_meta| segment_meta.list_files())
+                .filter(|file| !index_files_directory.join(file).exists())
+                .collect();
+            if missing_files.is_empty() {
+                Ok(list_segment_files(index_files_directory, &new_index_meta))
+            } else {
+                Err(TantivySearchError::InvalidArgument(format!(
+                    "missing segment files: {:?}",
+                    missing_files
+                )))
+            }
+        });
+    let segment_files: Vec<String> = match check_result {
+        Ok(segment_files) => segment_files,
+        Err(e) => {
+            ERROR!(function: "apply_segment_changes", "Invalid meta_json: {}", e);
+            write_meta(&old_meta_json)?;
+            return Err(e);
+        }
+    };
+
+    for file_name in removed_files.iter() {
+        if segment_files.contains(file_name) {
+            continue;
+        }
+        if let Err(e) = fs::remove_file(index_files_directory.join(file_name)) {
+            WARNING!(function: "apply_segment_changes", "Failed to remove {}: {}", file_name, e);
+        }
+    }
+
+    if free_index_reader(index_path)? {
+        load_index_reader(index_path)?;
+    }
+    Ok(true)
+}
+
+/// How to handle a row_id which exists in both target and source index.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RowIdConflictPolicy {
+    /// Merging fails if any row_id conflicts.
+    Fail,
+    /// Documents of target (or earlier source) are kept, conflicting ones of source are deleted.
+    KeepTarget,
+}
+
+impl FromStr for RowIdConflictPolicy {
+    type Err = TantivySearchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fail" => Ok(RowIdConflictPolicy::Fail),
+            "keep_target" => Ok(RowIdConflictPolicy::KeepTarget),
+            _ => Err(TantivySearchError::InvalidArgument(format!(
+                "unknown row_id conflict policy: {}",
+                s
+            ))),
+        }
+    }
+}
+
+// Collect row_ids of alive documents in `index`.
+fn collect_alive_row_ids(index: &Index) -> Result<Vec<u64>, TantivySearchError> {
+    let searcher = index
+        .reader_builder()
+        .reload_policy(ReloadPolicy::Manual)
+        .try_into()?
+        .searcher();
+    let mut row_ids: Vec<u64> = Vec::with_capacity(searcher.num_docs() as usize);
+    for segment_reader in searcher.segment_readers() {
+        let row_id_column = segment_reader.fast_fields().u64("row_id")?;
+        row_ids.extend(
+            segment_reader
+                .doc_ids_alive()
+                .filter_map(|doc_id| row_id_column.first(doc_id)),
+        );
+    }
+    Ok(row_ids)
+}
+
+// Copy source index into `staging_path`, row_ids seen in target or earlier sources are deleted
+// from the copy. Row_ids repeated inside the source itself are not conflicts, they are deduped
+// before comparing and all their documents are kept as they were in the source.
+// Returns the staging index meta, `seen_row_ids` is extended with row_ids kept by the copy.
+fn stage_source_index(
+    source_path: &str,
+    staging_path: &str,
+    row_id_conflict_policy: RowIdConflictPolicy,
+    seen_row_ids: &mut HashSet<u64>,
+) -> Result<IndexMeta, TantivySearchError> {
+    let source_index: Index = Index::open_in_dir(Path::new(source_path))?;
+    write_snapshot_files(source_path, staging_path, &source_index.load_metas()?)?;
+    let staging_index: Index = Index::open_in_dir(Path::new(staging_path))?;
+
+    let alive_row_ids: Vec<u64> = collect_alive_row_ids(&staging_index)?;
+    let source_row_ids: HashSet<u64> = alive_row_ids.iter().copied().collect();
+    if source_row_ids.len() < alive_row_ids.len() {
+        WARNING!(function: "merge_indexes", "{} has {} documents with repeated row_ids, they are kept", source_path, alive_row_ids.len() - source_row_ids.len());
+    }
+    let mut conflicting_row_ids: Vec<u64> = source_row_ids
+        .iter()
+        .filter(|row_id| seen_row_ids.contains(row_id))
+        .copied()
+        .collect();
+    conflicting_row_ids.sort_unstable();
+    seen_row_ids.extend(source_row_ids);
+    if !conflicting_row_ids.is_empty() {
+        if row_id_conflict_policy == RowIdConflictPolicy::Fail {
+            return Err(TantivySearchError::InvalidArgument(format!(
+                "{} row_ids of {} conflict, such as {}",
+                conflicting_row_ids.len(),
+                source_path,
+                conflicting_row_ids[0]
+            )));
+        }
+        let row_id_field: Field = staging_index.schema().get_field("row_id")?;
+        let mut staging_writer: IndexWriter =
+            staging_index.writer_with_num_threads(1, 15_000_000)?;
+        staging_writer.set_merge_policy(Box::new(NoMergePolicy));
+        for row_id in conflicting_row_ids {
+            staging_writer.delete_term(Term::from_field_u64(row_id_field, row_id));
+        }
+        staging_writer.commit()?;
+        staging_writer.wait_merging_threads()?;
+    }
+    Ok(staging_index.load_metas()?)
+}
+
+// Copy staged segments into target, then append them to meta.json of target.
+// Target writer must be freed before, returns attached docs count.
+fn attach_staged_segments(
+    index_path: &str,
+    staging_paths: &[String],
+    staging_metas: &[IndexMeta],
+) -> Result<u64, TantivySearchError> {
+    let target_index: Index = Index::open_in_dir(Path::new(index_path))?;
+    let mut index_meta: IndexMeta = target_index.load_metas()?;
+    let mut attached_docs: u64 = 0;
+    for (staging_path, staging_meta) in staging_paths.iter().zip(staging_metas.iter()) {
+        for segment_meta in staging_meta.segments.iter() {
+            if index_meta
+                .segments
+                .iter()
+                .any(|target_segment| target_segment.id() == segment_meta.id())
+            {
+                return Err(TantivySearchError::IncompatibleIndex(format!(
+                    "segment {} already exists in {}",
+                    segment_meta.id().uuid_string(),
+                    index_path
+                )));
+            }
+            attached_docs += segment_meta.num_docs() as u64;
+        }
+        let staging_index: Index = Index::open_in_dir(Path::new(staging_path))?;
+        for file in list_segment_files(Path::new(staging_path), staging_meta) {
+            copy_file_into_index(&staging_index, &target_index, &file)?;
+        }
+        index_meta
+            .segments
+            .extend(staging_meta.segments.iter().cloned());
+        index_meta.opstamp = index_meta.opstamp.max(staging_meta.opstamp);
+    }
+    let index_meta_json = serde_json::to_string_pretty(&index_meta)
+        .map_err(|e| IndexUtilsError::JsonSerializeError(e.to_string()))?;
+    target_index
+        .directory()
+        .atomic_write(Path::new(META_FILE_NAME), index_meta_json.as_bytes())
+        .map_err(|e| {
+            IndexUtilsError::WriteFileError(format!("file: {}, message: {}", META_FILE_NAME, e))
+        })?;
+    Ok(attached_docs)
+}
+
+/// Attach segments of `source_paths` to the index in `index_path`, returns attached docs count.
+/// Schemas and json parameters of all indexes must be equal, source indexes are not changed.
+/// The writer (and reader if loaded) of target will be reopened, even if attaching fails.
+pub fn merge_indexes(
+    index_path: &str,
+    source_paths: &Vec<String>,
+    row_id_conflict_policy: &str,
+) -> Result<u64, TantivySearchError> {
+    let row_id_conflict_policy = RowIdConflictPolicy::from_str(row_id_conflict_policy)?;
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "merge_indexes", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    let trimmed_index_path: &str = index_path.trim_end_matches('/');
+    let mut trimmed_source_paths: Vec<&str> = Vec::new();
+    for source_path in source_paths.iter() {
+        let trimmed_source_path: &str = source_path.trim_end_matches('/');
+        if trimmed_source_path == trimmed_index_path
+            || trimmed_source_paths.contains(&trimmed_source_path)
+        {
+            let error_info: String = format!("source path is duplicated: {:?}", source_path);
+            ERROR!(function: "merge_indexes", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+        trimmed_source_paths.push(trimmed_source_path);
+    }
+    if trimmed_source_paths.is_empty() {
+        return Err(TantivySearchError::InvalidArgument(
+            "source_paths can't be empty".to_string(),
+        ));
+    }
+
+    // Pending documents of target are merged as well.
+    let writer_loaded: bool = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .is_ok();
+    if writer_loaded {
+        commit_index(index_path)?;
+    }
+
+    // Segments can only be attached between indexes with same schema and json parameter.
+    let target_index: Index = Index::open_in_dir(index_files_directory)?;
+    let target_parameter: serde_json::Value = serde_json::from_str(
+        &IndexUtils::load_custom_index_setting(index_files_directory)?.tokenizers_json_parameter,
+    )
+    .map_err(|e| IndexUtilsError::JsonParseError(e.to_string()))?;
+    for source_path in trimmed_source_paths.iter() {
+        let source_directory: &Path = Path::new(source_path);
+        if !source_directory.join(META_FILE_NAME).exists() {
+            let error_info: String = format!("source path not exists: {:?}", source_path);
+            ERROR!(function: "merge_indexes", "{}", error_info);
+            return Err(TantivySearchError::IndexNotExists(error_info));
+        }
+        let source_parameter: serde_json::Value = serde_json::from_str(
+            &IndexUtils::load_custom_index_setting(source_directory)?.tokenizers_json_parameter,
+        )
+        .map_err(|e| IndexUtilsError::JsonParseError(e.to_string()))?;
+        if Index::open_in_dir(source_directory)?.schema() != target_index.schema()
+            || source_parameter != target_parameter
+        {
+            let error_info: String = format!(
+                "schema or json parameter of {} differs from {}",
+                source_path, index_path
+            );
+            ERROR!(function: "merge_indexes", "{}", error_info);
+            return Err(TantivySearchError::IncompatibleIndex(error_info));
+        }
+    }
+
+    // Stage each source index in a sibling directory of target.
+    let mut seen_row_ids: HashSet<u64> =
+        collect_alive_row_ids(&target_index)?.into_iter().collect();
+    let mut staging_paths: Vec<String> = Vec::new();
+    let mut staging_metas: Vec<IndexMeta> = Vec::new();
+    let remove_staging_paths = |staging_paths: &Vec<String>| {
+        for staging_path in staging_paths.iter() {
+            let _ = fs::remove_dir_all(staging_path);
+        }
+    };
+    for (i, source_path) in trimmed_source_paths.iter().enumerate() {
+        let staging_path: String = format!("{}.merge_{}", trimmed_index_path, i);
+        let _ = fs::remove_dir_all(&staging_path);
+        staging_paths.push(staging_path.clone());
+        match stage_source_index(
+            source_path,
+            &staging_path,
+            row_id_conflict_policy,
+            &mut seen_row_ids,
+        ) {
+            Ok(staging_meta) => staging_metas.push(staging_meta),
+            Err(e) => {
+                ERROR!(function: "merge_indexes", "Failed to stage {}: {}", source_path, e);
+                remove_staging_paths(&staging_paths);
+                return Err(e);
+            }
+        }
+    }
+
+    // Target is reopened after staged segments attached.
+    free_index_writer(index_path)?;
+    let reader_loaded: bool = free_index_reader(index_path)?;
+    let attach_result = attach_staged_segments(index_path, &staging_paths, &staging_metas);
+    remove_staging_paths(&staging_paths);
+
+    // meta.json is written at last, either the old or the merged index is in place,
+    // reload what has been released.
+    let mut reload_result: Result<bool, TantivySearchError> = Ok(true);
+    if writer_loaded {
+        reload_result = load_index_writer(index_path);
+    }
+    if reader_loaded && reload_result.is_ok() {
+        reload_result = load_index_reader(index_path);
+    }
+    let attached_docs: u64 = attach_result.map_err(|e| {
+        ERROR!(function: "merge_indexes", "Failed to attach segments: {}", e);
+        e
+    })?;
+    reload_result?;
+    INFO!(function: "merge_indexes", "index_path:{}, source_paths:{:?}, attached docs:{}", index_path, source_paths, attached_docs);
+    Ok(attached_docs)
+}
+
+// Query matching row_ids within `lower_bound` and `upper_bound`.
+fn row_id_range_query(
+    schema: &Schema,
+    lower_bound: Bound<u64>,
+    upper_bound: Bound<u64>,
+) -> Result<RangeQuery, TantivySearchError> {
+    let row_id_field: Field = schema.get_field("row_id")?;
+    let to_term_bound = |bound: Bound<u64>| match bound {
+        Bound::Included(row_id) => Bound::Included(Term::from_field_u64(row_id_field, row_id)),
+        Bound::Excluded(row_id) => Bound::Excluded(Term::from_field_u64(row_id_field, row_id)),
+        Bound::Unbounded => Bound::Unbounded,
+    };
+    Ok(RangeQuery::new_term_bounds(
+        "row_id".to_string(),
+        Type::U64,
+        &to_term_bound(lower_bound),
+        &to_term_bound(upper_bound),
+    ))
+}
+
+// Delete rows within `lower_bound` and `upper_bound` from an index without loaded writer,
+// segments with deleted docs are merged so that deleted docs are purged.
+fn purge_row_id_range(
+    index: &Index,
+    lower_bound: Bound<u64>,
+    upper_bound: Bound<u64>,
+) -> Result<(), TantivySearchError> {
+    let mut writer: IndexWriter = index.writer_with_num_threads(1, 15_000_000)?;
+    writer.set_merge_policy(Box::new(NoMergePolicy));
+    writer.delete_query(Box::new(row_id_range_query(
+        &index.schema(),
+        lower_bound,
+        upper_bound,
+    )?))?;
+    writer.commit()?;
+    for segment_meta in index.searchable_segment_metas()? {
+        if segment_meta.has_deletes() {
+            writer.merge(&[segment_meta.id()]).wait()?;
+        }
+    }
+    writer.wait_merging_threads()?;
+    Ok(())
+}
+
+/// Split the index in `index_path` by `split_row_id`, rows below it are kept in `left_path`,
+/// others are kept in `right_path`. Both indexes carry the json parameter of source, existing
+/// files of them will be removed. Source index is not changed.
+pub fn split_index(
+    index_path: &str,
+    split_row_id: u64,
+    left_path: &str,
+    right_path: &str,
+) -> Result<bool, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "split_index", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    let trimmed_paths: Vec<&str> = [index_path, left_path, right_path]
+        .iter()
+        .map(|path| path.trim_end_matches('/'))
+        .collect();
+    if trimmed_paths[0] == trimmed_paths[1]
+        || trimmed_paths[0] == trimmed_paths[2]
+        || trimmed_paths[1] == trimmed_paths[2]
+    {
+        let error_info: String = format!(
+            "index_path, left_path and right_path should be different: {:?}",
+            trimmed_paths
+        );
+        ERROR!(function: "split_index", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+
+    // Pending documents of source are split as well.
+    if FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .is_ok()
+    {
+        commit_index(index_path)?;
+    }
+    let index_meta: IndexMeta = load_pinnable_index_meta(index_path)?;
+    let source_index: Index = Index::open_in_dir(index_files_directory)?;
+
+    // Each half is a full copy of source, rows of the other half are purged.
+    let halves = [
+        (left_path, Bound::Included(split_row_id), Bound::Unbounded),
+        (right_path, Bound::Unbounded, Bound::Excluded(split_row_id)),
+    ];
+    for (half_path, lower_bound, upper_bound) in halves {
+        free_index_reader(half_path)?;
+        free_index_writer(half_path)?;
+        let split_result = install_index_files(&source_index, &index_meta, half_path)
+            .and_then(|half_index| purge_row_id_range(&half_index, lower_bound, upper_bound));
+        if let Err(e) = split_result {
+            ERROR!(function: "split_index", "Failed to split into {}: {}", half_path, e);
+            let _ = fs::remove_dir_all(left_path);
+            let _ = fs::remove_dir_all(right_path);
+            return Err(e);
+        }
+    }
+    INFO!(function: "split_index", "index_path:{}, split_row_id:{}, left_path:{}, right_path:{}", index_path, split_row_id, left_path, right_path);
+
+    Ok(true)
+}
+
+/// Verify the index in `index_path` without loading it, returns found problems, empty if intact.
+/// Checksums of all managed files are validated, so files being written by a running writer
+/// may be reported as damaged.
+pub fn verify_index(index_path: &str) -> Result<Vec<String>, TantivySearchError> {
+    let index_files_directory: &Path = Path::new(index_path);
+    if !index_files_directory.is_dir() {
+        let error_info: String = format!("index_path not exists: {:?}", index_path);
+        ERROR!(function: "verify_index", "{}", error_info);
+        return Err(TantivySearchError::IndexNotExists(error_info));
+    }
+    let mut problems: Vec<String> = Vec::new();
+
+    // Both column configs and index settings should be parsed from json parameter.
+    if !index_files_directory.join(INDEX_INFO_FILE_NAME).exists() {
+        problems.push(format!("{} is missing", INDEX_INFO_FILE_NAME));
+    } else if let Err(e) = IndexUtils::load_custom_index_setting(index_files_directory)
+        .map_err(TantivySearchError::from)
+        .and_then(|index_parameter_dto| {
+            TokenizerUtils::parse_tokenizer_json_to_config_map(
+                &index_parameter_dto.tokenizers_json_parameter,
+            )?;
+            TokenizerUtils::parse_index_settings_json(
+                &index_parameter_dto.tokenizers_json_parameter,
+            )?;
+            Ok(())
+        })
+    {
+        problems.push(format!("{} can't be parsed: {}", INDEX_INFO_FILE_NAME, e));
+    }
+
+    if !index_files_directory.join(META_FILE_NAME).exists() {
+        problems.push(format!("{} is missing", META_FILE_NAME));
+        return Ok(problems);
+    }
+    let (index, index_meta): (Index, IndexMeta) = match Index::open_in_dir(index_files_directory)
+        .and_then(|index| {
+            let index_meta: IndexMeta = index.load_metas()?;
+            Ok((index, index_meta))
+        }) {
+        Ok(opened) => opened,
+        Err(e) => {
+            problems.push(format!("{} can't be loaded: {}", META_FILE_NAME, e));
+            return Ok(problems);
+        }
+    };
+
+    // Temporary store only exists while a segment is being serialized.
+    for segment_meta in index_meta.segments.iter() {
+        for component in SegmentComponent::iterator() {
+            if *component == SegmentComponent::TempStore
+                || (*component == SegmentComponent::Delete && !segment_meta.has_deletes())
+            {
+                continue;
+            }
+            let file: PathBuf = segment_meta.relative_path(*component);
+            if !index.directory().exists(&file).unwrap_or(false) {
+                problems.push(format!(
+                    "segment {} misses file {:?}",
+                    segment_meta.id().uuid_string(),
+                    file
+                ));
+            }
+        }
+    }
+
+    // Removed files stay managed until next garbage collection, they are skipped.
+    let mut managed_files: Vec<PathBuf> =
+        index.directory().list_managed_files().into_iter().collect();
+    managed_files.sort();
+    for file in managed_files {
+        match index.directory().validate_checksum(&file) {
+            Ok(true) | Err(OpenReadError::FileDoesNotExist(_)) => {}
+            Ok(false) => problems.push(format!("checksum mismatch: {:?}", file)),
+            Err(e) => problems.push(format!("{:?} can't be validated: {}", file, e)),
+        }
+    }
+
+    if !problems.is_empty() {
+        WARNING!(function: "verify_index", "index_path:{}, problems:{:?}", index_path, problems);
+    }
+    Ok(problems)
+}
+
+pub fn create_index(
+    index_path: &str,
+    column_names: &Vec<String>,
+) -> Result<bool, TantivySearchError> {
+    create_index_with_parameter(index_path, column_names, "{}")
+}
+
+pub fn index_multi_column_docs(
+    index_path: &str,
+    row_id: u64,
+    column_names: &Vec<String>,
+    column_docs: &Vec<String>,
+) -> Result<bool, TantivySearchError> {
+    // Get index writer from CACHE
     let index_writer_bridge = FFI_INDEX_WRITER_CACHE
         .get_index_writer_bridge(index_path.to_string())
         .map_err(|e| {
@@ -345,96 +2227,637 @@ pub fn index_multi_type_column_docs(
     let mut doc = TantivyDocument::default();
     doc.add_u64(row_id_field, row_id);
 
-    // text field
     let mut column_idx = 0;
-    for column_name in text_column_names {
+    for column_name in column_names {
         let column_field = schema.get_field(column_name).map_err(|e| {
             ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
             TantivySearchError::TantivyError(e)
         })?;
-        doc.add_text(column_field, text_column_docs[column_idx].clone());
+        doc.add_text(column_field, column_docs[column_idx].clone());
         column_idx += 1;
     }
 
-    // i64 field
-    column_idx = 0;
-    for column_name in i64_column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-            ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
+    match index_writer_bridge.add_document(doc) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let error_info = format!("Failed to index doc:{}", e);
+            ERROR!(function: "index_multi_column_docs", "{}", error_info);
+            Err(TantivySearchError::InternalError(e))
+        }
+    }
+}
+
+// Build a document with multi type columns, row_id field is always added.
+fn build_multi_type_column_doc(
+    function_name: &str,
+    schema: &Schema,
+    row_id: u64,
+    text_column_names: &Vec<String>,
+    text_column_docs: &Vec<String>,
+    i64_column_names: &Vec<String>,
+    i64_column_docs: &Vec<i64>,
+    f64_column_names: &Vec<String>,
+    f64_column_docs: &Vec<f64>,
+    bytes_column_names: &Vec<String>,
+    bytes_column_docs: &Vec<Vec<u8>>,
+    date_column_names: &Vec<String>,
+    date_column_docs: &Vec<DateTime>,
+    bool_column_names: &Vec<String>,
+    bool_column_docs: &Vec<bool>,
+    u64_column_names: &Vec<String>,
+    u64_column_docs: &Vec<u64>,
+    ip_column_names: &Vec<String>,
+    ip_column_docs: &Vec<Ipv6Addr>,
+    json_column_names: &Vec<String>,
+    json_column_docs: &Vec<String>,
+) -> Result<TantivyDocument, TantivySearchError> {
+    let row_id_field = schema.get_field("row_id").map_err(|e| {
+        ERROR!(function: function_name, "Failed to get row_id field: {}", e.to_string());
+        TantivySearchError::TantivyError(e)
+    })?;
+    let get_field = |column_name: &String| {
+        schema.get_field(column_name).map_err(|e| {
+            ERROR!(function: function_name, "Failed to get {} field in schema: {}", column_name, e.to_string());
             TantivySearchError::TantivyError(e)
-        })?;
-        doc.add_i64(column_field, i64_column_docs[column_idx]);
-        column_idx += 1;
+        })
+    };
+
+    let mut doc = TantivyDocument::default();
+    doc.add_u64(row_id_field, row_id);
+
+    // text field
+    for (column_idx, column_name) in text_column_names.iter().enumerate() {
+        doc.add_text(
+            get_field(column_name)?,
+            text_column_docs[column_idx].clone(),
+        );
+    }
+
+    // i64 field
+    for (column_idx, column_name) in i64_column_names.iter().enumerate() {
+        doc.add_i64(get_field(column_name)?, i64_column_docs[column_idx]);
     }
 
     // f64 field
-    column_idx = 0;
-    for column_name in f64_column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-            ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
-            TantivySearchError::TantivyError(e)
-        })?;
-        doc.add_f64(column_field, f64_column_docs[column_idx]);
-        column_idx += 1;
+    for (column_idx, column_name) in f64_column_names.iter().enumerate() {
+        doc.add_f64(get_field(column_name)?, f64_column_docs[column_idx]);
     }
 
     // bytes field
-    column_idx = 0;
-    for column_name in bytes_column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-            ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
-            TantivySearchError::TantivyError(e)
-        })?;
-        doc.add_bytes(column_field, bytes_column_docs[column_idx].as_slice());
-        column_idx += 1;
+    for (column_idx, column_name) in bytes_column_names.iter().enumerate() {
+        doc.add_bytes(
+            get_field(column_name)?,
+            bytes_column_docs[column_idx].as_slice(),
+        );
     }
 
     // date field
-    column_idx = 0;
-    for column_name in date_column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-                    ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
+    for (column_idx, column_name) in date_column_names.iter().enumerate() {
+        doc.add_date(get_field(column_name)?, date_column_docs[column_idx]);
+    }
+
+    // bool field
+    for (column_idx, column_name) in bool_column_names.iter().enumerate() {
+        doc.add_bool(get_field(column_name)?, bool_column_docs[column_idx]);
+    }
+
+    // u64 field
+    for (column_idx, column_name) in u64_column_names.iter().enumerate() {
+        doc.add_u64(get_field(column_name)?, u64_column_docs[column_idx]);
+    }
+
+    // ip field
+    for (column_idx, column_name) in ip_column_names.iter().enumerate() {
+        doc.add_ip_addr(get_field(column_name)?, ip_column_docs[column_idx]);
+    }
+
+    // json field, each doc should be a json object string.
+    for (column_idx, column_name) in json_column_names.iter().enumerate() {
+        let json_object: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&json_column_docs[column_idx]).map_err(|e| {
+                let error_info = format!("Failed to parse {} json object: {}", column_name, e);
+                ERROR!(function: function_name, "{}", error_info);
+                TantivySearchError::InvalidArgument(error_info)
+            })?;
+        doc.add_field_value(get_field(column_name)?, json_object);
+    }
+
+    Ok(doc)
+}
+
+pub fn index_multi_type_column_docs(
+    index_path: &str,
+    row_id: u64,
+    text_column_names: &Vec<String>,
+    text_column_docs: &Vec<String>,
+    i64_column_names: &Vec<String>,
+    i64_column_docs: &Vec<i64>,
+    f64_column_names: &Vec<String>,
+    f64_column_docs: &Vec<f64>,
+    bytes_column_names: &Vec<String>,
+    bytes_column_docs: &Vec<Vec<u8>>,
+    date_column_names: &Vec<String>,
+    date_column_docs: &Vec<DateTime>,
+    bool_column_names: &Vec<String>,
+    bool_column_docs: &Vec<bool>,
+    u64_column_names: &Vec<String>,
+    u64_column_docs: &Vec<u64>,
+    ip_column_names: &Vec<String>,
+    ip_column_docs: &Vec<Ipv6Addr>,
+    json_column_names: &Vec<String>,
+    json_column_docs: &Vec<String>,
+) -> Result<bool, TantivySearchError> {
+    // Get index writer from CACHE
+    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "index_multi_type_column_docs", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let doc = build_multi_type_column_doc(
+        "index_multi_type_column_docs",
+        &index_writer_bridge.index.schema(),
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        u64_column_names,
+        u64_column_docs,
+        ip_column_names,
+        ip_column_docs,
+        json_column_names,
+        json_column_docs,
+    )?;
+
+    match index_writer_bridge.add_document(doc) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let error_info = format!("Failed to index doc:{}", e);
+            ERROR!(function: "index_multi_type_column_docs", "{}", error_info);
+            Err(TantivySearchError::InternalError(e))
+        }
+    }
+}
+
+pub fn upsert_multi_type_column_docs(
+    index_path: &str,
+    row_id: u64,
+    text_column_names: &Vec<String>,
+    text_column_docs: &Vec<String>,
+    i64_column_names: &Vec<String>,
+    i64_column_docs: &Vec<i64>,
+    f64_column_names: &Vec<String>,
+    f64_column_docs: &Vec<f64>,
+    bytes_column_names: &Vec<String>,
+    bytes_column_docs: &Vec<Vec<u8>>,
+    date_column_names: &Vec<String>,
+    date_column_docs: &Vec<DateTime>,
+    bool_column_names: &Vec<String>,
+    bool_column_docs: &Vec<bool>,
+    u64_column_names: &Vec<String>,
+    u64_column_docs: &Vec<u64>,
+    ip_column_names: &Vec<String>,
+    ip_column_docs: &Vec<Ipv6Addr>,
+) -> Result<bool, TantivySearchError> {
+    // Get index writer from CACHE
+    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "upsert_multi_type_column_docs", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let schema = index_writer_bridge.index.schema();
+    let doc = build_multi_type_column_doc(
+        "upsert_multi_type_column_docs",
+        &schema,
+        row_id,
+        text_column_names,
+        text_column_docs,
+        i64_column_names,
+        i64_column_docs,
+        f64_column_names,
+        f64_column_docs,
+        bytes_column_names,
+        bytes_column_docs,
+        date_column_names,
+        date_column_docs,
+        bool_column_names,
+        bool_column_docs,
+        u64_column_names,
+        u64_column_docs,
+        ip_column_names,
+        ip_column_docs,
+        &Vec::new(),
+        &Vec::new(),
+    )?;
+    let row_id_field = schema.get_field("row_id").map_err(|e| {
+        ERROR!(function: "upsert_multi_type_column_docs", "Failed to get row_id field: {}", e.to_string());
+        TantivySearchError::TantivyError(e)
+    })?;
+
+    // Old document is invisible only after next commit, together with the new one.
+    match index_writer_bridge.upsert_document(Term::from_field_u64(row_id_field, row_id), doc) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let error_info = format!("Failed to upsert doc:{}", e);
+            ERROR!(function: "upsert_multi_type_column_docs", "{}", error_info);
+            Err(TantivySearchError::InternalError(e))
+        }
+    }
+}
+
+// Resolve value ranges of each column with end offsets, values of column `c`
+// are located in `[offsets[c - 1], offsets[c])`, the start of first column is 0.
+fn column_value_ranges(
+    column_type: &str,
+    column_names: &Vec<String>,
+    column_offsets: &Vec<u64>,
+    docs_size: usize,
+) -> Result<Vec<Range<usize>>, TantivySearchError> {
+    if column_names.len() != column_offsets.len() {
+        let error_info = format!(
+            "{}_column_offsets size:{} doesn't match {}_column_names size:{}",
+            column_type,
+            column_offsets.len(),
+            column_type,
+            column_names.len()
+        );
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    let mut ranges: Vec<Range<usize>> = Vec::with_capacity(column_offsets.len());
+    let mut start: usize = 0;
+    for offset in column_offsets {
+        let end = *offset as usize;
+        if end < start || end > docs_size {
+            let error_info = format!(
+                "{}_column_offsets are invalid, offset:{} is out of range [{}, {}]",
+                column_type, end, start, docs_size
+            );
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+        ranges.push(start..end);
+        start = end;
+    }
+    if start != docs_size {
+        let error_info = format!(
+            "{}_column_docs size:{} doesn't match the last offset:{}",
+            column_type, docs_size, start
+        );
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    Ok(ranges)
+}
+
+pub fn index_multi_valued_column_docs(
+    index_path: &str,
+    row_id: u64,
+    text_column_names: &Vec<String>,
+    text_column_offsets: &Vec<u64>,
+    text_column_docs: &Vec<String>,
+    i64_column_names: &Vec<String>,
+    i64_column_offsets: &Vec<u64>,
+    i64_column_docs: &Vec<i64>,
+    f64_column_names: &Vec<String>,
+    f64_column_offsets: &Vec<u64>,
+    f64_column_docs: &Vec<f64>,
+    bytes_column_names: &Vec<String>,
+    bytes_column_offsets: &Vec<u64>,
+    bytes_column_docs: &Vec<Vec<u8>>,
+    date_column_names: &Vec<String>,
+    date_column_offsets: &Vec<u64>,
+    date_column_docs: &Vec<DateTime>,
+    bool_column_names: &Vec<String>,
+    bool_column_offsets: &Vec<u64>,
+    bool_column_docs: &Vec<bool>,
+    u64_column_names: &Vec<String>,
+    u64_column_offsets: &Vec<u64>,
+    u64_column_docs: &Vec<u64>,
+    ip_column_names: &Vec<String>,
+    ip_column_offsets: &Vec<u64>,
+    ip_column_docs: &Vec<Ipv6Addr>,
+) -> Result<bool, TantivySearchError> {
+    let value_ranges = |column_type: &str,
+                        column_names: &Vec<String>,
+                        column_offsets: &Vec<u64>,
+                        docs_size: usize| {
+        column_value_ranges(column_type, column_names, column_offsets, docs_size).map_err(|e| {
+            ERROR!(function: "index_multi_valued_column_docs", "{}", e);
+            e
+        })
+    };
+    let text_ranges = value_ranges(
+        "text",
+        text_column_names,
+        text_column_offsets,
+        text_column_docs.len(),
+    )?;
+    let i64_ranges = value_ranges(
+        "i64",
+        i64_column_names,
+        i64_column_offsets,
+        i64_column_docs.len(),
+    )?;
+    let f64_ranges = value_ranges(
+        "f64",
+        f64_column_names,
+        f64_column_offsets,
+        f64_column_docs.len(),
+    )?;
+    let bytes_ranges = value_ranges(
+        "bytes",
+        bytes_column_names,
+        bytes_column_offsets,
+        bytes_column_docs.len(),
+    )?;
+    let date_ranges = value_ranges(
+        "date",
+        date_column_names,
+        date_column_offsets,
+        date_column_docs.len(),
+    )?;
+    let bool_ranges = value_ranges(
+        "bool",
+        bool_column_names,
+        bool_column_offsets,
+        bool_column_docs.len(),
+    )?;
+    let u64_ranges = value_ranges(
+        "u64",
+        u64_column_names,
+        u64_column_offsets,
+        u64_column_docs.len(),
+    )?;
+    let ip_ranges = value_ranges(
+        "ip",
+        ip_column_names,
+        ip_column_offsets,
+        ip_column_docs.len(),
+    )?;
+
+    // Get index writer from CACHE
+    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "index_multi_valued_column_docs", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Get schema from index writer.
+    let schema = index_writer_bridge.index.schema();
+    let row_id_field = schema.get_field("row_id").map_err(|e| {
+        ERROR!(function: "index_multi_valued_column_docs", "Failed to get row_id field: {}", e.to_string());
+        TantivySearchError::TantivyError(e)
+    })?;
+    let get_field = |column_name: &String| {
+        schema.get_field(column_name).map_err(|e| {
+            ERROR!(function: "index_multi_valued_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
+            TantivySearchError::TantivyError(e)
+        })
+    };
+
+    let mut doc = TantivyDocument::default();
+    doc.add_u64(row_id_field, row_id);
+
+    // Each value is added to the same field, just like tantivy multi-value fields.
+    for (column_name, range) in text_column_names.iter().zip(text_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &text_column_docs[range] {
+            doc.add_text(column_field, value);
+        }
+    }
+    for (column_name, range) in i64_column_names.iter().zip(i64_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &i64_column_docs[range] {
+            doc.add_i64(column_field, *value);
+        }
+    }
+    for (column_name, range) in f64_column_names.iter().zip(f64_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &f64_column_docs[range] {
+            doc.add_f64(column_field, *value);
+        }
+    }
+    for (column_name, range) in bytes_column_names.iter().zip(bytes_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &bytes_column_docs[range] {
+            doc.add_bytes(column_field, value.as_slice());
+        }
+    }
+    for (column_name, range) in date_column_names.iter().zip(date_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &date_column_docs[range] {
+            doc.add_date(column_field, *value);
+        }
+    }
+    for (column_name, range) in bool_column_names.iter().zip(bool_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &bool_column_docs[range] {
+            doc.add_bool(column_field, *value);
+        }
+    }
+    for (column_name, range) in u64_column_names.iter().zip(u64_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &u64_column_docs[range] {
+            doc.add_u64(column_field, *value);
+        }
+    }
+    for (column_name, range) in ip_column_names.iter().zip(ip_ranges) {
+        let column_field = get_field(column_name)?;
+        for value in &ip_column_docs[range] {
+            doc.add_ip_addr(column_field, *value);
+        }
+    }
+
+    match index_writer_bridge.add_document(doc) {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let error_info = format!("Failed to index doc:{}", e);
+            ERROR!(function: "index_multi_valued_column_docs", "{}", error_info);
+            Err(TantivySearchError::InternalError(e))
+        }
+    }
+}
+
+pub fn index_multi_type_column_docs_batch(
+    index_path: &str,
+    row_ids: &Vec<u64>,
+    text_column_names: &Vec<String>,
+    text_column_docs: &Vec<String>,
+    i64_column_names: &Vec<String>,
+    i64_column_docs: &Vec<i64>,
+    f64_column_names: &Vec<String>,
+    f64_column_docs: &Vec<f64>,
+    bytes_column_names: &Vec<String>,
+    bytes_column_docs: &Vec<Vec<u8>>,
+    date_column_names: &Vec<String>,
+    date_column_docs: &Vec<String>,
+    bool_column_names: &Vec<String>,
+    bool_column_docs: &Vec<String>,
+    u64_column_names: &Vec<String>,
+    u64_column_docs: &Vec<u64>,
+    ip_column_names: &Vec<String>,
+    ip_column_docs: &Vec<String>,
+) -> Result<Vec<RowIdWithError>, TantivySearchError> {
+    let num_rows = row_ids.len();
+
+    // Each column docs vector should contain `num_rows` values for every column.
+    let column_sizes: [(&str, usize, usize); 8] = [
+        ("text", text_column_names.len(), text_column_docs.len()),
+        ("i64", i64_column_names.len(), i64_column_docs.len()),
+        ("f64", f64_column_names.len(), f64_column_docs.len()),
+        ("bytes", bytes_column_names.len(), bytes_column_docs.len()),
+        ("date", date_column_names.len(), date_column_docs.len()),
+        ("bool", bool_column_names.len(), bool_column_docs.len()),
+        ("u64", u64_column_names.len(), u64_column_docs.len()),
+        ("ip", ip_column_names.len(), ip_column_docs.len()),
+    ];
+    for (column_type, names_size, docs_size) in column_sizes {
+        if names_size * num_rows != docs_size {
+            let error_info = format!(
+                "{}_column_docs size:{} doesn't match {}_column_names size:{} * row_ids size:{}",
+                column_type, docs_size, column_type, names_size, num_rows
+            );
+            ERROR!(function: "index_multi_type_column_docs_batch", "{}", error_info);
+            return Err(TantivySearchError::InvalidArgument(error_info));
+        }
+    }
+
+    // Get index writer from CACHE
+    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "index_multi_type_column_docs_batch", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Get schema from index writer.
+    let schema = index_writer_bridge.index.schema();
+    let row_id_field = schema.get_field("row_id").map_err(|e| {
+        ERROR!(function: "index_multi_type_column_docs_batch", "Failed to get row_id field: {}", e.to_string());
+        TantivySearchError::TantivyError(e)
+    })?;
+
+    // Resolve all column fields once, a missing column fails the whole batch.
+    let get_fields = |column_names: &Vec<String>| -> Result<Vec<Field>, TantivySearchError> {
+        column_names
+            .iter()
+            .map(|column_name| {
+                schema.get_field(column_name).map_err(|e| {
+                    ERROR!(function: "index_multi_type_column_docs_batch", "Failed to get {} field in schema: {}", column_name, e.to_string());
                     TantivySearchError::TantivyError(e)
-                })?;
-        doc.add_date(column_field, date_column_docs[column_idx].clone());
-        column_idx += 1;
+                })
+            })
+            .collect()
+    };
+    let text_fields = get_fields(text_column_names)?;
+    let i64_fields = get_fields(i64_column_names)?;
+    let f64_fields = get_fields(f64_column_names)?;
+    let bytes_fields = get_fields(bytes_column_names)?;
+    let date_fields = get_fields(date_column_names)?;
+    let bool_fields = get_fields(bool_column_names)?;
+    let u64_fields = get_fields(u64_column_names)?;
+    let ip_fields = get_fields(ip_column_names)?;
+
+    // Build documents row by row, rows with invalid value will be reported.
+    let mut failed_rows: Vec<RowIdWithError> = Vec::new();
+    let mut doc_row_ids: Vec<u64> = Vec::with_capacity(num_rows);
+    let mut docs: Vec<TantivyDocument> = Vec::with_capacity(num_rows);
+
+    'rows: for (row_idx, row_id) in row_ids.iter().enumerate() {
+        let mut doc = TantivyDocument::default();
+        doc.add_u64(row_id_field, *row_id);
+
+        for (column_idx, field) in text_fields.iter().enumerate() {
+            doc.add_text(*field, &text_column_docs[column_idx * num_rows + row_idx]);
+        }
+        for (column_idx, field) in i64_fields.iter().enumerate() {
+            doc.add_i64(*field, i64_column_docs[column_idx * num_rows + row_idx]);
+        }
+        for (column_idx, field) in f64_fields.iter().enumerate() {
+            doc.add_f64(*field, f64_column_docs[column_idx * num_rows + row_idx]);
+        }
+        for (column_idx, field) in bytes_fields.iter().enumerate() {
+            doc.add_bytes(
+                *field,
+                bytes_column_docs[column_idx * num_rows + row_idx].as_slice(),
+            );
+        }
+        for (column_idx, field) in u64_fields.iter().enumerate() {
+            doc.add_u64(*field, u64_column_docs[column_idx * num_rows + row_idx]);
+        }
+        for (column_idx, field) in date_fields.iter().enumerate() {
+            match parse_datetime(&date_column_docs[column_idx * num_rows + row_idx]) {
+                Ok(date) => doc.add_date(*field, date),
+                Err(e) => {
+                    let error_info = format!("column {}: {}", date_column_names[column_idx], e);
+                    WARNING!(function: "index_multi_type_column_docs_batch", "row_id:{}, {}", row_id, error_info);
+                    failed_rows.push(RowIdWithError::new(*row_id, error_info));
+                    continue 'rows;
+                }
+            }
+        }
+        for (column_idx, field) in bool_fields.iter().enumerate() {
+            match parse_bool(&bool_column_docs[column_idx * num_rows + row_idx]) {
+                Ok(value) => doc.add_bool(*field, value),
+                Err(e) => {
+                    let error_info = format!("column {}: {}", bool_column_names[column_idx], e);
+                    WARNING!(function: "index_multi_type_column_docs_batch", "row_id:{}, {}", row_id, error_info);
+                    failed_rows.push(RowIdWithError::new(*row_id, error_info));
+                    continue 'rows;
+                }
+            }
+        }
+        for (column_idx, field) in ip_fields.iter().enumerate() {
+            match parse_ip(&ip_column_docs[column_idx * num_rows + row_idx]) {
+                Ok(ip) => doc.add_ip_addr(*field, ip),
+                Err(e) => {
+                    let error_info = format!("column {}: {}", ip_column_names[column_idx], e);
+                    WARNING!(function: "index_multi_type_column_docs_batch", "row_id:{}, {}", row_id, error_info);
+                    failed_rows.push(RowIdWithError::new(*row_id, error_info));
+                    continue 'rows;
+                }
+            }
+        }
+        doc_row_ids.push(*row_id);
+        docs.push(doc);
     }
 
-    // bool field
-    column_idx = 0;
-    for column_name in bool_column_names {
-        let column_field = schema.get_field(column_name).map_err(|e| {
-                        ERROR!(function: "index_multi_column_docs", "Failed to get {} field in schema: {}", column_name, e.to_string());
-                        TantivySearchError::TantivyError(e)
-                    })?;
-        doc.add_bool(column_field, bool_column_docs[column_idx].clone());
-        column_idx += 1;
-    }
+    // Push all documents under a single writer lock.
+    let add_results = index_writer_bridge.add_documents(docs).map_err(|e| {
+        ERROR!(function: "index_multi_type_column_docs_batch", "Failed to index docs:{}", e);
+        TantivySearchError::InternalError(e)
+    })?;
 
-    match index_writer_bridge.add_document(doc) {
-        Ok(_) => Ok(true),
-        Err(e) => {
-            let error_info = format!("Failed to index doc:{}", e);
-            ERROR!(function: "index_multi_column_docs", "{}", error_info);
-            Err(TantivySearchError::InternalError(e))
+    for (row_id, add_result) in doc_row_ids.iter().zip(add_results.into_iter()) {
+        if let Err(e) = add_result {
+            ERROR!(function: "index_multi_type_column_docs_batch", "Failed to index row_id:{}, {}", row_id, e);
+            failed_rows.push(RowIdWithError::new(*row_id, e));
         }
     }
-}
 
-pub fn delete_row_ids(index_path: &str, row_ids: &Vec<u64>) -> Result<bool, TantivySearchError> {
-    // Get index writer from CACHE
-    let index_writer_bridge =
-        match FFI_INDEX_WRITER_CACHE.get_index_writer_bridge(index_path.to_string()) {
-            Ok(content) => content,
-            Err(e) => {
-                ERROR!(function: "delete_row_ids", "{}", e);
-                return Err(TantivySearchError::InternalError(e));
-            }
-        };
+    Ok(failed_rows)
+}
 
+// Queue delete terms for `row_ids`, returns the opstamp of the last delete.
+fn queue_delete_row_ids(
+    function_name: &str,
+    index_writer_bridge: &IndexWriterBridge,
+    row_ids: &Vec<u64>,
+) -> Result<Opstamp, TantivySearchError> {
     let schema = index_writer_bridge.index.schema();
     let row_id_field = schema.get_field("row_id").map_err(|e| {
-        ERROR!(function: "delete_row_ids", "Failed to get row_id field: {}", e.to_string());
+        ERROR!(function: function_name, "Failed to get row_id field: {}", e.to_string());
         TantivySearchError::TantivyError(e)
     })?;
 
@@ -445,9 +2868,23 @@ pub fn delete_row_ids(index_path: &str, row_ids: &Vec<u64>) -> Result<bool, Tant
 
     // Delete row_id terms.
     index_writer_bridge.delete_terms(terms).map_err(|e| {
-        ERROR!(function: "delete_row_ids", "{}", e);
+        ERROR!(function: function_name, "{}", e);
         TantivySearchError::InternalError(e)
-    })?;
+    })
+}
+
+pub fn delete_row_ids(index_path: &str, row_ids: &Vec<u64>) -> Result<bool, TantivySearchError> {
+    // Get index writer from CACHE
+    let index_writer_bridge =
+        match FFI_INDEX_WRITER_CACHE.get_index_writer_bridge(index_path.to_string()) {
+            Ok(content) => content,
+            Err(e) => {
+                ERROR!(function: "delete_row_ids", "{}", e);
+                return Err(TantivySearchError::InternalError(e));
+            }
+        };
+
+    queue_delete_row_ids("delete_row_ids", &index_writer_bridge, row_ids)?;
     // After delete_term, need commit index writer.
     index_writer_bridge.commit().map_err(|e| {
         let error_info = format!("Failed to commit index writer: {}", e.to_string());
@@ -473,6 +2910,142 @@ pub fn delete_row_ids(index_path: &str, row_ids: &Vec<u64>) -> Result<bool, Tant
     Ok(reload_status)
 }
 
+// Delete documents matching `query`, then commit and reload the index reader if loaded.
+fn delete_query_and_commit(
+    function_name: &str,
+    index_path: &str,
+    index_writer_bridge: &IndexWriterBridge,
+    query: Box<dyn Query>,
+) -> Result<bool, TantivySearchError> {
+    index_writer_bridge.delete_query(query).map_err(|e| {
+        let error_info = format!("Failed to delete by query: {}", e);
+        ERROR!(function: function_name, "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    index_writer_bridge.commit().map_err(|e| {
+        let error_info = format!("Failed to commit index writer: {}", e);
+        ERROR!(function: function_name, "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+    if let Ok(index_reader_bridge) =
+        FFI_INDEX_SEARCHER_CACHE.get_index_reader_bridge(index_path.to_string())
+    {
+        index_reader_bridge.reload().map_err(|e| {
+            ERROR!(function: function_name, "Can't reload reader after delete operation: {}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+    }
+    Ok(true)
+}
+
+/// Delete rows whose row_id is in `[start_row_id, end_row_id)`.
+pub fn delete_row_id_range(
+    index_path: &str,
+    start_row_id: u64,
+    end_row_id: u64,
+) -> Result<bool, TantivySearchError> {
+    if start_row_id >= end_row_id {
+        let error_info: String = format!(
+            "start_row_id {} should be less than end_row_id {}",
+            start_row_id, end_row_id
+        );
+        ERROR!(function: "delete_row_id_range", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "delete_row_id_range", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let range_query: RangeQuery = row_id_range_query(
+        &index_writer_bridge.index.schema(),
+        Bound::Included(start_row_id),
+        Bound::Excluded(end_row_id),
+    )?;
+    delete_query_and_commit(
+        "delete_row_id_range",
+        index_path,
+        &index_writer_bridge,
+        Box::new(range_query),
+    )
+}
+
+/// Delete rows matching `term` in `column_name`. Text terms are tokenized with the column
+/// tokenizer and must produce exactly one token, other terms are parsed with column type.
+pub fn delete_by_term(
+    index_path: &str,
+    column_name: &str,
+    term: &str,
+) -> Result<bool, TantivySearchError> {
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "delete_by_term", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let term_query: Box<dyn Query> = exact_term_query(
+        "delete_by_term",
+        &index_writer_bridge.index,
+        column_name,
+        term,
+    )?;
+    delete_query_and_commit(
+        "delete_by_term",
+        index_path,
+        &index_writer_bridge,
+        term_query,
+    )
+}
+
+/// Delete rows whose `column_name` value is in `[lower_bound, upper_bound]`, bounds are parsed
+/// with the column type and empty bound means unbounded.
+pub fn delete_by_range(
+    index_path: &str,
+    column_name: &str,
+    lower_bound: &str,
+    upper_bound: &str,
+) -> Result<bool, TantivySearchError> {
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "delete_by_range", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let range_query: RangeQuery = column_range_query(
+        "delete_by_range",
+        &index_writer_bridge.index.schema(),
+        column_name,
+        lower_bound,
+        upper_bound,
+    )?;
+    delete_query_and_commit(
+        "delete_by_range",
+        index_path,
+        &index_writer_bridge,
+        Box::new(range_query),
+    )
+}
+
+pub fn delete_row_ids_deferred(
+    index_path: &str,
+    row_ids: &Vec<u64>,
+) -> Result<Opstamp, TantivySearchError> {
+    // Get index writer from CACHE
+    let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "delete_row_ids_deferred", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Deleted rows are still visible until next commit.
+    queue_delete_row_ids("delete_row_ids_deferred", &index_writer_bridge, row_ids)
+}
+
 pub fn commit_index(index_path: &str) -> Result<bool, TantivySearchError> {
     // get index writer bridge from CACHE
     let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
@@ -499,6 +3072,292 @@ pub fn commit_index(index_path: &str) -> Result<bool, TantivySearchError> {
     Ok(true)
 }
 
+// Split searchable segments into at most `max_segments` groups, merge each group into one segment.
+// Merges started before the merge policy was switched off may still consume segments of a group,
+// then the groups are planned again from the remaining segments.
+fn merge_segment_groups(
+    index_writer_bridge: &IndexWriterBridge,
+    max_segments: u32,
+) -> Result<(), TantivySearchError> {
+    for attempt in 1..=FORCE_MERGE_MAX_ATTEMPTS {
+        let mut segment_metas = index_writer_bridge
+            .index
+            .searchable_segment_metas()
+            .map_err(|e| {
+                ERROR!(function: "force_merge", "Failed to get searchable segments: {}", e);
+                TantivySearchError::TantivyError(e)
+            })?;
+
+        // Single segment with deleted docs is merged as well, so that deleted docs are purged.
+        segment_metas.sort_by_key(|meta| meta.max_doc());
+        let group_size = (segment_metas.len() + max_segments as usize - 1) / max_segments as usize;
+        let mut replanned = false;
+        for group in segment_metas.chunks(group_size.max(1)) {
+            if group.len() == 1 && !group[0].has_deletes() {
+                continue;
+            }
+            let segment_ids: Vec<SegmentId> = group.iter().map(|meta| meta.id()).collect();
+            if let Err(e) = index_writer_bridge.merge(&segment_ids) {
+                let searchable_segment_ids = index_writer_bridge
+                    .index
+                    .searchable_segment_ids()
+                    .map_err(|e| {
+                        ERROR!(function: "force_merge", "Failed to get searchable segments: {}", e);
+                        TantivySearchError::TantivyError(e)
+                    })?;
+                let consumed = segment_ids
+                    .iter()
+                    .any(|segment_id| !searchable_segment_ids.contains(segment_id));
+                if consumed && attempt < FORCE_MERGE_MAX_ATTEMPTS {
+                    WARNING!(function: "force_merge", "Segments were merged in background, plan again: {}", e);
+                    replanned = true;
+                    break;
+                }
+                let error_info = format!("Failed to merge segments: {}", e);
+                ERROR!(function: "force_merge", "{}", error_info);
+                return Err(TantivySearchError::InternalError(error_info));
+            }
+        }
+        if !replanned {
+            break;
+        }
+    }
+    Ok(())
+}
+
+pub fn force_merge(index_path: &str, max_segments: u32) -> Result<(u32, u32), TantivySearchError> {
+    if max_segments == 0 {
+        let error_info = "max_segments should be greater than 0".to_string();
+        ERROR!(function: "force_merge", "{}", error_info);
+        return Err(TantivySearchError::InvalidArgument(error_info));
+    }
+
+    // Get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "force_merge", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let segments_before = index_writer_bridge
+        .index
+        .searchable_segment_ids()
+        .map_err(|e| {
+            ERROR!(function: "force_merge", "Failed to get searchable segments: {}", e);
+            TantivySearchError::TantivyError(e)
+        })?
+        .len() as u32;
+
+    // Background merges would pick the same segments as the explicit merges below,
+    // so merge policy is switched off during the call and the persisted one is restored after.
+    let index_parameter_dto: IndexParameterDTO = IndexUtils::load_custom_index_setting_of_index(
+        &index_writer_bridge.index,
+    )
+    .map_err(|e| {
+        ERROR!(function: "force_merge", "{}", e);
+        TantivySearchError::IndexUtilsError(e)
+    })?;
+    let index_settings: IndexSettingsConfig =
+        TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)
+            .map_err(|e| {
+            ERROR!(function: "force_merge", "{}", e);
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+    index_writer_bridge
+        .set_merge_policy(Box::new(NoMergePolicy))
+        .map_err(|e| {
+            ERROR!(function: "force_merge", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+    let merge_result = merge_segment_groups(&index_writer_bridge, max_segments);
+    if let Err(e) =
+        index_writer_bridge.set_merge_policy(build_merge_policy(&index_settings.merge_policy))
+    {
+        ERROR!(function: "force_merge", "Failed to restore merge policy: {}", e);
+    }
+    merge_result?;
+
+    if let Err(e) = index_writer_bridge.garbage_collect_files() {
+        WARNING!(function: "force_merge", "Failed to garbage collect files: {}", e);
+    }
+
+    let segments_after = index_writer_bridge
+        .index
+        .searchable_segment_ids()
+        .map_err(|e| {
+            ERROR!(function: "force_merge", "Failed to get searchable segments: {}", e);
+            TantivySearchError::TantivyError(e)
+        })?
+        .len() as u32;
+
+    // Reload index reader from CACHE
+    if let Ok(index_reader_bridge) =
+        FFI_INDEX_SEARCHER_CACHE.get_index_reader_bridge(index_path.to_string())
+    {
+        index_reader_bridge.reload().map_err(|e| {
+            ERROR!(function: "force_merge", "Can't reload reader after merge: {}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+    }
+
+    INFO!(function: "force_merge", "index_path:{}, segments before:{}, after:{}", index_path, segments_before, segments_after);
+    Ok((segments_before, segments_after))
+}
+
+pub fn commit_index_with_payload(
+    index_path: &str,
+    payload: &str,
+) -> Result<bool, TantivySearchError> {
+    // get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "commit_index_with_payload", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    index_writer_bridge
+        .commit_with_payload(payload)
+        .map_err(|e| {
+            let error_info = format!("Failed to commit index writer: {}", e.to_string());
+            ERROR!(function: "commit_index_with_payload", "{}", error_info);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // get index reader bridge from CACHE
+    if let Ok(index_reader_bridge) =
+        FFI_INDEX_SEARCHER_CACHE.get_index_reader_bridge(index_path.to_string())
+    {
+        let _ = index_reader_bridge.reader.reload();
+    }
+
+    Ok(true)
+}
+
+pub fn rollback_index(index_path: &str) -> Result<bool, TantivySearchError> {
+    // get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "rollback_index", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Load persisted merge policy, it will be set again after rollback.
+    let index_parameter_dto: IndexParameterDTO = IndexUtils::load_custom_index_setting_of_index(
+        &index_writer_bridge.index,
+    )
+    .map_err(|e| {
+        ERROR!(function: "rollback_index", "{}", e);
+        TantivySearchError::IndexUtilsError(e)
+    })?;
+    let index_settings: IndexSettingsConfig =
+        TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)
+            .map_err(|e| {
+            ERROR!(function: "rollback_index", "{}", e);
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+
+    index_writer_bridge
+        .rollback(build_merge_policy(&index_settings.merge_policy))
+        .map_err(|e| {
+            ERROR!(function: "rollback_index", "Failed to rollback index writer: {}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    Ok(true)
+}
+
+pub fn prepare_commit_with_payload(
+    index_path: &str,
+    payload: &str,
+) -> Result<bool, TantivySearchError> {
+    // get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "prepare_commit_with_payload", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    index_writer_bridge.prepare_commit(payload).map_err(|e| {
+        ERROR!(function: "prepare_commit_with_payload", "Failed to prepare commit: {}", e);
+        TantivySearchError::InternalError(e)
+    })?;
+
+    Ok(true)
+}
+
+pub fn commit_prepared(index_path: &str) -> Result<bool, TantivySearchError> {
+    // get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "commit_prepared", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    index_writer_bridge.commit_prepared().map_err(|e| {
+        ERROR!(function: "commit_prepared", "Failed to commit prepared commit: {}", e);
+        TantivySearchError::InternalError(e)
+    })?;
+
+    // get index reader bridge from CACHE
+    if let Ok(index_reader_bridge) =
+        FFI_INDEX_SEARCHER_CACHE.get_index_reader_bridge(index_path.to_string())
+    {
+        let _ = index_reader_bridge.reader.reload();
+    }
+
+    Ok(true)
+}
+
+pub fn abort_prepared(index_path: &str) -> Result<bool, TantivySearchError> {
+    // get index writer bridge from CACHE
+    let index_writer_bridge: Arc<IndexWriterBridge> = FFI_INDEX_WRITER_CACHE
+        .get_index_writer_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function: "abort_prepared", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Aborting rolls the writer back, so the persisted merge policy is set again like `rollback_index`.
+    let index_parameter_dto: IndexParameterDTO = IndexUtils::load_custom_index_setting_of_index(
+        &index_writer_bridge.index,
+    )
+    .map_err(|e| {
+        ERROR!(function: "abort_prepared", "{}", e);
+        TantivySearchError::IndexUtilsError(e)
+    })?;
+    let index_settings: IndexSettingsConfig =
+        TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)
+            .map_err(|e| {
+            ERROR!(function: "abort_prepared", "{}", e);
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+
+    index_writer_bridge
+        .abort_prepared(build_merge_policy(&index_settings.merge_policy))
+        .map_err(|e| {
+            ERROR!(function: "abort_prepared", "Failed to abort prepared commit: {}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    Ok(true)
+}
+
+pub fn get_commit_payload(index_path: &str) -> Result<String, TantivySearchError> {
+    // Read the last commit meta from meta.json, in-memory index is resolved from cache.
+    let index: Index = resolve_index("get_commit_payload", index_path)?;
+    let index_meta = index.load_metas().map_err(|e| {
+        ERROR!(function: "get_commit_payload", "Failed to load index metas: {}", e);
+        TantivySearchError::TantivyError(e)
+    })?;
+
+    Ok(index_meta.payload.unwrap_or_default())
+}
+
 pub fn free_index_writer(index_path: &str) -> Result<bool, TantivySearchError> {
     // get index writer bridge from CACHE
     let index_writer_bridge: Arc<IndexWriterBridge> =
@@ -563,6 +3422,14 @@ pub fn load_index_writer(index_path: &str) -> Result<bool, TantivySearchError> {
             TantivySearchError::TokenizerUtilsError(e)
         })?;
 
+    // Parse persisted index level settings.
+    let index_settings: IndexSettingsConfig =
+        TokenizerUtils::parse_index_settings_json(&index_parameter_dto.tokenizers_json_parameter)
+            .map_err(|e| {
+            ERROR!(function:"load_index_writer", "{}", e);
+            TantivySearchError::TokenizerUtilsError(e)
+        })?;
+
     // Register tokenizer config into `index`.
     for (column_name, tokenizer_config) in col_tokenizer_map.iter() {
         TokenizerUtils::register_tokenizer_to_index(
@@ -575,6 +3442,7 @@ pub fn load_index_writer(index_path: &str) -> Result<bool, TantivySearchError> {
             ERROR!(function:"load_index_writer", "{}", e);
             TantivySearchError::TokenizerUtilsError(e)
         })?;
+        TokenizerUtils::register_query_synonyms_marker(&mut index, &column_name, tokenizer_config);
     }
 
     #[cfg(feature = "use-shared-search-pool")]
@@ -602,25 +3470,15 @@ pub fn load_index_writer(index_path: &str) -> Result<bool, TantivySearchError> {
         })?;
     }
 
-    // Create the writer with a specified buffer size (e.g., 64 MB).
-    let writer = index
-        .writer_with_num_threads(2, 1024 * 1024 * 64)
-        .map_err(|e| {
-            let error_info = format!("Failed to create tantivy writer: {}", e);
-            ERROR!(function:"load_index_writer", "{}", error_info);
-            TantivySearchError::TantivyError(e)
-        })?;
-
-    // Configure and set the merge policy.
-    let mut merge_policy = tantivy::merge_policy::LogMergePolicy::default();
-    merge_policy.set_min_num_segments(5);
-    writer.set_merge_policy(Box::new(merge_policy));
+    // Create the writer with index level settings.
+    let writer = create_index_writer("load_index_writer", &index, &index_settings)?;
 
     // Save index_writer_bridge to cache.
     let index_writer_bridge: IndexWriterBridge = IndexWriterBridge {
         index,
         path: index_path.trim_end_matches('/').to_string(),
         writer: Mutex::new(Some(writer)),
+        prepared_payload: Mutex::new(None),
     };
 
     FFI_INDEX_WRITER_CACHE
@@ -1,19 +1,45 @@
 #[cfg(test)]
 mod tests {
+    use roaring::RoaringBitmap;
     use std::cmp::min;
+    use std::net::Ipv6Addr;
+    use std::sync::Arc;
     use tantivy::collector::Count;
-    use tantivy::query::QueryParser;
+    use tantivy::query::{QueryParser, RangeQuery, TermQuery};
+    use tantivy::schema::IndexRecordOption;
+    use tantivy::store::{Compressor, ZstdCompressor};
+    use tantivy::{DocAddress, Index, TantivyDocument, Term};
     use tempfile::TempDir;
 
+    use crate::common::converter::parse_ip;
+    use crate::common::errors::TantivySearchError;
     use crate::common::tests::{
         get_mocked_docs, index_3column_docs_with_index_writer_bridge,
         search_with_index_writer_bridge,
     };
     use crate::index::implements::api_index_impl::{
-        commit_index, create_index, create_index_with_parameter, delete_row_ids, free_index_writer,
-        index_multi_column_docs,
+        abort_prepared, add_columns_to_index, apply_segment_changes, commit_index,
+        commit_index_with_payload, commit_prepared, create_index, create_index_with_parameter,
+        create_ram_index_with_parameter, create_snapshot, delete_by_range, delete_by_term,
+        delete_row_id_range, delete_row_ids, delete_row_ids_deferred, force_merge,
+        free_index_writer, get_commit_payload, get_reindex_progress, index_multi_column_docs,
+        index_multi_type_column_docs, index_multi_type_column_docs_batch,
+        index_multi_valued_column_docs, list_segment_changes, load_index_writer, merge_indexes,
+        open_or_create_index_with_parameter, persist_ram_index, prepare_commit_with_payload,
+        read_segment_file, reindex_with_parameter, release_segment_changes, release_snapshot,
+        restore_snapshot, rollback_index, split_index, upsert_multi_type_column_docs, verify_index,
+        write_segment_file,
     };
-    use crate::{FFI_INDEX_WRITER_CACHE, TEST_MUTEX};
+    use crate::search::implements::api_common_impl::{
+        free_index_reader, get_index_json_parameter, get_index_meta_json, load_index_reader,
+    };
+    use crate::search::implements::api_dingo_impl::bm25_search_with_column_names;
+    use crate::search::implements::strategy::query_strategy::{
+        ParserQueryStrategy, QueryExecutor, QueryStrategy, RangeQueryStrategy,
+        SentenceQueryStrategy, SingleTermQueryStrategy,
+    };
+    use crate::tokenizer::tokenizer_utils::TokenizerUtils;
+    use crate::{FFI_INDEX_SEARCHER_CACHE, FFI_INDEX_WRITER_CACHE, TEST_MUTEX};
 
     #[test]
     pub fn test_create_index_with_valid_tokenizer() {
@@ -32,68 +58,1853 @@ mod tests {
         let index_writer_bridge =
             index_3column_docs_with_index_writer_bridge(temp_directory_str, true);
 
-        search_with_index_writer_bridge(index_writer_bridge)
+        search_with_index_writer_bridge(index_writer_bridge)
+    }
+
+    #[test]
+    pub fn test_create_index_with_invalid_tokenizer() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+
+        let result = create_index_with_parameter(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
+            "{abc}",
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_create_index_with_not_empty_directory() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+
+        // Create index in a clean directory.
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
+            "{}",
+        )
+        .is_ok());
+        // Create index in a not empty directory.
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
+            "{}",
+        )
+        .is_ok());
+    }
+
+    #[test]
+    pub fn test_create_index_with_index_settings() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = r#"{
+            "index_settings": {
+                "writer_num_threads": 1,
+                "writer_memory_budget": 33554432,
+                "merge_policy": {"type": "no_merge"}
+            },
+            "col1": {"tokenizer": {"type": "default"}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        let index_writer_bridge =
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        let merge_policy = format!(
+            "{:?}",
+            index_writer_bridge
+                .writer
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .get_merge_policy()
+        );
+        assert!(merge_policy.contains("NoMergePolicy"));
+
+        // Persisted index settings will be applied after reload.
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(load_index_writer(temp_directory_str).is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let merge_policy = format!(
+            "{:?}",
+            index_writer_bridge
+                .writer
+                .lock()
+                .unwrap()
+                .as_ref()
+                .unwrap()
+                .get_merge_policy()
+        );
+        assert!(merge_policy.contains("NoMergePolicy"));
+
+        // Invalid merge policy tunables.
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            r#"{"index_settings": {"merge_policy": {"type": "log", "del_docs_ratio_before_merge": 2.0}}}"#,
+        )
+        .is_err());
+
+        // Invalid writer threads.
+        for invalid_index_settings in [
+            r#"{"index_settings": {"writer_num_threads": 0}}"#,
+            r#"{"index_settings": {"writer_num_threads": 9}}"#,
+        ] {
+            assert!(create_index_with_parameter(
+                temp_directory_str,
+                &column_names,
+                invalid_index_settings
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_create_index_with_docstore_settings() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = r#"{
+            "index_settings": {
+                "docstore_compressor": {"type": "zstd", "level": 3},
+                "docstore_block_size": 4096
+            },
+            "col1": {"tokenizer": {"type": "default", "store_doc": true}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+        index_3column_docs_with_index_writer_bridge(temp_directory_str, true);
+
+        // Docstore settings are kept after reload, stored docs can be read.
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(load_index_writer(temp_directory_str).is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let index_settings = index_writer_bridge.index.settings();
+        assert_eq!(
+            index_settings.docstore_compression,
+            Compressor::Zstd(ZstdCompressor {
+                compression_level: Some(3)
+            })
+        );
+        assert_eq!(index_settings.docstore_blocksize, 4096);
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        let col1_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("col1")
+            .unwrap();
+        let doc: TantivyDocument = searcher.doc(DocAddress::new(0, 0)).unwrap();
+        assert!(doc.get_first(col1_field).is_some());
+
+        for invalid_index_settings in [
+            r#"{"index_settings": {"docstore_block_size": 0}}"#,
+            r#"{"index_settings": {"docstore_compressor": {"type": "brotli"}}}"#,
+        ] {
+            assert!(create_index_with_parameter(
+                temp_directory_str,
+                &column_names,
+                invalid_index_settings
+            )
+            .is_err());
+        }
+    }
+
+    #[test]
+    pub fn test_create_index_with_custom_stop_words() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let stop_words_directory = TempDir::new().unwrap();
+        let stop_words_file = stop_words_directory.path().join("stop_words.txt");
+        std::fs::write(&stop_words_file, "empires\n\nfall\n").unwrap();
+
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = format!(
+            r#"{{
+            "col1": {{"tokenizer": {{
+                "type": "simple",
+                "stop_word_filters": ["english"],
+                "stop_words": ["Ancient"],
+                "stop_words_file": {:?},
+                "case_sensitive": true
+            }}}}
+        }}"#,
+            stop_words_file.to_str().unwrap()
+        );
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            &index_json_parameter
+        )
+        .is_ok());
+        index_3column_docs_with_index_writer_bridge(temp_directory_str, true);
+
+        // Stop words of the file are persisted with index, the file isn't needed anymore.
+        std::fs::remove_file(&stop_words_file).unwrap();
+        let stored_json_parameter = get_index_json_parameter(temp_directory_str).unwrap();
+        assert!(!stored_json_parameter.contains("stop_words_file"));
+        assert!(load_index_reader(temp_directory_str).is_ok());
+
+        let mut col_tokenizer_map =
+            TokenizerUtils::parse_tokenizer_json_to_config_map(&stored_json_parameter).unwrap();
+        let text_analyzer = &mut col_tokenizer_map.get_mut("col1").unwrap().text_analyzer;
+        let mut tokens: Vec<String> = Vec::new();
+        text_analyzer
+            .token_stream("Ancient empires rise and fall")
+            .process(&mut |token| tokens.push(token.text.clone()));
+        assert_eq!(tokens, vec!["rise".to_string()]);
+
+        // Stop words match any case of the text by default.
+        let mut col_tokenizer_map = TokenizerUtils::parse_tokenizer_json_to_config_map(
+            r#"{"col1": {"tokenizer": {"type": "simple", "stop_words": ["Ancient", "FALL"]}}}"#,
+        )
+        .unwrap();
+        let text_analyzer = &mut col_tokenizer_map.get_mut("col1").unwrap().text_analyzer;
+        let mut tokens: Vec<String> = Vec::new();
+        text_analyzer
+            .token_stream("ANCIENT empires rise and Fall")
+            .process(&mut |token| tokens.push(token.text.clone()));
+        assert_eq!(
+            tokens,
+            vec!["empires".to_string(), "rise".to_string(), "and".to_string()]
+        );
+
+        // Missing stop-word file is rejected.
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            &index_json_parameter
+        )
+        .is_err());
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(free_index_reader(temp_directory_str).is_ok());
+    }
+
+    #[test]
+    pub fn test_create_index_with_synonyms() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let synonyms_directory = TempDir::new().unwrap();
+        let synonyms_file = synonyms_directory.path().join("synonyms.txt");
+        std::fs::write(&synonyms_file, "power, strength\n\nwonders, marvels\n").unwrap();
+
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = format!(
+            r#"{{
+            "col1": {{"tokenizer": {{"type": "simple", "synonyms": {{
+                "groups": [["Old", "ancient"]], "file": {:?}, "expand": "index"
+            }}}}}},
+            "col2": {{"tokenizer": {{"type": "simple", "synonyms": {{
+                "groups": [["judgment", "verdict"]], "expand": "query"
+            }}}}}},
+            "col3": {{"tokenizer": {{"type": "stem", "stem_languages": ["english"], "synonyms": {{
+                "file": {:?}
+            }}}}}}
+        }}"#,
+            synonyms_file.to_str().unwrap(),
+            synonyms_file.to_str().unwrap()
+        );
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            &index_json_parameter
+        )
+        .is_ok());
+        index_3column_docs_with_index_writer_bridge(temp_directory_str, true);
+
+        // Synonyms of the file are persisted with index, the file isn't needed anymore.
+        std::fs::remove_file(&synonyms_file).unwrap();
+        assert!(!get_index_json_parameter(temp_directory_str)
+            .unwrap()
+            .contains("synonyms.txt"));
+        assert!(load_index_reader(temp_directory_str).is_ok());
+        let searcher = FFI_INDEX_SEARCHER_CACHE
+            .get_index_reader_bridge(temp_directory_str.to_string())
+            .unwrap()
+            .reader
+            .searcher();
+
+        for (column_name, term, expected_row_ids) in [
+            // Expanded when indexing.
+            ("col1", "old", vec![0, 4]),
+            ("col1", "strength", vec![3]),
+            // Expanded when querying.
+            ("col2", "verdict", vec![1, 4]),
+            // Synonyms are stemmed as the column text.
+            ("col3", "marvels", vec![3]),
+        ] {
+            let term_query = SingleTermQueryStrategy {
+                column_name,
+                term,
+                row_id_range: None,
+            };
+            let result = QueryExecutor::new(&term_query).execute(&searcher).unwrap();
+            assert_eq!(result.iter().collect::<Vec<u32>>(), expected_row_ids);
+        }
+
+        // Bm25 search matches any word of a synonym group.
+        for (column_name, sentence, expected_row_ids) in
+            [("col2", "verdict", vec![1, 4]), ("col1", "old", vec![0, 4])]
+        {
+            let mut row_ids: Vec<u64> = bm25_search_with_column_names(
+                temp_directory_str,
+                sentence,
+                10,
+                &vec![],
+                false,
+                false,
+                0,
+                0,
+                false,
+                &vec![column_name.to_string()],
+                false,
+            )
+            .unwrap()
+            .iter()
+            .map(|item| item.row_id)
+            .collect();
+            row_ids.sort();
+            assert_eq!(row_ids, expected_row_ids);
+        }
+
+        // Phrases match any word of a synonym group in place, their order is kept.
+        for (sentence, expected_row_ids) in [
+            ("\"verdict through\"", vec![1]),
+            ("\"judgment through\"", vec![1]),
+            ("\"through verdict\"", vec![]),
+            ("\"reasoning and verdict\"", vec![4]),
+        ] {
+            let parser_query = ParserQueryStrategy {
+                column_name: "col2",
+                sentence,
+            };
+            let result = QueryExecutor::new(&parser_query)
+                .execute(&searcher)
+                .unwrap();
+            assert_eq!(result.iter().collect::<Vec<u32>>(), expected_row_ids);
+        }
+
+        // An in-memory index is searched with the indexing analyzer.
+        assert!(create_ram_index_with_parameter(
+            "synonyms_ram_index",
+            &vec!["col2".to_string()],
+            r#"{"col2": {"tokenizer": {"type": "simple", "synonyms": {
+                "groups": [["judgment", "verdict"]], "expand": "query"
+            }}}}"#
+        )
+        .is_err());
+
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(free_index_reader(temp_directory_str).is_ok());
+    }
+
+    #[test]
+    pub fn test_ngram_phrase_without_synonyms() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["title".to_string()];
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            r#"{"title": {"tokenizer": {"type": "ngram", "min_gram": 2, "max_gram": 3}}}"#
+        )
+        .is_ok());
+        for (row_id, title) in [(0, "xbc ab"), (1, "abc")] {
+            assert!(index_multi_column_docs(
+                temp_directory_str,
+                row_id,
+                &column_names,
+                &vec![title.to_string()]
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+        assert!(load_index_reader(temp_directory_str).is_ok());
+        let searcher = FFI_INDEX_SEARCHER_CACHE
+            .get_index_reader_bridge(temp_directory_str.to_string())
+            .unwrap()
+            .reader
+            .searcher();
+
+        // Grams of a column without synonyms are all required, "xbc ab" lacks "abc".
+        let parser_query = ParserQueryStrategy {
+            column_name: "title",
+            sentence: "abc",
+        };
+        let result = QueryExecutor::new(&parser_query)
+            .execute(&searcher)
+            .unwrap();
+        assert_eq!(result.iter().collect::<Vec<u32>>(), vec![1]);
+
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(free_index_reader(temp_directory_str).is_ok());
+    }
+
+    #[test]
+    pub fn test_create_index_sorted_by_row_id() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string()];
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            r#"{"index_settings": {"sort_by_row_id": true}}"#,
+        )
+        .is_ok());
+
+        // Index docs in descending row_id order.
+        for row_id in (0..10u64).rev() {
+            assert!(index_multi_column_docs(
+                temp_directory_str,
+                row_id,
+                &column_names,
+                &vec![format!("apple {}", row_id)]
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        // Sort setting is kept after reload, docs in segment are ordered by row_id.
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(load_index_writer(temp_directory_str).is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let sort_by_field = index_writer_bridge.index.settings().sort_by_field.clone();
+        assert_eq!(sort_by_field.unwrap().field, "row_id");
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        for segment_reader in searcher.segment_readers() {
+            let row_id_column = segment_reader
+                .fast_fields()
+                .u64("row_id")
+                .unwrap()
+                .first_or_default_col(0);
+            let row_ids: Vec<u64> = (0..segment_reader.max_doc())
+                .map(|doc| row_id_column.get_val(doc))
+                .collect();
+            assert!(row_ids.windows(2).all(|pair| pair[0] <= pair[1]));
+        }
+
+        // Range queries stop once row_id exceeds the range end.
+        assert!(load_index_reader(temp_directory_str).is_ok());
+        for query_unlimited in [false, true] {
+            let mut row_ids: Vec<u64> = bm25_search_with_column_names(
+                temp_directory_str,
+                "apple",
+                10,
+                &vec![],
+                false,
+                true,
+                3,
+                7,
+                false,
+                &vec![],
+                query_unlimited,
+            )
+            .unwrap()
+            .iter()
+            .map(|item| item.row_id)
+            .collect();
+            row_ids.sort();
+            assert_eq!(row_ids, vec![3, 4, 5, 6]);
+        }
+        assert!(free_index_reader(temp_directory_str).unwrap());
+    }
+
+    #[test]
+    pub fn test_open_or_create_index_with_parameter() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = r#"{"col1": {"tokenizer": {"type": "default"}}}"#;
+
+        // Create index in a clean directory and commit some docs.
+        assert!(open_or_create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter,
+            false
+        )
+        .is_ok());
+        index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+
+        // Compatible index is opened, committed docs are kept.
+        assert!(open_or_create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter,
+            false
+        )
+        .is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        assert_eq!(
+            index_writer_bridge
+                .index
+                .reader()
+                .unwrap()
+                .searcher()
+                .num_docs(),
+            5
+        );
+
+        // Incompatible column configs or schema.
+        assert!(open_or_create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            r#"{"col1": {"tokenizer": {"type": "raw"}}}"#,
+            false
+        )
+        .is_err());
+        assert!(open_or_create_index_with_parameter(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string()],
+            index_json_parameter,
+            false
+        )
+        .is_err());
+
+        // Wipe existing index when explicitly asked.
+        assert!(open_or_create_index_with_parameter(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string()],
+            index_json_parameter,
+            true
+        )
+        .is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        assert_eq!(
+            index_writer_bridge
+                .index
+                .reader()
+                .unwrap()
+                .searcher()
+                .num_docs(),
+            0
+        );
+    }
+
+    #[test]
+    pub fn test_create_index_by_default() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+
+        assert!(create_index(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()]
+        )
+        .is_ok());
+        assert!(create_index(
+            temp_directory_str,
+            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()]
+        )
+        .is_ok());
+    }
+
+    #[test]
+    pub fn test_index_multi_column_docs() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+
+        assert!(create_index(temp_directory_str, &column_names).is_ok());
+
+        // Index some documents.
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+
+        for row_id in 0..min(min(col1_docs.len(), col2_docs.len()), col3_docs.len()) {
+            let column_docs = vec![
+                col1_docs[row_id].clone(),
+                col2_docs[row_id].clone(),
+                col3_docs[row_id].clone(),
+            ];
+            assert!(index_multi_column_docs(
+                temp_directory_str,
+                row_id as u64,
+                &column_names,
+                &column_docs
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        // get index writer from CACHE
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+
+        search_with_index_writer_bridge(index_writer_bridge)
+    }
+
+    #[test]
+    pub fn test_index_multi_type_column_docs_batch() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec![
+            "text".to_string(),
+            "num".to_string(),
+            "dt".to_string(),
+            "hash".to_string(),
+            "addr".to_string(),
+        ];
+        let index_json_parameter = r#"{
+            "text": {"tokenizer": {"type": "default"}},
+            "num": {"tokenizer": {"type": "i64"}},
+            "dt": {"tokenizer": {"type": "datetime"}},
+            "hash": {"tokenizer": {"type": "u64"}},
+            "addr": {"tokenizer": {"type": "ip"}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        // Values are laid out column by column, row_id 2 carries an invalid datetime,
+        // row_id 1 carries an invalid ip.
+        let row_ids: Vec<u64> = vec![0, 1, 2];
+        let text_docs: Vec<String> = vec![
+            "Ancient empires rise and fall".to_string(),
+            "Ancient philosophies provide wisdom".to_string(),
+            "Artistic expressions reflect heritages".to_string(),
+        ];
+        let i64_docs: Vec<i64> = vec![10, 20, 30];
+        let date_docs: Vec<String> = vec![
+            "2024-05-20T12:00:00Z".to_string(),
+            "2024-05-21T12:00:00Z".to_string(),
+            "not a datetime".to_string(),
+        ];
+        let u64_docs: Vec<u64> = vec![u64::MAX, 1, 2];
+        let ip_docs: Vec<String> = vec![
+            "192.168.1.10".to_string(),
+            "not an ip".to_string(),
+            "::1".to_string(),
+        ];
+
+        let failed_rows = index_multi_type_column_docs_batch(
+            temp_directory_str,
+            &row_ids,
+            &vec!["text".to_string()],
+            &text_docs,
+            &vec!["num".to_string()],
+            &i64_docs,
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec!["dt".to_string()],
+            &date_docs,
+            &vec![],
+            &vec![],
+            &vec!["hash".to_string()],
+            &u64_docs,
+            &vec!["addr".to_string()],
+            &ip_docs,
+        )
+        .unwrap();
+        let mut failed_row_ids: Vec<u64> = failed_rows.iter().map(|row| row.row_id).collect();
+        failed_row_ids.sort();
+        assert_eq!(failed_row_ids, vec![1, 2]);
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let text_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("text")
+            .unwrap();
+        let query_parser = QueryParser::for_index(&index_writer_bridge.index, vec![text_field]);
+        let text_query = query_parser.parse_query("Ancient").unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 1);
+        assert_eq!(searcher.search(&text_query, &Count).unwrap(), 1);
+        let schema = index_writer_bridge.index.schema();
+        let hash_query = TermQuery::new(
+            Term::from_field_u64(schema.get_field("hash").unwrap(), u64::MAX),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&hash_query, &Count).unwrap(), 1);
+        let addr_query = TermQuery::new(
+            Term::from_field_ip_addr(
+                schema.get_field("addr").unwrap(),
+                parse_ip("192.168.1.10").unwrap(),
+            ),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&addr_query, &Count).unwrap(), 1);
+
+        // Values size doesn't match `column_names * row_ids` will fail the whole batch.
+        assert!(index_multi_type_column_docs_batch(
+            temp_directory_str,
+            &row_ids,
+            &vec!["text".to_string()],
+            &vec!["Ancient".to_string()],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_index_multi_valued_column_docs() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec![
+            "tags".to_string(),
+            "scores".to_string(),
+            "addrs".to_string(),
+        ];
+        let index_json_parameter = r#"{
+            "tags": {"tokenizer": {"type": "default"}},
+            "scores": {"tokenizer": {"type": "i64"}},
+            "addrs": {"tokenizer": {"type": "ip"}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        // Row 0 has 2 tags, 3 scores and 2 addrs, row 1 has 1 tag, no score and 1 addr.
+        let rows: Vec<(Vec<String>, Vec<i64>, Vec<Ipv6Addr>)> = vec![
+            (
+                vec!["ancient".to_string(), "history".to_string()],
+                vec![1, 5, 9],
+                vec![parse_ip("10.0.0.1").unwrap(), parse_ip("10.0.0.2").unwrap()],
+            ),
+            (
+                vec!["modern art".to_string()],
+                vec![],
+                vec![parse_ip("10.0.0.3").unwrap()],
+            ),
+        ];
+        for (row_id, (tags, scores, addrs)) in rows.iter().enumerate() {
+            assert!(index_multi_valued_column_docs(
+                temp_directory_str,
+                row_id as u64,
+                &vec!["tags".to_string()],
+                &vec![tags.len() as u64],
+                tags,
+                &vec!["scores".to_string()],
+                &vec![scores.len() as u64],
+                scores,
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec!["addrs".to_string()],
+                &vec![addrs.len() as u64],
+                addrs,
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let schema = index_writer_bridge.index.schema();
+        let tags_field = schema.get_field("tags").unwrap();
+        let scores_field = schema.get_field("scores").unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        let query_parser = QueryParser::for_index(&index_writer_bridge.index, vec![tags_field]);
+
+        // Any of the values can be matched.
+        let text_query = query_parser.parse_query("history").unwrap();
+        assert_eq!(searcher.search(&text_query, &Count).unwrap(), 1);
+        let term_query = TermQuery::new(
+            Term::from_field_i64(scores_field, 5),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&term_query, &Count).unwrap(), 1);
+        let range_query = RangeQuery::new_i64("scores".to_string(), 6..10);
+        assert_eq!(searcher.search(&range_query, &Count).unwrap(), 1);
+        let addr_query = TermQuery::new(
+            Term::from_field_ip_addr(
+                schema.get_field("addrs").unwrap(),
+                parse_ip("10.0.0.2").unwrap(),
+            ),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&addr_query, &Count).unwrap(), 1);
+
+        // Offsets size doesn't match column names, or out of range.
+        assert!(index_multi_valued_column_docs(
+            temp_directory_str,
+            2,
+            &vec!["tags".to_string()],
+            &vec![3],
+            &vec!["ancient".to_string()],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_index_multi_type_column_docs_with_json() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["title".to_string(), "attrs".to_string()];
+        let index_json_parameter = r#"{
+            "title": {"tokenizer": {"type": "default"}},
+            "attrs": {"tokenizer": {"type": "json", "tokenizer": {"type": "default"}}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        let json_docs = vec![
+            r#"{"color": "red", "size": {"name": "large"}}"#,
+            r#"{"color": "blue red", "size": {"name": "small"}}"#,
+            r#"{"color": "green"}"#,
+        ];
+        for (row_id, json_doc) in json_docs.iter().enumerate() {
+            assert!(index_multi_type_column_docs(
+                temp_directory_str,
+                row_id as u64,
+                &vec!["title".to_string()],
+                &vec!["Ancient".to_string()],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec!["attrs".to_string()],
+                &vec![json_doc.to_string()],
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+
+        // Query nested keys with a dotted path column name.
+        let term_query = SingleTermQueryStrategy {
+            column_name: "attrs.color",
+            term: "red",
+            row_id_range: None,
+        };
+        let result = QueryExecutor::new(&term_query).execute(&searcher).unwrap();
+        assert_eq!(result.iter().collect::<Vec<u32>>(), vec![0, 1]);
+        let term_query = SingleTermQueryStrategy {
+            column_name: "attrs.size.name",
+            term: "small",
+            row_id_range: None,
+        };
+        let result = QueryExecutor::new(&term_query).execute(&searcher).unwrap();
+        assert_eq!(result.iter().collect::<Vec<u32>>(), vec![1]);
+        let parser_query = ParserQueryStrategy {
+            column_name: "attrs",
+            sentence: "attrs.color:green",
+        };
+        let result = QueryExecutor::new(&parser_query)
+            .execute(&searcher)
+            .unwrap();
+        assert_eq!(result.iter().collect::<Vec<u32>>(), vec![2]);
+
+        // Json column can't be queried without a path.
+        let term_query = SingleTermQueryStrategy {
+            column_name: "attrs",
+            term: "red",
+            row_id_range: None,
+        };
+        assert!(QueryExecutor::new(&term_query).execute(&searcher).is_err());
+
+        // Json doc should be a json object.
+        assert!(index_multi_type_column_docs(
+            temp_directory_str,
+            3,
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec!["attrs".to_string()],
+            &vec!["[1, 2]".to_string()],
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_index_u64_and_ip_columns() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["hash".to_string(), "addr".to_string()];
+        let index_json_parameter = r#"{
+            "hash": {"tokenizer": {"type": "u64"}},
+            "addr": {"tokenizer": {"type": "ip", "fast": true}}
+        }"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        let rows: Vec<(u64, &str)> = vec![
+            (u64::MAX, "192.168.1.10"),
+            (42, "192.168.2.1"),
+            (1 << 63, "2001:db8::1"),
+        ];
+        for (row_id, (hash, addr)) in rows.iter().enumerate() {
+            assert!(index_multi_type_column_docs(
+                temp_directory_str,
+                row_id as u64,
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec!["hash".to_string()],
+                &vec![*hash],
+                &vec!["addr".to_string()],
+                &vec![parse_ip(addr).unwrap()],
+                &vec![],
+                &vec![],
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        let row_ids = |strategy: &dyn QueryStrategy<Arc<RoaringBitmap>>| {
+            QueryExecutor::new(strategy)
+                .execute(&searcher)
+                .unwrap()
+                .iter()
+                .collect::<Vec<u32>>()
+        };
+
+        // Values overflow i64 are kept as is.
+        let term_query = SingleTermQueryStrategy {
+            column_name: "hash",
+            term: &u64::MAX.to_string(),
+            row_id_range: None,
+        };
+        assert_eq!(row_ids(&term_query), vec![0]);
+        let range_query = RangeQueryStrategy {
+            column_name: "hash",
+            lower_bound: "43",
+            upper_bound: "",
+        };
+        assert_eq!(row_ids(&range_query), vec![0, 2]);
+
+        // IPv4 and IPv6 addresses can be queried together.
+        let term_query = SingleTermQueryStrategy {
+            column_name: "addr",
+            term: "2001:db8::1",
+            row_id_range: None,
+        };
+        assert_eq!(row_ids(&term_query), vec![2]);
+        let range_query = RangeQueryStrategy {
+            column_name: "addr",
+            lower_bound: "192.168.1.0",
+            upper_bound: "192.168.1.255",
+        };
+        assert_eq!(row_ids(&range_query), vec![0]);
+
+        // Invalid values are rejected.
+        let term_query = SingleTermQueryStrategy {
+            column_name: "addr",
+            term: "not an ip",
+            row_id_range: None,
+        };
+        assert!(QueryExecutor::new(&term_query).execute(&searcher).is_err());
+        let range_query = RangeQueryStrategy {
+            column_name: "hash",
+            lower_bound: "-1",
+            upper_bound: "",
+        };
+        assert!(QueryExecutor::new(&range_query).execute(&searcher).is_err());
+    }
+
+    #[test]
+    pub fn test_add_columns_to_index() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let index_json_parameter = r#"{"title": {"tokenizer": {"type": "default"}}}"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &vec!["title".to_string()],
+            index_json_parameter
+        )
+        .is_ok());
+        // Pending document should be committed before schema changed.
+        assert!(index_multi_column_docs(
+            temp_directory_str,
+            0,
+            &vec!["title".to_string()],
+            &vec!["Ancient empires".to_string()]
+        )
+        .is_ok());
+
+        let new_column_names = vec!["tags".to_string()];
+        let new_json_parameter = r#"{"tags": {"tokenizer": {"type": "raw"}}}"#;
+        assert!(
+            add_columns_to_index(temp_directory_str, &new_column_names, new_json_parameter).is_ok()
+        );
+        assert!(index_multi_column_docs(
+            temp_directory_str,
+            1,
+            &vec!["title".to_string(), "tags".to_string()],
+            &vec!["Modern art".to_string(), "Gallery".to_string()]
+        )
+        .is_ok());
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        // Merged config is persisted, the index can be reloaded.
+        let stored_json_parameter: serde_json::Value =
+            serde_json::from_str(&get_index_json_parameter(temp_directory_str).unwrap()).unwrap();
+        assert!(stored_json_parameter.get("title").is_some());
+        assert!(stored_json_parameter.get("tags").is_some());
+        assert!(free_index_writer(temp_directory_str).is_ok());
+        assert!(load_index_writer(temp_directory_str).is_ok());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let schema = index_writer_bridge.index.schema();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        let title_parser = QueryParser::for_index(
+            &index_writer_bridge.index,
+            vec![schema.get_field("title").unwrap()],
+        );
+        assert_eq!(
+            searcher
+                .search(&title_parser.parse_query("ancient").unwrap(), &Count)
+                .unwrap(),
+            1
+        );
+        let term_query = TermQuery::new(
+            Term::from_field_text(schema.get_field("tags").unwrap(), "Gallery"),
+            IndexRecordOption::Basic,
+        );
+        assert_eq!(searcher.search(&term_query, &Count).unwrap(), 1);
+
+        // Segments written before the column is added can be merged with new ones.
+        let segment_ids_before = index_writer_bridge.index.searchable_segment_ids().unwrap();
+        assert!(add_columns_to_index(
+            temp_directory_str,
+            &vec!["year".to_string()],
+            r#"{"year": {"tokenizer": {"type": "i64", "fast": true}}}"#
+        )
+        .is_ok());
+        // Segment files are immutable, rewritten segments get new ids.
+        let segment_ids_after = index_writer_bridge.index.searchable_segment_ids().unwrap();
+        assert_eq!(segment_ids_after.len(), segment_ids_before.len());
+        assert!(segment_ids_after
+            .iter()
+            .all(|segment_id| !segment_ids_before.contains(segment_id)));
+        assert!(index_multi_type_column_docs(
+            temp_directory_str,
+            2,
+            &vec!["title".to_string()],
+            &vec!["Modern sculpture".to_string()],
+            &vec!["year".to_string()],
+            &vec![1990],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+        )
+        .is_ok());
+        assert!(commit_index(temp_directory_str).is_ok());
+        assert!(force_merge(temp_directory_str, 1).is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        assert_eq!(searcher.num_docs(), 3);
+        let range_query = RangeQuery::new_i64("year".to_string(), 1900..2000);
+        assert_eq!(searcher.search(&range_query, &Count).unwrap(), 1);
+        assert_eq!(
+            searcher
+                .search(&title_parser.parse_query("modern").unwrap(), &Count)
+                .unwrap(),
+            2
+        );
+
+        // Existing columns and index settings can't be changed.
+        assert!(
+            add_columns_to_index(temp_directory_str, &new_column_names, new_json_parameter)
+                .is_err()
+        );
+        assert!(add_columns_to_index(
+            temp_directory_str,
+            &vec!["extra".to_string()],
+            r#"{"index_settings": {"writer_num_threads": 4}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_reindex_with_parameter() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let index_path = temp_directory.path().join("index");
+        let index_path_str = index_path.to_str().unwrap();
+        let column_names = vec!["title".to_string(), "score".to_string()];
+        let index_json_parameter = r#"{
+            "title": {"tokenizer": {"type": "simple", "store_doc": true}},
+            "score": {"tokenizer": {"type": "i64", "store_doc": true}}
+        }"#;
+        assert!(
+            create_index_with_parameter(index_path_str, &column_names, index_json_parameter)
+                .is_ok()
+        );
+        let titles = vec!["Running dogs", "Cats sleeping", "A dog runs"];
+        for (row_id, title) in titles.iter().enumerate() {
+            assert!(index_multi_type_column_docs(
+                index_path_str,
+                (row_id * 10) as u64,
+                &vec!["title".to_string()],
+                &vec![title.to_string()],
+                &vec!["score".to_string()],
+                &vec![row_id as i64],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+            )
+            .is_ok());
+        }
+        assert!(delete_row_ids(index_path_str, &vec![10]).is_ok());
+        assert!(commit_index(index_path_str).is_ok());
+
+        let row_ids = |sentence: &str| {
+            let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+                .get_index_writer_bridge(index_path_str.to_string())
+                .unwrap();
+            let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+            let sentence_query = SentenceQueryStrategy {
+                column_name: "title",
+                sentence,
+                row_id_range: None,
+            };
+            QueryExecutor::new(&sentence_query)
+                .execute(&searcher)
+                .unwrap()
+                .iter()
+                .collect::<Vec<u32>>()
+        };
+        assert_eq!(row_ids("run"), Vec::<u32>::new());
+
+        // Stem tokenizer makes `running` and `runs` searchable by `run`.
+        let new_json_parameter = r#"{
+            "title": {"tokenizer": {"type": "stem", "stem_languages": ["English"], "store_doc": true}},
+            "score": {"tokenizer": {"type": "i64", "store_doc": true}}
+        }"#;
+        assert_eq!(
+            reindex_with_parameter(index_path_str, new_json_parameter).unwrap(),
+            2
+        );
+        assert_eq!(get_reindex_progress(index_path_str).unwrap(), (2, 2, true));
+        assert_eq!(row_ids("run"), vec![0, 20]);
+        assert_eq!(
+            get_index_json_parameter(index_path_str).unwrap(),
+            new_json_parameter
+        );
+        assert!(!temp_directory.path().join("index.reindex").exists());
+        assert!(!temp_directory.path().join("index.reindex_backup").exists());
+
+        // Column type can't be changed.
+        let invalid_json_parameter = r#"{
+            "title": {"tokenizer": {"type": "stem", "stem_languages": ["English"], "store_doc": true}},
+            "score": {"tokenizer": {"type": "f64", "store_doc": true}}
+        }"#;
+        assert!(reindex_with_parameter(index_path_str, invalid_json_parameter).is_err());
+        assert_eq!(row_ids("run"), vec![0, 20]);
+        // Released writer is reloaded after a failed reindex.
+        assert!(FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(index_path_str.to_string())
+            .is_ok());
+    }
+
+    #[test]
+    pub fn test_reindex_without_stored_columns() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["title".to_string()];
+        let index_json_parameter = r#"{"title": {"tokenizer": {"type": "default"}}}"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        assert!(reindex_with_parameter(
+            temp_directory_str,
+            r#"{"title": {"tokenizer": {"type": "raw"}}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    pub fn test_snapshot_create_release_restore() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let index_path = temp_directory.path().join("index");
+        let index_path_str = index_path.to_str().unwrap();
+        let snapshot_path = temp_directory.path().join("snapshot");
+        let snapshot_path_str = snapshot_path.to_str().unwrap();
+        let restored_path = temp_directory.path().join("restored");
+        let restored_path_str = restored_path.to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(index_path_str, &column_names).is_ok());
+        index_3column_docs_with_index_writer_bridge(index_path_str, false);
+
+        let opstamp = create_snapshot(index_path_str, snapshot_path_str).unwrap();
+        assert!(snapshot_path.join("meta.json").exists());
+        assert!(snapshot_path.join("custom_index_setting.json").exists());
+        assert!(create_snapshot(index_path_str, snapshot_path_str).is_err());
+
+        // Later commits and merges don't affect the snapshot.
+        index_3column_docs_with_index_writer_bridge(index_path_str, false);
+        assert_eq!(force_merge(index_path_str, 1).unwrap(), (2, 1));
+        assert!(release_snapshot(snapshot_path_str).unwrap());
+        assert!(!release_snapshot(snapshot_path_str).unwrap());
+
+        assert!(
+            restore_snapshot(temp_directory.path().to_str().unwrap(), restored_path_str).is_err()
+        );
+        assert!(restore_snapshot(snapshot_path_str, restored_path_str).unwrap());
+        let restored_index = Index::open_in_dir(&restored_path).unwrap();
+        assert_eq!(restored_index.load_metas().unwrap().opstamp, opstamp);
+        assert_eq!(restored_index.reader().unwrap().searcher().num_docs(), 5);
+
+        // Restored index can be loaded and written.
+        assert!(load_index_writer(restored_path_str).is_ok());
+        index_3column_docs_with_index_writer_bridge(restored_path_str, false);
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(restored_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 10);
+
+        // A broken snapshot keeps the existing index, its writer is reloaded.
+        let broken_path = temp_directory.path().join("broken");
+        std::fs::create_dir(&broken_path).unwrap();
+        std::fs::write(broken_path.join("meta.json"), "broken").unwrap();
+        std::fs::copy(
+            snapshot_path.join("custom_index_setting.json"),
+            broken_path.join("custom_index_setting.json"),
+        )
+        .unwrap();
+        assert!(restore_snapshot(broken_path.to_str().unwrap(), restored_path_str).is_err());
+        assert!(!temp_directory.path().join("restored.restore").exists());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(restored_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 10);
+
+        // Restoring over an existing index swaps in the snapshot.
+        assert!(restore_snapshot(snapshot_path_str, restored_path_str).unwrap());
+        assert!(!temp_directory.path().join("restored.restore").exists());
+        assert!(!temp_directory
+            .path()
+            .join("restored.restore_backup")
+            .exists());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(restored_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 5);
+    }
+
+    #[test]
+    pub fn test_segment_replication() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let leader_path = temp_directory.path().join("leader");
+        let leader_path_str = leader_path.to_str().unwrap();
+        let follower_path = temp_directory.path().join("follower");
+        let follower_path_str = follower_path.to_str().unwrap();
+        let snapshot_path = temp_directory.path().join("snapshot");
+        let snapshot_path_str = snapshot_path.to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(leader_path_str, &column_names).is_ok());
+        index_3column_docs_with_index_writer_bridge(leader_path_str, false);
+
+        // Follower starts from a snapshot of leader.
+        assert!(create_snapshot(leader_path_str, snapshot_path_str).is_ok());
+        assert!(restore_snapshot(snapshot_path_str, follower_path_str).unwrap());
+        assert!(release_snapshot(snapshot_path_str).unwrap());
+
+        // Ship segment changes of leader to follower.
+        let replicate = || {
+            let follower_files = list_segment_changes(follower_path_str, "self", &vec![])
+                .unwrap()
+                .added_files;
+            assert!(release_segment_changes(follower_path_str, "self").unwrap());
+            let segment_changes =
+                list_segment_changes(leader_path_str, "follower", &follower_files).unwrap();
+            for file_name in segment_changes.added_files.iter() {
+                let content = read_segment_file(leader_path_str, "follower", file_name).unwrap();
+                assert!(write_segment_file(follower_path_str, file_name, &content).unwrap());
+            }
+            assert!(apply_segment_changes(
+                follower_path_str,
+                &segment_changes.meta_json,
+                &segment_changes.removed_files
+            )
+            .unwrap());
+            let follower_index = Index::open_in_dir(&follower_path).unwrap();
+            assert_eq!(
+                follower_index.load_metas().unwrap().opstamp,
+                segment_changes.opstamp
+            );
+            (
+                follower_index.reader().unwrap().searcher().num_docs(),
+                segment_changes,
+            )
+        };
+
+        index_3column_docs_with_index_writer_bridge(leader_path_str, false);
+        assert!(delete_row_ids(leader_path_str, &vec![0]).is_ok());
+        let (num_docs, segment_changes) = replicate();
+        assert_eq!(num_docs, 8);
+        assert!(!segment_changes.added_files.is_empty());
+
+        // Merged segments are removed from follower.
+        assert_eq!(force_merge(leader_path_str, 1).unwrap(), (2, 1));
+        let (num_docs, segment_changes) = replicate();
+        assert_eq!(num_docs, 8);
+        assert!(!segment_changes.removed_files.is_empty());
+        for file_name in segment_changes.removed_files.iter() {
+            assert!(!follower_path.join(file_name).exists());
+        }
+
+        // Only listed segment files can be read.
+        assert!(read_segment_file(leader_path_str, "follower", "meta.json").is_err());
+        assert!(read_segment_file(leader_path_str, "follower", "../leader/meta.json").is_err());
+        assert!(write_segment_file(follower_path_str, "../meta.json", &[]).is_err());
+
+        // Pins of each follower are kept until its next listing or release.
+        index_3column_docs_with_index_writer_bridge(leader_path_str, false);
+        let segment_changes = list_segment_changes(leader_path_str, "follower", &vec![]).unwrap();
+        let other_changes = list_segment_changes(leader_path_str, "other", &vec![]).unwrap();
+        assert_eq!(segment_changes.added_files, other_changes.added_files);
+        let file_name = &segment_changes.added_files[0];
+        assert!(read_segment_file(leader_path_str, "follower", file_name).is_ok());
+        assert!(read_segment_file(leader_path_str, "unknown", file_name).is_err());
+        assert!(release_segment_changes(leader_path_str, "other").unwrap());
+        assert!(!release_segment_changes(leader_path_str, "other").unwrap());
+        assert!(read_segment_file(leader_path_str, "follower", file_name).is_ok());
+
+        // Old meta.json is kept if segment files are missing.
+        assert!(
+            apply_segment_changes(follower_path_str, &segment_changes.meta_json, &vec![]).is_err()
+        );
+        let follower_index = Index::open_in_dir(&follower_path).unwrap();
+        assert_eq!(follower_index.reader().unwrap().searcher().num_docs(), 8);
+    }
+
+    #[test]
+    pub fn test_merge_indexes() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let target_path = temp_directory.path().join("target");
+        let target_path_str = target_path.to_str().unwrap();
+        let source_path = temp_directory.path().join("source");
+        let source_path_str = source_path.to_str().unwrap().to_string();
+        let other_path = temp_directory.path().join("other");
+        let other_path_str = other_path.to_str().unwrap().to_string();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(target_path_str, &column_names).is_ok());
+        index_3column_docs_with_index_writer_bridge(target_path_str, false);
+
+        // Row ids 3 and 4 exist in both target and source.
+        assert!(create_index(&source_path_str, &column_names).is_ok());
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        for row_id in 3..7 {
+            assert!(index_multi_column_docs(
+                &source_path_str,
+                row_id,
+                &column_names,
+                &vec![
+                    col1_docs[0].clone(),
+                    col2_docs[0].clone(),
+                    col3_docs[0].clone()
+                ],
+            )
+            .is_ok());
+        }
+        assert!(commit_index(&source_path_str).is_ok());
+
+        let source_paths = vec![source_path_str.clone()];
+        assert!(merge_indexes(target_path_str, &source_paths, "unknown").is_err());
+        assert!(merge_indexes(target_path_str, &source_paths, "fail").is_err());
+        assert!(!temp_directory.path().join("target.merge_0").exists());
+        assert_eq!(
+            merge_indexes(target_path_str, &source_paths, "keep_target").unwrap(),
+            2
+        );
+        assert!(!temp_directory.path().join("target.merge_0").exists());
+
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(target_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 7);
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(source_path_str.clone())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 4);
+
+        // Attached segments can be merged with target segments.
+        assert_eq!(force_merge(target_path_str, 1).unwrap(), (2, 1));
+
+        // Indexes with different json parameters can't be merged.
+        let index_json_parameter = r#"{"col1": {"tokenizer": {"type": "raw"}}}"#;
+        assert!(
+            create_index_with_parameter(&other_path_str, &column_names, index_json_parameter)
+                .is_ok()
+        );
+        assert!(matches!(
+            merge_indexes(target_path_str, &vec![other_path_str.clone()], "fail"),
+            Err(TantivySearchError::IncompatibleIndex(_))
+        ));
+        assert!(
+            merge_indexes(target_path_str, &vec![target_path_str.to_string()], "fail").is_err()
+        );
+
+        // A copy of target shares its segment ids, row 0 stays alive only in the copy so that
+        // its segment survives staging. Attaching fails and the writer of target is reloaded.
+        let copy_path = temp_directory.path().join("copy");
+        std::fs::create_dir(&copy_path).unwrap();
+        for entry in std::fs::read_dir(&target_path).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), copy_path.join(entry.file_name())).unwrap();
+        }
+        assert!(delete_row_ids(target_path_str, &vec![0]).is_ok());
+        assert!(matches!(
+            merge_indexes(
+                target_path_str,
+                &vec![copy_path.to_str().unwrap().to_string()],
+                "keep_target"
+            ),
+            Err(TantivySearchError::IncompatibleIndex(_))
+        ));
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(target_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 6);
+    }
+
+    #[test]
+    pub fn test_merge_indexes_with_repeated_row_ids() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let target_path = temp_directory.path().join("target");
+        let target_path_str = target_path.to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(target_path_str, &column_names).is_ok());
+        index_3column_docs_with_index_writer_bridge(target_path_str, false);
+
+        // Row id 5 is repeated inside the first source, row id 6 exists in both sources.
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        let row_docs = vec![
+            col1_docs[0].clone(),
+            col2_docs[0].clone(),
+            col3_docs[0].clone(),
+        ];
+        let mut source_paths: Vec<String> = Vec::new();
+        for (source_name, row_ids) in [("first", vec![5, 5, 6]), ("second", vec![6, 7])] {
+            let source_path = temp_directory.path().join(source_name);
+            let source_path_str = source_path.to_str().unwrap().to_string();
+            assert!(create_index(&source_path_str, &column_names).is_ok());
+            for row_id in row_ids {
+                assert!(index_multi_column_docs(
+                    &source_path_str,
+                    row_id,
+                    &column_names,
+                    &row_docs
+                )
+                .is_ok());
+            }
+            assert!(commit_index(&source_path_str).is_ok());
+            source_paths.push(source_path_str);
+        }
+
+        // Repeated row ids of a source are kept, only row ids seen before the source are deleted.
+        assert_eq!(
+            merge_indexes(target_path_str, &source_paths, "keep_target").unwrap(),
+            4
+        );
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(target_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 9);
+        let row_id_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("row_id")
+            .unwrap();
+        for (row_id, expected_count) in [(5, 2), (6, 1), (7, 1)] {
+            let term_query = TermQuery::new(
+                Term::from_field_u64(row_id_field, row_id),
+                IndexRecordOption::Basic,
+            );
+            assert_eq!(
+                searcher.search(&term_query, &Count).unwrap(),
+                expected_count
+            );
+        }
     }
 
     #[test]
-    pub fn test_create_index_with_invalid_tokenizer() {
+    pub fn test_split_index() {
         let _guard = TEST_MUTEX.lock().unwrap();
 
         let temp_directory = TempDir::new().unwrap();
-        let temp_directory_str = temp_directory.path().to_str().unwrap();
-
-        let result = create_index_with_parameter(
-            temp_directory_str,
-            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
-            "{abc}",
+        let source_path = temp_directory.path().join("source");
+        let source_path_str = source_path.to_str().unwrap();
+        let left_path = temp_directory.path().join("left");
+        let left_path_str = left_path.to_str().unwrap();
+        let right_path = temp_directory.path().join("right");
+        let right_path_str = right_path.to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = r#"{"col1": {"tokenizer": {"type": "raw"}}}"#;
+        assert!(
+            create_index_with_parameter(source_path_str, &column_names, index_json_parameter)
+                .is_ok()
         );
-        assert!(result.is_err());
+        index_3column_docs_with_index_writer_bridge(source_path_str, false);
+
+        assert!(split_index(source_path_str, 2, source_path_str, right_path_str).is_err());
+        assert!(split_index(source_path_str, 2, left_path_str, right_path_str).unwrap());
+
+        // Rows of the other half are purged, both halves can be loaded.
+        for (half_path_str, expected_row_ids) in
+            [(left_path_str, vec![0, 1]), (right_path_str, vec![2, 3, 4])]
+        {
+            assert_eq!(
+                get_index_json_parameter(half_path_str).unwrap(),
+                index_json_parameter
+            );
+            assert!(load_index_writer(half_path_str).is_ok());
+            let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+                .get_index_writer_bridge(half_path_str.to_string())
+                .unwrap();
+            let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+            assert_eq!(searcher.num_docs(), expected_row_ids.len() as u64);
+            let mut row_ids: Vec<u64> = Vec::new();
+            for segment_reader in searcher.segment_readers() {
+                assert_eq!(segment_reader.num_deleted_docs(), 0);
+                let row_id_column = segment_reader.fast_fields().u64("row_id").unwrap();
+                row_ids.extend(
+                    segment_reader
+                        .doc_ids_alive()
+                        .filter_map(|doc_id| row_id_column.first(doc_id)),
+                );
+            }
+            row_ids.sort();
+            assert_eq!(row_ids, expected_row_ids);
+        }
+
+        // Source index is not changed.
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(source_path_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 5);
     }
 
     #[test]
-    pub fn test_create_index_with_not_empty_directory() {
+    pub fn test_delete_by_row_id_range_and_query() {
         let _guard = TEST_MUTEX.lock().unwrap();
 
         let temp_directory = TempDir::new().unwrap();
         let temp_directory_str = temp_directory.path().to_str().unwrap();
-
-        // Create index in a clean directory.
-        assert!(create_index_with_parameter(
-            temp_directory_str,
-            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
-            "{}",
-        )
-        .is_ok());
-        // Create index in a not empty directory.
+        let column_names = vec!["title".to_string(), "score".to_string()];
+        let index_json_parameter = r#"{"score": {"tokenizer": {"type": "i64"}}}"#;
         assert!(create_index_with_parameter(
             temp_directory_str,
-            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()],
-            "{}",
+            &column_names,
+            index_json_parameter
         )
         .is_ok());
+        for row_id in 0..10u64 {
+            let title = if row_id % 2 == 0 {
+                "red apple"
+            } else {
+                "green pear"
+            };
+            assert!(index_multi_type_column_docs(
+                temp_directory_str,
+                row_id,
+                &vec!["title".to_string()],
+                &vec![title.to_string()],
+                &vec!["score".to_string()],
+                &vec![(row_id * 10) as i64],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+                &vec![],
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+
+        let alive_row_ids = || {
+            let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+                .get_index_writer_bridge(temp_directory_str.to_string())
+                .unwrap();
+            let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+            let mut row_ids: Vec<u64> = Vec::new();
+            for segment_reader in searcher.segment_readers() {
+                let row_id_column = segment_reader.fast_fields().u64("row_id").unwrap();
+                row_ids.extend(
+                    segment_reader
+                        .doc_ids_alive()
+                        .filter_map(|doc_id| row_id_column.first(doc_id)),
+                );
+            }
+            row_ids.sort();
+            row_ids
+        };
+
+        // Row id range excludes the end.
+        assert!(delete_row_id_range(temp_directory_str, 4, 4).is_err());
+        assert!(delete_row_id_range(temp_directory_str, 2, 4).unwrap());
+        assert_eq!(alive_row_ids(), vec![0, 1, 4, 5, 6, 7, 8, 9]);
+
+        // Numeric range is inclusive, empty bound means unbounded.
+        assert!(delete_by_range(temp_directory_str, "title", "a", "b").is_err());
+        assert!(delete_by_range(temp_directory_str, "score", "70", "").unwrap());
+        assert_eq!(alive_row_ids(), vec![0, 1, 4, 5, 6]);
+
+        assert!(delete_by_term(temp_directory_str, "score", "abc").is_err());
+        assert!(delete_by_term(temp_directory_str, "score", "50").unwrap());
+        // Text term must be analyzed into exactly one token.
+        assert!(delete_by_term(temp_directory_str, "title", "Apple pie").is_err());
+        assert!(delete_by_term(temp_directory_str, "title", "").is_err());
+        assert_eq!(alive_row_ids(), vec![0, 1, 4, 6]);
+        assert!(delete_by_term(temp_directory_str, "title", "Apple").unwrap());
+        assert_eq!(alive_row_ids(), vec![1]);
     }
 
     #[test]
-    pub fn test_create_index_by_default() {
+    pub fn test_delete_by_term_on_ngram_and_synonyms_columns() {
         let _guard = TEST_MUTEX.lock().unwrap();
 
         let temp_directory = TempDir::new().unwrap();
         let temp_directory_str = temp_directory.path().to_str().unwrap();
-
-        assert!(create_index(
-            temp_directory_str,
-            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()]
-        )
-        .is_ok());
-        assert!(create_index(
+        let column_names = vec!["title".to_string(), "tag".to_string()];
+        assert!(create_index_with_parameter(
             temp_directory_str,
-            &vec!["col1".to_string(), "col2".to_string(), "col3".to_string()]
+            &column_names,
+            r#"{"title": {"tokenizer": {"type": "ngram", "min_gram": 2, "max_gram": 3}},
+                "tag": {"tokenizer": {"type": "simple", "synonyms": {
+                    "groups": [["laptop", "notebook"]]
+                }}}}"#
         )
         .is_ok());
+        for (row_id, title, tag) in [(0, "Apple", "laptop"), (1, "April", "phone")] {
+            assert!(index_multi_column_docs(
+                temp_directory_str,
+                row_id,
+                &column_names,
+                &vec![title.to_string(), tag.to_string()]
+            )
+            .is_ok());
+        }
+        assert!(commit_index(temp_directory_str).is_ok());
+        let num_docs = || {
+            let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+                .get_index_writer_bridge(temp_directory_str.to_string())
+                .unwrap();
+            let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+            searcher.num_docs()
+        };
+
+        // Grams of ngram tokenizer are several terms, even if they share a position.
+        assert!(delete_by_term(temp_directory_str, "title", "Apple").is_err());
+        assert_eq!(num_docs(), 2);
+
+        // Synonyms of the original token are ignored.
+        assert!(delete_by_term(temp_directory_str, "tag", "notebook").unwrap());
+        assert_eq!(num_docs(), 1);
     }
 
     #[test]
-    pub fn test_index_multi_column_docs() {
+    pub fn test_upsert_multi_type_column_docs() {
         let _guard = TEST_MUTEX.lock().unwrap();
 
         let temp_directory = TempDir::new().unwrap();
@@ -102,31 +1913,47 @@ mod tests {
 
         assert!(create_index(temp_directory_str, &column_names).is_ok());
 
-        // Index some documents.
-        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        // Index and commit some documents
+        let index_writer_bridge =
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        let col1_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("col1")
+            .unwrap();
+        let query_parser = QueryParser::for_index(&index_writer_bridge.index, vec![col1_field]);
+        let text_query = query_parser.parse_query("Ancient").unwrap();
+        let reader = index_writer_bridge.index.reader().unwrap();
+        assert_eq!(reader.searcher().search(&text_query, &Count).unwrap(), 2);
+        let num_docs = reader.searcher().num_docs();
 
-        for row_id in 0..min(min(col1_docs.len(), col2_docs.len()), col3_docs.len()) {
-            let column_docs = vec![
-                col1_docs[row_id].clone(),
-                col2_docs[row_id].clone(),
-                col3_docs[row_id].clone(),
-            ];
-            assert!(index_multi_column_docs(
-                temp_directory_str,
-                row_id as u64,
-                &column_names,
-                &column_docs
-            )
-            .is_ok());
-        }
+        // Replace row_id 0 with a document doesn't contain `Ancient`.
+        assert!(upsert_multi_type_column_docs(
+            temp_directory_str,
+            0,
+            &column_names,
+            &vec!["Modern".to_string(), "".to_string(), "".to_string()],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+            &vec![],
+        )
+        .is_ok());
         assert!(commit_index(temp_directory_str).is_ok());
+        assert!(reader.reload().is_ok());
 
-        // get index writer from CACHE
-        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
-            .get_index_writer_bridge(temp_directory_str.to_string())
-            .unwrap();
-
-        search_with_index_writer_bridge(index_writer_bridge)
+        assert_eq!(reader.searcher().search(&text_query, &Count).unwrap(), 1);
+        assert_eq!(reader.searcher().num_docs(), num_docs);
     }
 
     #[test]
@@ -203,6 +2030,212 @@ mod tests {
         assert_eq!(count_col1, 1);
     }
 
+    #[test]
+    pub fn test_delete_row_ids_deferred() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        // Create temp ffi index writer.
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+
+        assert!(create_index(temp_directory_str, &column_names).is_ok());
+
+        // Index and commit some documents
+        let index_writer_bridge =
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+
+        // Init some necessary variables for search.
+        let col1_field = index_writer_bridge
+            .index
+            .schema()
+            .get_field("col1")
+            .unwrap();
+        let query_parser = QueryParser::for_index(&index_writer_bridge.index, vec![col1_field]);
+        let text_query = query_parser.parse_query("Ancient").unwrap();
+        let reader = index_writer_bridge.index.reader().unwrap();
+
+        // Opstamp increases with each queued delete.
+        let opstamp_1 = delete_row_ids_deferred(temp_directory_str, &vec![0, 1]).unwrap();
+        let opstamp_2 = delete_row_ids_deferred(temp_directory_str, &vec![2, 3]).unwrap();
+        assert!(opstamp_2 > opstamp_1);
+
+        // Deleted rows are still visible before commit.
+        assert!(reader.reload().is_ok());
+        let count_col1 = reader.searcher().search(&text_query, &Count).unwrap();
+        assert_eq!(count_col1, 2);
+
+        assert!(commit_index(temp_directory_str).is_ok());
+        assert!(reader.reload().is_ok());
+        let count_col1 = reader.searcher().search(&text_query, &Count).unwrap();
+        assert_eq!(count_col1, 1);
+    }
+
+    #[test]
+    pub fn test_force_merge() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter = r#"{"index_settings": {"merge_policy": {"type": "no_merge"}}}"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        // Each commit generates a new segment.
+        for _ in 0..4 {
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        }
+        assert!(force_merge(temp_directory_str, 0).is_err());
+        assert_eq!(force_merge(temp_directory_str, 2).unwrap(), (4, 2));
+
+        // Deleted docs are purged even if segments count reaches the target.
+        assert!(delete_row_ids(temp_directory_str, &vec![0]).is_ok());
+        assert_eq!(force_merge(temp_directory_str, 1).unwrap(), (2, 1));
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.segment_readers().len(), 1);
+        assert_eq!(searcher.segment_reader(0).num_deleted_docs(), 0);
+        assert_eq!(searcher.num_docs(), 16);
+    }
+
+    #[test]
+    pub fn test_force_merge_with_background_merging() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        let index_json_parameter =
+            r#"{"index_settings": {"merge_policy": {"type": "log", "min_num_segments": 2}}}"#;
+        assert!(create_index_with_parameter(
+            temp_directory_str,
+            &column_names,
+            index_json_parameter
+        )
+        .is_ok());
+
+        // Background merges may be running on the same segments when force merge starts.
+        for _ in 0..8 {
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        }
+        let (_, segments_after) = force_merge(temp_directory_str, 1).unwrap();
+        assert_eq!(segments_after, 1);
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(temp_directory_str.to_string())
+            .unwrap();
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 40);
+
+        // Merge policy is restored, segments of new commits are merged in background again.
+        for _ in 0..4 {
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        }
+        assert!(index_writer_bridge.wait_merging_threads().is_ok());
+        let segments = index_writer_bridge.index.searchable_segment_ids().unwrap();
+        assert!(segments.len() < 5);
+    }
+
+    #[test]
+    pub fn test_commit_payload_and_rollback() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(temp_directory_str, &column_names).is_ok());
+        assert_eq!(get_commit_payload(temp_directory_str).unwrap(), "");
+
+        // Commit payload is persisted in meta.json.
+        let index_writer_bridge =
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        assert!(commit_index_with_payload(temp_directory_str, "raft_index:100").is_ok());
+        assert_eq!(
+            get_commit_payload(temp_directory_str).unwrap(),
+            "raft_index:100"
+        );
+
+        // Uncommitted rows are discarded by rollback.
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        assert!(index_multi_column_docs(
+            temp_directory_str,
+            100,
+            &column_names,
+            &vec![
+                col1_docs[0].clone(),
+                col2_docs[0].clone(),
+                col3_docs[0].clone()
+            ],
+        )
+        .is_ok());
+        assert!(rollback_index(temp_directory_str).is_ok());
+        assert!(commit_index_with_payload(temp_directory_str, "raft_index:101").is_ok());
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 5);
+        assert_eq!(
+            get_commit_payload(temp_directory_str).unwrap(),
+            "raft_index:101"
+        );
+    }
+
+    #[test]
+    pub fn test_two_phase_commit() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(temp_directory_str, &column_names).is_ok());
+        let index_writer_bridge =
+            index_3column_docs_with_index_writer_bridge(temp_directory_str, false);
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        let row_docs = vec![
+            col1_docs[0].clone(),
+            col2_docs[0].clone(),
+            col3_docs[0].clone(),
+        ];
+
+        // Nothing to commit or abort before prepare.
+        assert!(commit_prepared(temp_directory_str).is_err());
+        assert!(abort_prepared(temp_directory_str).is_err());
+
+        // Prepared documents stay invisible, and writes are rejected until commit.
+        assert!(prepare_commit_with_payload(temp_directory_str, "raft_index:200").unwrap());
+        assert!(prepare_commit_with_payload(temp_directory_str, "raft_index:201").is_err());
+        assert!(
+            index_multi_column_docs(temp_directory_str, 100, &column_names, &row_docs).is_err()
+        );
+        assert!(commit_index(temp_directory_str).is_err());
+        assert_eq!(get_commit_payload(temp_directory_str).unwrap(), "");
+        assert!(commit_prepared(temp_directory_str).unwrap());
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 5);
+        assert_eq!(
+            get_commit_payload(temp_directory_str).unwrap(),
+            "raft_index:200"
+        );
+
+        // Aborted documents and payload are discarded, writes are accepted again.
+        assert!(index_multi_column_docs(temp_directory_str, 100, &column_names, &row_docs).is_ok());
+        assert!(prepare_commit_with_payload(temp_directory_str, "raft_index:201").unwrap());
+        assert!(abort_prepared(temp_directory_str).unwrap());
+        assert!(commit_prepared(temp_directory_str).is_err());
+        assert!(index_multi_column_docs(temp_directory_str, 101, &column_names, &row_docs).is_ok());
+        assert!(commit_index_with_payload(temp_directory_str, "raft_index:202").unwrap());
+        let searcher = index_writer_bridge.index.reader().unwrap().searcher();
+        assert_eq!(searcher.num_docs(), 6);
+        assert_eq!(
+            get_commit_payload(temp_directory_str).unwrap(),
+            "raft_index:202"
+        );
+    }
+
     #[test]
     pub fn test_free_index_writer() {
         let _guard = TEST_MUTEX.lock().unwrap();
@@ -239,4 +2272,139 @@ mod tests {
             .as_mut()
             .is_none());
     }
+
+    #[test]
+    pub fn test_verify_index() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+        assert!(create_index(temp_directory_str, &column_names).is_ok());
+        index_3column_docs_with_index_writer_bridge(temp_directory_str, true);
+        assert!(free_index_writer(temp_directory_str).is_ok());
+
+        assert!(verify_index(temp_directory.path().join("missing").to_str().unwrap()).is_err());
+        assert!(verify_index(temp_directory_str).unwrap().is_empty());
+
+        // Damaged and missing segment files are reported.
+        let mut segment_files: Vec<std::path::PathBuf> = std::fs::read_dir(temp_directory.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let extension = path.extension().and_then(|e| e.to_str());
+                extension == Some("idx") || extension == Some("store")
+            })
+            .collect();
+        segment_files.sort();
+        let damaged_file = segment_files
+            .iter()
+            .find(|path| path.extension().unwrap() == "idx")
+            .unwrap();
+        let mut content = std::fs::read(damaged_file).unwrap();
+        content[0] ^= 0xff;
+        std::fs::write(damaged_file, content).unwrap();
+        let missing_file = segment_files
+            .iter()
+            .find(|path| path.extension().unwrap() == "store")
+            .unwrap();
+        std::fs::remove_file(missing_file).unwrap();
+
+        let problems = verify_index(temp_directory_str).unwrap();
+        assert_eq!(problems.len(), 2);
+        assert!(problems
+            .iter()
+            .any(|problem| problem.contains("checksum mismatch")
+                && problem.contains(damaged_file.file_name().unwrap().to_str().unwrap())));
+        assert!(problems
+            .iter()
+            .any(|problem| problem.contains("misses file")
+                && problem.contains(missing_file.file_name().unwrap().to_str().unwrap())));
+
+        // Unparsable custom index setting is reported as well.
+        std::fs::write(
+            temp_directory.path().join("custom_index_setting.json"),
+            "{\"tokenizers_json_parameter\": 1}",
+        )
+        .unwrap();
+        let problems = verify_index(temp_directory_str).unwrap();
+        assert_eq!(problems.len(), 3);
+        assert!(problems[0].starts_with("custom_index_setting.json can't be parsed"));
+    }
+
+    #[test]
+    pub fn test_ram_index_and_persist() {
+        let _guard = TEST_MUTEX.lock().unwrap();
+
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+        let persist_path = temp_directory.path().join("persisted");
+        let persist_path_str = persist_path.to_str().unwrap();
+        let index_name = "ram://test_ram_index_and_persist";
+        let column_names = vec!["col1".to_string(), "col2".to_string(), "col3".to_string()];
+
+        assert!(create_ram_index_with_parameter(temp_directory_str, &column_names, "{}").is_err());
+        assert!(create_ram_index_with_parameter(index_name, &column_names, "{}").unwrap());
+        assert!(!std::path::Path::new(index_name).exists());
+
+        // In-memory index is written, committed and searched like a disk index.
+        let index_writer_bridge = index_3column_docs_with_index_writer_bridge(index_name, true);
+        search_with_index_writer_bridge(index_writer_bridge);
+        assert!(load_index_reader(index_name).unwrap());
+        let index_reader_bridge = FFI_INDEX_SEARCHER_CACHE
+            .get_index_reader_bridge(index_name.to_string())
+            .unwrap();
+        assert_eq!(index_reader_bridge.reader.searcher().num_docs(), 5);
+
+        // Json parameter, meta, commit payload and rollback are resolved from cache.
+        assert_eq!(get_index_json_parameter(index_name).unwrap(), "{}");
+        assert!(get_index_meta_json(index_name)
+            .unwrap()
+            .contains("\"segments\""));
+        assert_eq!(get_commit_payload(index_name).unwrap(), "");
+        assert!(commit_index_with_payload(index_name, "raft_index:7").unwrap());
+        assert_eq!(get_commit_payload(index_name).unwrap(), "raft_index:7");
+        let (col1_docs, col2_docs, col3_docs) = get_mocked_docs();
+        assert!(index_multi_column_docs(
+            index_name,
+            100,
+            &column_names,
+            &vec![
+                col1_docs[0].clone(),
+                col2_docs[0].clone(),
+                col3_docs[0].clone()
+            ],
+        )
+        .unwrap());
+        assert!(rollback_index(index_name).unwrap());
+        assert!(commit_index_with_payload(index_name, "raft_index:8").unwrap());
+        assert_eq!(index_reader_bridge.reader.searcher().num_docs(), 5);
+        assert_eq!(get_commit_payload(index_name).unwrap(), "raft_index:8");
+
+        // Persisted index can be loaded from disk.
+        assert!(persist_ram_index(index_name, index_name).is_err());
+        assert!(persist_ram_index(index_name, persist_path_str).unwrap());
+        assert_eq!(get_index_json_parameter(persist_path_str).unwrap(), "{}");
+        assert_eq!(
+            get_commit_payload(persist_path_str).unwrap(),
+            "raft_index:8"
+        );
+        assert!(load_index_writer(persist_path_str).is_ok());
+        let index_writer_bridge = FFI_INDEX_WRITER_CACHE
+            .get_index_writer_bridge(persist_path_str.to_string())
+            .unwrap();
+        search_with_index_writer_bridge(index_writer_bridge);
+        assert!(load_index_reader(persist_path_str).unwrap());
+        let index_reader_bridge = FFI_INDEX_SEARCHER_CACHE
+            .get_index_reader_bridge(persist_path_str.to_string())
+            .unwrap();
+        assert_eq!(index_reader_bridge.reader.searcher().num_docs(), 5);
+
+        // In-memory index is gone after it's freed.
+        assert!(free_index_reader(index_name).unwrap());
+        assert!(free_index_writer(index_name).unwrap());
+        assert!(load_index_reader(index_name).is_err());
+        assert!(free_index_reader(persist_path_str).unwrap());
+        assert!(free_index_writer(persist_path_str).unwrap());
+    }
 }
@@ -1,10 +1,21 @@
 use ffi::BM25Result;
+use ffi::BatchIndexResult;
 use ffi::BoolResult;
+use ffi::BytesResult;
 use ffi::DocWithFreq;
+use ffi::FieldSpaceUsage;
 use ffi::FieldTokenNums;
+use ffi::ForceMergeResult;
+use ffi::IndexStatisticsResult;
+use ffi::ReindexProgressResult;
+use ffi::RowIdWithError;
 use ffi::RowIdWithScore;
+use ffi::SegmentChangesResult;
+use ffi::SegmentStatistics;
 use ffi::Statistics;
 use ffi::StringResult;
+use ffi::U64Result;
+use ffi::VerifyIndexResult;
 use std::cmp::Ordering;
 
 mod common;
@@ -55,6 +66,68 @@ pub mod ffi {
         error_msg: String,
     }
 
+    #[derive(Debug, Clone)]
+    pub struct U64Result {
+        result: u64,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct BytesResult {
+        result: Vec<u8>,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ForceMergeResult {
+        segments_before: u32,
+        segments_after: u32,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct ReindexProgressResult {
+        reindexed_docs: u64,
+        total_docs: u64,
+        finished: bool,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct SegmentChangesResult {
+        opstamp: u64,
+        added_files: Vec<String>,
+        removed_files: Vec<String>,
+        meta_json: String,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct VerifyIndexResult {
+        problems: Vec<String>,
+        error_code: i32,
+        error_msg: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct RowIdWithError {
+        pub row_id: u64,
+        pub error_msg: String,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct BatchIndexResult {
+        indexed_count: u64,
+        failed_rows: Vec<RowIdWithError>,
+        error_code: i32,
+        error_msg: String,
+    }
+
     #[derive(Debug, Clone, PartialEq)]
     pub struct DocWithFreq {
         pub term_str: String,
@@ -76,6 +149,36 @@ pub mod ffi {
         pub total_num_docs: u64,
     }
 
+    #[derive(Debug, Clone)]
+    pub struct SegmentStatistics {
+        pub segment_id: String,
+        pub max_doc: u32,
+        pub num_deleted_docs: u32,
+        pub postings_bytes: u64,
+        pub positions_bytes: u64,
+        pub term_dict_bytes: u64,
+        pub fast_fields_bytes: u64,
+        pub fieldnorms_bytes: u64,
+        pub store_bytes: u64,
+        pub deletes_bytes: u64,
+        pub total_bytes: u64,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct FieldSpaceUsage {
+        pub field_name: String,
+        pub num_bytes: u64,
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct IndexStatisticsResult {
+        segments: Vec<SegmentStatistics>,
+        fields: Vec<FieldSpaceUsage>,
+        total_bytes: u64,
+        error_code: i32,
+        error_msg: String,
+    }
+
     extern "Rust" {
         pub fn ffi_varify_index_parameter(index_json_parameter: &CxxString) -> BoolResult;
 
@@ -90,6 +193,50 @@ pub mod ffi {
             index_json_parameter: &CxxString,
         ) -> BoolResult;
 
+        /// Create an in-memory tantivy index, no file will be written.
+        /// It's written, committed and searched with `index_name` like a disk index.
+        /// arguments:
+        /// - `index_name`: logical name of index, can't be an existing path.
+        /// - `column_names`: which columns will be used to build index.
+        /// - `index_json_parameter`: config index with json.
+        fn ffi_create_ram_index_with_parameter(
+            index_name: &CxxString,
+            column_names: &CxxVector<CxxString>,
+            index_json_parameter: &CxxString,
+        ) -> BoolResult;
+
+        /// Save committed segments of an in-memory index as a disk index.
+        /// arguments:
+        /// - `index_name`: logical name of in-memory index.
+        /// - `index_path`: index directory, existing files will be removed.
+        fn ffi_persist_ram_index(index_name: &CxxString, index_path: &CxxString) -> BoolResult;
+
+        /// Open existing tantivy index if compatible, otherwise create it.
+        /// Existing index with different column configs or schema will cause an error.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `column_names`: which columns will be used to build index.
+        /// - `index_json_parameter`: config index with json.
+        /// - `wipe_existing`: remove existing index and recreate it.
+        fn ffi_open_or_create_index_with_parameter(
+            index_path: &CxxString,
+            column_names: &CxxVector<CxxString>,
+            index_json_parameter: &CxxString,
+            wipe_existing: bool,
+        ) -> BoolResult;
+
+        /// Add new columns to an existing tantivy index, documents indexed before
+        /// don't have these columns. The merged config is persisted with the index.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `column_names`: new columns, can't exist in the index.
+        /// - `index_json_parameter`: tokenizer configs of new columns.
+        fn ffi_add_columns_to_index(
+            index_path: &CxxString,
+            column_names: &CxxVector<CxxString>,
+            index_json_parameter: &CxxString,
+        ) -> BoolResult;
+
         /// Create tantivy index by default.
         /// argements:
         /// - `index_path`: index directory.
@@ -145,17 +292,477 @@ pub mod ffi {
             bool_column_docs: &CxxVector<CxxString>,
         ) -> BoolResult;
 
+        /// Index multi type column docs with given rowId, including u64 and ip columns.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_id`: row_id given by ClickHouse.
+        /// - `text_column_names`: align with column_docs.
+        /// - `text_column_docs`: align with column_names.
+        /// - `i64_column_names`: align with column_docs.
+        /// - `i64_column_docs`: align with column_names.
+        /// - `f64_column_names`: align with column_docs.
+        /// - `f64_column_docs`: align with column_names.
+        /// - `bytes_column_names`: align with column_docs.
+        /// - `bytes_column_docs`: align with column_names.
+        /// - `date_column_names`: align with column_docs.
+        /// - `date_column_docs`: align with column_names.
+        /// - `bool_column_names`: align with column_docs.
+        /// - `bool_column_docs`: align with column_names.
+        /// - `u64_column_names`: align with column_docs.
+        /// - `u64_column_docs`: align with column_names.
+        /// - `ip_column_names`: align with column_docs.
+        /// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+        fn ffi_index_multi_type_column_docs_with_u64_and_ip(
+            index_path: &CxxString,
+            row_id: u64,
+            text_column_names: &CxxVector<CxxString>,
+            text_column_docs: &CxxVector<CxxString>,
+            i64_column_names: &CxxVector<CxxString>,
+            i64_column_docs: &CxxVector<i64>,
+            f64_column_names: &CxxVector<CxxString>,
+            f64_column_docs: &CxxVector<f64>,
+            bytes_column_names: &CxxVector<CxxString>,
+            bytes_column_docs: &CxxVector<CxxString>,
+            date_column_names: &CxxVector<CxxString>,
+            date_column_docs: &CxxVector<CxxString>,
+            bool_column_names: &CxxVector<CxxString>,
+            bool_column_docs: &CxxVector<CxxString>,
+            u64_column_names: &CxxVector<CxxString>,
+            u64_column_docs: &CxxVector<u64>,
+            ip_column_names: &CxxVector<CxxString>,
+            ip_column_docs: &CxxVector<CxxString>,
+        ) -> BoolResult;
+
+        /// Replace the document of given rowId with multi type columns.
+        /// Deleting old document and adding the new one happen under the same writer lock,
+        /// both of them become visible on next commit.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_id`: row_id given by ClickHouse.
+        /// - `text_column_names`: align with column_docs.
+        /// - `text_column_docs`: align with column_names.
+        /// - `i64_column_names`: align with column_docs.
+        /// - `i64_column_docs`: align with column_names.
+        /// - `f64_column_names`: align with column_docs.
+        /// - `f64_column_docs`: align with column_names.
+        /// - `bytes_column_names`: align with column_docs.
+        /// - `bytes_column_docs`: align with column_names.
+        /// - `date_column_names`: align with column_docs.
+        /// - `date_column_docs`: align with column_names.
+        /// - `bool_column_names`: align with column_docs.
+        /// - `bool_column_docs`: align with column_names.
+        /// - `u64_column_names`: align with column_docs.
+        /// - `u64_column_docs`: align with column_names.
+        /// - `ip_column_names`: align with column_docs.
+        /// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+        fn ffi_upsert_multi_type_column_docs(
+            index_path: &CxxString,
+            row_id: u64,
+            text_column_names: &CxxVector<CxxString>,
+            text_column_docs: &CxxVector<CxxString>,
+            i64_column_names: &CxxVector<CxxString>,
+            i64_column_docs: &CxxVector<i64>,
+            f64_column_names: &CxxVector<CxxString>,
+            f64_column_docs: &CxxVector<f64>,
+            bytes_column_names: &CxxVector<CxxString>,
+            bytes_column_docs: &CxxVector<CxxString>,
+            date_column_names: &CxxVector<CxxString>,
+            date_column_docs: &CxxVector<CxxString>,
+            bool_column_names: &CxxVector<CxxString>,
+            bool_column_docs: &CxxVector<CxxString>,
+            u64_column_names: &CxxVector<CxxString>,
+            u64_column_docs: &CxxVector<u64>,
+            ip_column_names: &CxxVector<CxxString>,
+            ip_column_docs: &CxxVector<CxxString>,
+        ) -> BoolResult;
+
+        /// Index multi type columns with given rowId, including json columns.
+        /// Each json doc should be a JSON object string, its nested keys can be queried
+        /// with a dotted path column name, e.g. `attrs.color`.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_id`: row_id given by ClickHouse.
+        /// - `text_column_names`: align with column_docs.
+        /// - `text_column_docs`: align with column_names.
+        /// - `i64_column_names`: align with column_docs.
+        /// - `i64_column_docs`: align with column_names.
+        /// - `f64_column_names`: align with column_docs.
+        /// - `f64_column_docs`: align with column_names.
+        /// - `bytes_column_names`: align with column_docs.
+        /// - `bytes_column_docs`: align with column_names.
+        /// - `date_column_names`: align with column_docs.
+        /// - `date_column_docs`: align with column_names.
+        /// - `bool_column_names`: align with column_docs.
+        /// - `bool_column_docs`: align with column_names.
+        /// - `u64_column_names`: align with column_docs.
+        /// - `u64_column_docs`: align with column_names.
+        /// - `ip_column_names`: align with column_docs.
+        /// - `ip_column_docs`: IPv4 or IPv6 address strings, align with column_names.
+        /// - `json_column_names`: align with column_docs.
+        /// - `json_column_docs`: align with column_names.
+        fn ffi_index_multi_type_column_docs_with_json(
+            index_path: &CxxString,
+            row_id: u64,
+            text_column_names: &CxxVector<CxxString>,
+            text_column_docs: &CxxVector<CxxString>,
+            i64_column_names: &CxxVector<CxxString>,
+            i64_column_docs: &CxxVector<i64>,
+            f64_column_names: &CxxVector<CxxString>,
+            f64_column_docs: &CxxVector<f64>,
+            bytes_column_names: &CxxVector<CxxString>,
+            bytes_column_docs: &CxxVector<CxxString>,
+            date_column_names: &CxxVector<CxxString>,
+            date_column_docs: &CxxVector<CxxString>,
+            bool_column_names: &CxxVector<CxxString>,
+            bool_column_docs: &CxxVector<CxxString>,
+            u64_column_names: &CxxVector<CxxString>,
+            u64_column_docs: &CxxVector<u64>,
+            ip_column_names: &CxxVector<CxxString>,
+            ip_column_docs: &CxxVector<CxxString>,
+            json_column_names: &CxxVector<CxxString>,
+            json_column_docs: &CxxVector<CxxString>,
+        ) -> BoolResult;
+
+        /// Index multi-valued columns with given rowId, each column may contain several values.
+        /// Each `*_column_offsets` aligns with `*_column_names`, `offsets[c]` is the end position
+        /// (exclusive) of column `c` values in `*_column_docs`, the start is `offsets[c - 1]` or 0.
+        /// Values of a column are indexed as tantivy multi-value field, BM25, term and range
+        /// queries match a row if any of its values matches.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_id`: row_id given by ClickHouse.
+        /// - `text_column_names`: text columns name.
+        /// - `text_column_offsets`: end offsets of text columns values.
+        /// - `text_column_docs`: text columns values.
+        /// - `i64_column_names`: i64 columns name.
+        /// - `i64_column_offsets`: end offsets of i64 columns values.
+        /// - `i64_column_docs`: i64 columns values.
+        /// - `f64_column_names`: f64 columns name.
+        /// - `f64_column_offsets`: end offsets of f64 columns values.
+        /// - `f64_column_docs`: f64 columns values.
+        /// - `bytes_column_names`: bytes columns name.
+        /// - `bytes_column_offsets`: end offsets of bytes columns values.
+        /// - `bytes_column_docs`: bytes columns values.
+        /// - `date_column_names`: date columns name.
+        /// - `date_column_offsets`: end offsets of date columns values.
+        /// - `date_column_docs`: date columns values, RFC3339 format.
+        /// - `bool_column_names`: bool columns name.
+        /// - `bool_column_offsets`: end offsets of bool columns values.
+        /// - `bool_column_docs`: bool columns values, `true` or `false`.
+        /// - `u64_column_names`: u64 columns name.
+        /// - `u64_column_offsets`: end offsets of u64 columns values.
+        /// - `u64_column_docs`: u64 columns values.
+        /// - `ip_column_names`: ip columns name.
+        /// - `ip_column_offsets`: end offsets of ip columns values.
+        /// - `ip_column_docs`: ip columns values, IPv4 or IPv6 address strings.
+        fn ffi_index_multi_valued_column_docs(
+            index_path: &CxxString,
+            row_id: u64,
+            text_column_names: &CxxVector<CxxString>,
+            text_column_offsets: &CxxVector<u64>,
+            text_column_docs: &CxxVector<CxxString>,
+            i64_column_names: &CxxVector<CxxString>,
+            i64_column_offsets: &CxxVector<u64>,
+            i64_column_docs: &CxxVector<i64>,
+            f64_column_names: &CxxVector<CxxString>,
+            f64_column_offsets: &CxxVector<u64>,
+            f64_column_docs: &CxxVector<f64>,
+            bytes_column_names: &CxxVector<CxxString>,
+            bytes_column_offsets: &CxxVector<u64>,
+            bytes_column_docs: &CxxVector<CxxString>,
+            date_column_names: &CxxVector<CxxString>,
+            date_column_offsets: &CxxVector<u64>,
+            date_column_docs: &CxxVector<CxxString>,
+            bool_column_names: &CxxVector<CxxString>,
+            bool_column_offsets: &CxxVector<u64>,
+            bool_column_docs: &CxxVector<CxxString>,
+            u64_column_names: &CxxVector<CxxString>,
+            u64_column_offsets: &CxxVector<u64>,
+            u64_column_docs: &CxxVector<u64>,
+            ip_column_names: &CxxVector<CxxString>,
+            ip_column_offsets: &CxxVector<u64>,
+            ip_column_docs: &CxxVector<CxxString>,
+        ) -> BoolResult;
+
+        /// Index a batch of rows with multi type columns under a single writer lock.
+        /// Each `*_column_docs` is laid out column by column, its size must be
+        /// `*_column_names.size() * row_ids.size()`, the value of column `c` for row `r`
+        /// is located at `c * row_ids.size() + r`.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_ids`: a group of row_ids given by DingoDB.
+        /// - `text_column_names`: text columns name.
+        /// - `text_column_docs`: text columns value.
+        /// - `i64_column_names`: i64 columns name.
+        /// - `i64_column_docs`: i64 columns value.
+        /// - `f64_column_names`: f64 columns name.
+        /// - `f64_column_docs`: f64 columns value.
+        /// - `bytes_column_names`: bytes columns name.
+        /// - `bytes_column_docs`: bytes columns value.
+        /// - `date_column_names`: date columns name.
+        /// - `date_column_docs`: date columns value, RFC3339 format.
+        /// - `bool_column_names`: bool columns name.
+        /// - `bool_column_docs`: bool columns value, `true` or `false`.
+        /// - `u64_column_names`: u64 columns name.
+        /// - `u64_column_docs`: u64 columns value.
+        /// - `ip_column_names`: ip columns name.
+        /// - `ip_column_docs`: ip columns value, IPv4 or IPv6 address strings.
+        fn ffi_index_multi_type_column_docs_batch(
+            index_path: &CxxString,
+            row_ids: &CxxVector<u64>,
+            text_column_names: &CxxVector<CxxString>,
+            text_column_docs: &CxxVector<CxxString>,
+            i64_column_names: &CxxVector<CxxString>,
+            i64_column_docs: &CxxVector<i64>,
+            f64_column_names: &CxxVector<CxxString>,
+            f64_column_docs: &CxxVector<f64>,
+            bytes_column_names: &CxxVector<CxxString>,
+            bytes_column_docs: &CxxVector<CxxString>,
+            date_column_names: &CxxVector<CxxString>,
+            date_column_docs: &CxxVector<CxxString>,
+            bool_column_names: &CxxVector<CxxString>,
+            bool_column_docs: &CxxVector<CxxString>,
+            u64_column_names: &CxxVector<CxxString>,
+            u64_column_docs: &CxxVector<u64>,
+            ip_column_names: &CxxVector<CxxString>,
+            ip_column_docs: &CxxVector<CxxString>,
+        ) -> BatchIndexResult;
+
         /// Delete a group of rowIds.
         /// arguments:
         /// - `index_path`: index directory.
         /// - `row_ids`: a group of rowIds need be deleted.
         fn ffi_delete_row_ids(index_path: &CxxString, row_ids: &CxxVector<u64>) -> BoolResult;
 
+        /// Queue delete terms of a group of rowIds without commit.
+        /// Deleted rows become invisible after next `ffi_index_writer_commit`.
+        /// Returns the opstamp assigned to the last delete operation.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `row_ids`: a group of rowIds need be deleted.
+        fn ffi_delete_row_ids_deferred(
+            index_path: &CxxString,
+            row_ids: &CxxVector<u64>,
+        ) -> U64Result;
+
+        /// Delete rows whose rowId is in `[start_row_id, end_row_id)`, then commit.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `start_row_id`: first rowId need be deleted.
+        /// - `end_row_id`: rowId after the last one need be deleted.
+        fn ffi_delete_row_id_range(
+            index_path: &CxxString,
+            start_row_id: u64,
+            end_row_id: u64,
+        ) -> BoolResult;
+
+        /// Delete rows matching a term of a column, then commit.
+        /// Text terms are tokenized, rows matching any token are deleted.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `column_name`: which column will be matched.
+        /// - `term`: term value, parsed with the column type.
+        fn ffi_delete_by_term(
+            index_path: &CxxString,
+            column_name: &CxxString,
+            term: &CxxString,
+        ) -> BoolResult;
+
+        /// Delete rows whose column value is in `[lower_bound, upper_bound]`, then commit.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `column_name`: numeric, datetime or ip column.
+        /// - `lower_bound`: lower bound value, empty means unbounded.
+        /// - `upper_bound`: upper bound value, empty means unbounded.
+        fn ffi_delete_by_range(
+            index_path: &CxxString,
+            column_name: &CxxString,
+            lower_bound: &CxxString,
+            upper_bound: &CxxString,
+        ) -> BoolResult;
+
+        /// Merge searchable segments down to `max_segments`, deleted docs will be purged.
+        /// It waits until merge finished and reloads the index reader.
+        /// Background merging is paused during the call, the merge policy is restored afterwards.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `max_segments`: target segments count, should be greater than 0.
+        fn ffi_force_merge(index_path: &CxxString, max_segments: u32) -> ForceMergeResult;
+
+        /// Rebuild index with a new json parameter from stored documents, row_ids are kept.
+        /// All columns should be stored, column types can't be changed. The new index is built
+        /// in a sibling directory, then swapped with the old one. Returns reindexed docs count.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `index_json_parameter`: new json parameter of the index.
+        fn ffi_reindex_with_parameter(
+            index_path: &CxxString,
+            index_json_parameter: &CxxString,
+        ) -> U64Result;
+
+        /// Get progress of the running or last finished reindex.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_get_reindex_progress(index_path: &CxxString) -> ReindexProgressResult;
+
+        /// Create a point-in-time snapshot of committed segments, segment files are hard linked
+        /// (or copied) into `snapshot_dir` together with meta.json and custom index setting.
+        /// Segment files are pinned until released. Returns the opstamp of the snapshot.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `snapshot_dir`: snapshot directory, can't exist before.
+        fn ffi_create_snapshot(index_path: &CxxString, snapshot_dir: &CxxString) -> U64Result;
+
+        /// Unpin segment files of a snapshot, files in `snapshot_dir` are kept.
+        /// arguments:
+        /// - `snapshot_dir`: snapshot directory.
+        fn ffi_release_snapshot(snapshot_dir: &CxxString) -> BoolResult;
+
+        /// Install a snapshot as the index in `index_path`, existing index files are replaced once
+        /// the snapshot is installed.
+        /// arguments:
+        /// - `snapshot_dir`: snapshot directory.
+        /// - `index_path`: index directory.
+        fn ffi_restore_snapshot(snapshot_dir: &CxxString, index_path: &CxxString) -> BoolResult;
+
+        /// List segment files added and removed since a follower's segment files, together with
+        /// meta.json of the latest commit. Listed files are pinned for the follower until its
+        /// next listing or `ffi_release_segment_changes`. Pins only hold while the leader index
+        /// writer loaded during listing is alive, list again after the writer is reloaded.
+        /// arguments:
+        /// - `index_path`: leader index directory.
+        /// - `follower_id`: identifies the follower, each follower has its own pins.
+        /// - `known_files`: segment files of the follower, list them with empty `known_files`.
+        fn ffi_list_segment_changes(
+            index_path: &CxxString,
+            follower_id: &CxxString,
+            known_files: &CxxVector<CxxString>,
+        ) -> SegmentChangesResult;
+
+        /// Unpin segment files listed for a follower by `ffi_list_segment_changes`.
+        /// arguments:
+        /// - `index_path`: leader index directory.
+        /// - `follower_id`: identifies the follower.
+        fn ffi_release_segment_changes(
+            index_path: &CxxString,
+            follower_id: &CxxString,
+        ) -> BoolResult;
+
+        /// Read bytes of a segment file listed by the last `ffi_list_segment_changes` of
+        /// a follower. The footer is excluded, `ffi_write_segment_file` appends it again.
+        /// arguments:
+        /// - `index_path`: leader index directory.
+        /// - `follower_id`: identifies the follower.
+        /// - `file_name`: segment file name.
+        fn ffi_read_segment_file(
+            index_path: &CxxString,
+            follower_id: &CxxString,
+            file_name: &CxxString,
+        ) -> BytesResult;
+
+        /// Write a segment file into a follower index, it's visible after changes applied.
+        /// arguments:
+        /// - `index_path`: follower index directory.
+        /// - `file_name`: segment file name.
+        /// - `content`: segment file bytes.
+        fn ffi_write_segment_file(
+            index_path: &CxxString,
+            file_name: &CxxString,
+            content: &CxxVector<u8>,
+        ) -> BoolResult;
+
+        /// Install meta.json of the leader into a follower index and remove stale segment files.
+        /// The follower can't have a loaded writer, its reader will be reopened if loaded.
+        /// arguments:
+        /// - `index_path`: follower index directory.
+        /// - `meta_json`: meta.json listed from the leader.
+        /// - `removed_files`: segment files removed from the leader.
+        fn ffi_apply_segment_changes(
+            index_path: &CxxString,
+            meta_json: &CxxString,
+            removed_files: &CxxVector<CxxString>,
+        ) -> BoolResult;
+
+        /// Attach segments of source indexes to the target index without re-ingesting.
+        /// Schemas and json parameters must be equal, source indexes are not changed.
+        /// Returns attached docs count.
+        /// arguments:
+        /// - `index_path`: target index directory.
+        /// - `source_paths`: source index directories.
+        /// - `row_id_conflict_policy`: `fail`, or `keep_target` to delete conflicting source docs.
+        fn ffi_merge_indexes(
+            index_path: &CxxString,
+            source_paths: &CxxVector<CxxString>,
+            row_id_conflict_policy: &CxxString,
+        ) -> U64Result;
+
+        /// Split an index by row_id, rows below `split_row_id` go to `left_path`, others go to
+        /// `right_path`. Both carry the json parameter of source, source index is not changed.
+        /// arguments:
+        /// - `index_path`: source index directory.
+        /// - `split_row_id`: the first row_id of right index.
+        /// - `left_path`: left index directory, existing files will be removed.
+        /// - `right_path`: right index directory, existing files will be removed.
+        fn ffi_split_index(
+            index_path: &CxxString,
+            split_row_id: u64,
+            left_path: &CxxString,
+            right_path: &CxxString,
+        ) -> BoolResult;
+
+        /// Verify an index directory before serving from it, returns found problems.
+        /// Checksums of managed files, segment files listed in meta.json and
+        /// custom_index_setting.json are checked, `problems` is empty if index is intact.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_verify_index(index_path: &CxxString) -> VerifyIndexResult;
+
         /// Commit index writer
         /// arguments:
         /// - `index_path`: index directory.
         fn ffi_index_writer_commit(index_path: &CxxString) -> BoolResult;
 
+        /// Commit index writer with a payload, such as raft log index.
+        /// The payload is recorded in meta.json and can be read by `ffi_get_commit_payload`.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `payload`: opaque string saved with this commit.
+        fn ffi_index_writer_commit_with_payload(
+            index_path: &CxxString,
+            payload: &CxxString,
+        ) -> BoolResult;
+
+        /// Rollback index writer, discard all operations after last commit.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_index_writer_rollback(index_path: &CxxString) -> BoolResult;
+
+        /// Prepare a two-phase commit with a payload, such as raft log index.
+        /// Pending documents are flushed, other write operations are rejected
+        /// until `ffi_index_writer_commit_prepared` or `ffi_index_writer_abort_prepared`.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `payload`: opaque string saved with the prepared commit.
+        fn ffi_index_writer_prepare_commit_with_payload(
+            index_path: &CxxString,
+            payload: &CxxString,
+        ) -> BoolResult;
+
+        /// Commit the prepared commit, its payload is recorded in meta.json.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_index_writer_commit_prepared(index_path: &CxxString) -> BoolResult;
+
+        /// Abort the prepared commit, discard all operations after last commit.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_index_writer_abort_prepared(index_path: &CxxString) -> BoolResult;
+
+        /// Get the payload of last commit, empty string means no payload.
+        /// arguments:
+        /// - `index_path`: index directory.
+        fn ffi_get_commit_payload(index_path: &CxxString) -> StringResult;
+
         /// Free index writer
         /// arguments:
         /// - `index_path`: index directory.
@@ -290,6 +897,20 @@ pub mod ffi {
             pattern: &CxxString,
         ) -> Vec<u8>;
 
+        /// Execute a range query on u64, i64, f64, bool, datetime or ip column,
+        /// and return rowIds u8 bitmap. Both bounds are inclusive.
+        /// arguments:
+        /// - `index_path`: index directory.
+        /// - `column_name`: which column will execute search.
+        /// - `lower_bound`: parsed with column type, empty string means unbounded.
+        /// - `upper_bound`: parsed with column type, empty string means unbounded.
+        pub fn ffi_range_query_bitmap(
+            index_path: &CxxString,
+            column_name: &CxxString,
+            lower_bound: &CxxString,
+            upper_bound: &CxxString,
+        ) -> Vec<u8>;
+
         /// Execute a regex query and return rowIds u8 bitmap.
         /// arguments:
         /// - `index_path`: index directory.
@@ -376,6 +997,12 @@ pub mod ffi {
         /// - `index_path`: index directory.
         pub fn ffi_get_index_meta_json(index_path: &CxxString) -> StringResult;
 
+        /// Get statistics of the loaded index reader, including on-disk size of each segment
+        /// broken down by component, and space usage of each field over all segments.
+        /// arguments:
+        /// - `index_path`: index directory.
+        pub fn ffi_get_index_statistics(index_path: &CxxString) -> IndexStatisticsResult;
+
         /// Get index json parameter
         /// arguments:
         /// - `index_path`: index directory.
@@ -430,6 +1057,13 @@ impl DocWithFreq {
     }
 }
 
+#[allow(dead_code)]
+impl RowIdWithError {
+    fn new(row_id: u64, error_msg: String) -> Self {
+        RowIdWithError { row_id, error_msg }
+    }
+}
+
 #[allow(dead_code)]
 impl Statistics {
     fn new(docs_freq: Vec<DocWithFreq>, total_num_tokens: u64, total_num_docs: u64) -> Self {
@@ -5,6 +5,7 @@ use crate::search::implements::api_clickhouse_impl::query_term_bitmap;
 use crate::search::implements::api_clickhouse_impl::query_term_with_range;
 use crate::search::implements::api_clickhouse_impl::query_terms_bitmap;
 use crate::search::implements::api_clickhouse_impl::query_terms_with_range;
+use crate::search::implements::api_clickhouse_impl::range_query_bitmap;
 use crate::search::implements::api_clickhouse_impl::regex_term_bitmap;
 use crate::search::implements::api_clickhouse_impl::regex_term_with_range;
 use crate::BoolResult;
@@ -441,3 +442,47 @@ pub fn ffi_regex_term_bitmap(
         }
     }
 }
+
+pub fn ffi_range_query_bitmap(
+    index_path: &CxxString,
+    column_name: &CxxString,
+    lower_bound: &CxxString,
+    upper_bound: &CxxString,
+) -> Vec<u8> {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_range_query_bitmap", "Can't convert 'index_path', message: {}", e);
+            return Vec::new();
+        }
+    };
+    let column_name: String = match CXX_STRING_CONERTER.convert(column_name) {
+        Ok(name) => name,
+        Err(e) => {
+            ERROR!(function: "ffi_range_query_bitmap", "Can't convert 'column_name', message: {}", e);
+            return Vec::new();
+        }
+    };
+    let lower_bound: String = match CXX_STRING_CONERTER.convert(lower_bound) {
+        Ok(bound) => bound,
+        Err(e) => {
+            ERROR!(function: "ffi_range_query_bitmap", "Can't convert 'lower_bound', message: {}", e);
+            return Vec::new();
+        }
+    };
+    let upper_bound: String = match CXX_STRING_CONERTER.convert(upper_bound) {
+        Ok(bound) => bound,
+        Err(e) => {
+            ERROR!(function: "ffi_range_query_bitmap", "Can't convert 'upper_bound', message: {}", e);
+            return Vec::new();
+        }
+    };
+
+    match range_query_bitmap(&index_path, &column_name, &lower_bound, &upper_bound) {
+        Ok(status) => status,
+        Err(e) => {
+            ERROR!(function: "ffi_range_query_bitmap", "Error happend. {}", e);
+            Vec::new()
+        }
+    }
+}
@@ -2,11 +2,12 @@ use crate::logger::logger_bridge::TantivySearchLogger;
 use crate::search::implements::api_common_impl::free_index_reader;
 use crate::search::implements::api_common_impl::get_index_json_parameter;
 use crate::search::implements::api_common_impl::get_index_meta_json;
+use crate::search::implements::api_common_impl::get_index_statistics;
 use crate::search::implements::api_common_impl::get_indexed_doc_counts;
 use crate::search::implements::api_common_impl::load_index_reader;
 use crate::CXX_STRING_CONERTER;
 use crate::{common::constants::LOG_CALLBACK, ERROR};
-use crate::{BoolResult, StringResult};
+use crate::{BoolResult, IndexStatisticsResult, StringResult};
 use cxx::CxxString;
 
 pub fn ffi_get_index_json_parameter(index_path: &CxxString) -> StringResult {
@@ -73,6 +74,44 @@ pub fn ffi_get_index_meta_json(index_path: &CxxString) -> StringResult {
     }
 }
 
+pub fn ffi_get_index_statistics(index_path: &CxxString) -> IndexStatisticsResult {
+    let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
+        Ok(path) => path,
+        Err(e) => {
+            ERROR!(function: "ffi_get_index_statistics", "Can't convert 'index_path', message: {}", e);
+            let error_msg_for_cxx: String = format!("Can't convert 'index_path', message: {}", e);
+            return IndexStatisticsResult {
+                segments: Vec::new(),
+                fields: Vec::new(),
+                total_bytes: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    };
+
+    match get_index_statistics(&index_path) {
+        Ok((segments, fields, total_bytes)) => IndexStatisticsResult {
+            segments,
+            fields,
+            total_bytes,
+            error_code: 0,
+            error_msg: String::new(),
+        },
+        Err(e) => {
+            ERROR!(function: "ffi_get_index_statistics", "Error getting index statistics: {}", e);
+            let error_msg_for_cxx: String = format!("Error getting index statistics: {}", e);
+            return IndexStatisticsResult {
+                segments: Vec::new(),
+                fields: Vec::new(),
+                total_bytes: 0,
+                error_code: -1,
+                error_msg: error_msg_for_cxx,
+            };
+        }
+    }
+}
+
 pub fn ffi_load_index_reader(index_path: &CxxString) -> BoolResult {
     let index_path: String = match CXX_STRING_CONERTER.convert(index_path) {
         Ok(path) => path,
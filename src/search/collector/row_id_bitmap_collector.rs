@@ -3,7 +3,10 @@ use std::sync::Arc;
 use roaring::RoaringBitmap;
 use tantivy::collector::{Collector, SegmentCollector};
 use tantivy::columnar::Column;
-use tantivy::{Score, SegmentReader};
+use tantivy::query::Weight;
+use tantivy::{Score, SegmentOrdinal, SegmentReader};
+
+use crate::search::utils::index_searcher_utils::FFiIndexSearcherUtils;
 
 /*
     Struct visualization.
@@ -25,11 +28,21 @@ use tantivy::{Score, SegmentReader};
 
 pub struct RowIdRoaringCollector {
     pub row_id_field: String,
+    pub row_id_range: Option<(u64, u64)>,
 }
 
 impl RowIdRoaringCollector {
     pub fn with_field(row_id_field: String) -> RowIdRoaringCollector {
-        RowIdRoaringCollector { row_id_field }
+        RowIdRoaringCollector {
+            row_id_field,
+            row_id_range: None,
+        }
+    }
+
+    // `row_id_range` is used to skip segments out of range, the range is [start, end)
+    pub fn with_range(mut self, row_id_range: (u64, u64)) -> RowIdRoaringCollector {
+        self.row_id_range = Some(row_id_range);
+        self
     }
 }
 
@@ -68,6 +81,34 @@ impl Collector for RowIdRoaringCollector {
         }
         Ok(Arc::new(row_id_roaring_bitmap))
     }
+
+    // Segments without row_ids in `row_id_range` won't be searched.
+    fn collect_segment(
+        &self,
+        weight: &dyn Weight,
+        segment_ord: SegmentOrdinal,
+        reader: &SegmentReader,
+    ) -> tantivy::Result<<Self::Child as SegmentCollector>::Fruit> {
+        if let Some((start_id, end_id)) = self.row_id_range {
+            if !FFiIndexSearcherUtils::segment_overlaps_row_id_range(reader, start_id, end_id) {
+                return Ok(Arc::new(RoaringBitmap::new()));
+            }
+        }
+
+        let mut segment_collector = self.for_segment(segment_ord, reader)?;
+        match reader.alive_bitset() {
+            Some(alive_bitset) => weight.for_each_no_score(reader, &mut |docs| {
+                for doc in docs.iter().cloned() {
+                    if alive_bitset.is_alive(doc) {
+                        segment_collector.collect(doc, 0.0);
+                    }
+                }
+            })?,
+            None => weight
+                .for_each_no_score(reader, &mut |docs| segment_collector.collect_block(docs))?,
+        }
+        Ok(segment_collector.harvest())
+    }
 }
 
 pub struct RowIdRoaringSegmentCollector {
@@ -204,4 +245,44 @@ mod tests {
             .expect("Can't execute search.");
         assert_eq!(searched_bitmap_1.len(), 2);
     }
+
+    #[test]
+    fn test_rowid_roaring_collector_with_range() {
+        let temp_path = TempDir::new().expect("Can't create temp path");
+        let temp_path_str = temp_path.path().to_str().unwrap();
+        let index_reader = get_reader_from_index_path(temp_path_str);
+        let searcher = index_reader.searcher();
+
+        // Row_id range of all segments covers indexed row_ids.
+        let mut min_row_id = u64::MAX;
+        let mut max_row_id = u64::MIN;
+        for segment_reader in searcher.segment_readers() {
+            let (segment_min, segment_max) =
+                FFiIndexSearcherUtils::segment_row_id_range(segment_reader).unwrap();
+            min_row_id = min_row_id.min(segment_min);
+            max_row_id = max_row_id.max(segment_max);
+        }
+        assert_eq!((min_row_id, max_row_id), (0, 4));
+
+        let text_field = searcher.index().schema().get_field("text").unwrap();
+        let query_parser = QueryParser::for_index(searcher.index(), vec![text_field]);
+        let text_query = query_parser
+            .parse_query("Ancient")
+            .expect("Can't parse query");
+
+        let row_id_collector =
+            RowIdRoaringCollector::with_field("row_id".to_string()).with_range((0, 5));
+        let searched_bitmap = searcher
+            .search(&text_query, &row_id_collector)
+            .expect("Can't execute search.");
+        assert_eq!(searched_bitmap.len(), 2);
+
+        // Segments out of range are skipped.
+        let row_id_collector =
+            RowIdRoaringCollector::with_field("row_id".to_string()).with_range((5, 100));
+        let searched_bitmap = searcher
+            .search(&text_query, &row_id_collector)
+            .expect("Can't execute search.");
+        assert!(searched_bitmap.is_empty());
+    }
 }
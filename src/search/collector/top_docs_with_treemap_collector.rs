@@ -8,6 +8,7 @@ use tantivy::query::Weight;
 use tantivy::schema::{Field, Value};
 use tantivy::{DocAddress, DocId, Score, Searcher, SegmentOrdinal, SegmentReader, TantivyDocument};
 
+use crate::search::utils::index_searcher_utils::FFiIndexSearcherUtils;
 use crate::RowIdWithScore;
 
 // Class Inheritance Diagram:
@@ -28,6 +29,7 @@ pub struct TopDocsWithFilter64 {
     pub limit: usize,
     pub row_id_treemap: Option<Arc<RoaringTreemap>>,
     pub row_id_range: Option<(u64, u64)>,
+    pub sorted_by_row_id: bool,
     pub searcher: Option<Searcher>,
     pub text_fields: Option<Vec<Field>>,
     pub need_text: bool,
@@ -42,6 +44,7 @@ impl TopDocsWithFilter64 {
             limit,
             row_id_treemap: None,
             row_id_range: None,
+            sorted_by_row_id: false,
             searcher: None,
             text_fields: None,
             need_text: false,
@@ -61,6 +64,13 @@ impl TopDocsWithFilter64 {
         self
     }
 
+    // `sorted_by_row_id` means docs in each segment are sorted by row_id,
+    // so the scorer can skip to the `row_id_range` and stop once row_id exceeds the range end.
+    pub fn with_sorted_by_row_id(mut self, sorted_by_row_id: bool) -> TopDocsWithFilter64 {
+        self.sorted_by_row_id = sorted_by_row_id;
+        self
+    }
+
     // `searcher` is used to search origin text content.
     pub fn with_searcher(mut self, searcher: Searcher) -> TopDocsWithFilter64 {
         self.searcher = Some(searcher.clone());
@@ -182,6 +192,13 @@ impl Collector for TopDocsWithFilter64 {
         segment_ord: SegmentOrdinal,
         reader: &SegmentReader,
     ) -> tantivy::Result<<Self::Child as SegmentCollector>::Fruit> {
+        // Skip segments whose row_ids are all outside of `row_id_range`.
+        if let Some((start_id, end_id)) = self.row_id_range {
+            if !FFiIndexSearcherUtils::segment_overlaps_row_id_range(reader, start_id, end_id) {
+                return Ok(Vec::new());
+            }
+        }
+
         // REFINE: need a more efficient way to initialize binary-heap.
         let heap_len = cmp::min(self.limit, self.initial_heap_size);
         let mut heap: BinaryHeap<RowIdWithScore> = BinaryHeap::with_capacity(heap_len);
@@ -192,7 +209,48 @@ impl Collector for TopDocsWithFilter64 {
             .unwrap()
             .first_or_default_col(0);
 
-        if let Some(alive_bitset) = reader.alive_bitset() {
+        if let (true, Some((start, end))) = (self.sorted_by_row_id, self.row_id_range) {
+            let (start_doc, end_doc) = FFiIndexSearcherUtils::sorted_row_id_doc_range(
+                reader.max_doc(),
+                |doc| row_id_field_reader.get_val(doc),
+                start,
+                end,
+            );
+            let alive_bitset = reader.alive_bitset();
+            FFiIndexSearcherUtils::for_each_in_doc_range(
+                weight,
+                reader,
+                start_doc,
+                end_doc,
+                &mut |doc, score| {
+                    let row_id = row_id_field_reader.get_val(doc);
+                    if self.row_id_treemap.is_some()
+                        && !self.row_id_treemap.clone().unwrap().contains(row_id)
+                    {
+                        return;
+                    }
+                    if alive_bitset.is_some() && alive_bitset.unwrap().is_deleted(doc) {
+                        return;
+                    }
+                    let heap_item = RowIdWithScore {
+                        row_id,
+                        score,
+                        seg_id: segment_ord,
+                        doc_id: doc,
+                        docs: self.extract_doc_text(doc, segment_ord),
+                    };
+                    if heap.len() < heap_len {
+                        heap.push(heap_item);
+                        return;
+                    }
+                    if let Some(mut head) = heap.peek_mut() {
+                        if heap_item < *head {
+                            *head = heap_item;
+                        }
+                    }
+                },
+            )?;
+        } else if let Some(alive_bitset) = reader.alive_bitset() {
             let mut threshold = Score::MIN;
             weight.for_each_pruning(threshold, reader, &mut |doc, score| {
                 let row_id = row_id_field_reader.get_val(doc);
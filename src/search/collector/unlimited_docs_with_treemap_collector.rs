@@ -8,6 +8,7 @@ use tantivy::query::Weight;
 use tantivy::schema::{Field, Value};
 use tantivy::{DocAddress, DocId, Score, Searcher, SegmentOrdinal, SegmentReader, TantivyDocument};
 
+use crate::search::utils::index_searcher_utils::FFiIndexSearcherUtils;
 use crate::RowIdWithScore;
 
 // Class Inheritance Diagram:
@@ -24,6 +25,7 @@ use crate::RowIdWithScore;
 pub struct UnlimitedDocsWithFilter64 {
     pub row_id_treemap: Option<Arc<RoaringTreemap>>,
     pub row_id_range: Option<(u64, u64)>,
+    pub sorted_by_row_id: bool,
     pub searcher: Option<Searcher>,
     pub text_fields: Option<Vec<Field>>,
     pub need_text: bool,
@@ -35,6 +37,7 @@ impl UnlimitedDocsWithFilter64 {
         Self {
             row_id_treemap: None,
             row_id_range: None,
+            sorted_by_row_id: false,
             searcher: None,
             text_fields: None,
             need_text: false,
@@ -53,6 +56,13 @@ impl UnlimitedDocsWithFilter64 {
         self
     }
 
+    // `sorted_by_row_id` means docs in each segment are sorted by row_id,
+    // so the scorer can skip to the `row_id_range` and stop once row_id exceeds the range end.
+    pub fn with_sorted_by_row_id(mut self, sorted_by_row_id: bool) -> UnlimitedDocsWithFilter64 {
+        self.sorted_by_row_id = sorted_by_row_id;
+        self
+    }
+
     // `searcher` is used to search origin text content.
     pub fn with_searcher(mut self, searcher: Searcher) -> UnlimitedDocsWithFilter64 {
         self.searcher = Some(searcher.clone());
@@ -156,6 +166,13 @@ impl Collector for UnlimitedDocsWithFilter64 {
         segment_ord: SegmentOrdinal,
         reader: &SegmentReader,
     ) -> tantivy::Result<<Self::Child as SegmentCollector>::Fruit> {
+        // Skip segments whose row_ids are all outside of `row_id_range`.
+        if let Some((start_id, end_id)) = self.row_id_range {
+            if !FFiIndexSearcherUtils::segment_overlaps_row_id_range(reader, start_id, end_id) {
+                return Ok(Vec::new());
+            }
+        }
+
         let mut vec_row_ids_with_scores: Vec<RowIdWithScore> = vec![];
         let row_id_field_reader = reader
             .fast_fields()
@@ -163,7 +180,40 @@ impl Collector for UnlimitedDocsWithFilter64 {
             .unwrap()
             .first_or_default_col(0);
 
-        if let Some(alive_bitset) = reader.alive_bitset() {
+        if let (true, Some((start, end))) = (self.sorted_by_row_id, self.row_id_range) {
+            let (start_doc, end_doc) = FFiIndexSearcherUtils::sorted_row_id_doc_range(
+                reader.max_doc(),
+                |doc| row_id_field_reader.get_val(doc),
+                start,
+                end,
+            );
+            let alive_bitset = reader.alive_bitset();
+            FFiIndexSearcherUtils::for_each_in_doc_range(
+                weight,
+                reader,
+                start_doc,
+                end_doc,
+                &mut |doc, score| {
+                    let row_id = row_id_field_reader.get_val(doc);
+                    if self.row_id_treemap.is_some()
+                        && !self.row_id_treemap.clone().unwrap().contains(row_id)
+                    {
+                        return;
+                    }
+                    if alive_bitset.is_some() && alive_bitset.unwrap().is_deleted(doc) {
+                        return;
+                    }
+                    let vec_item = RowIdWithScore {
+                        row_id,
+                        score,
+                        seg_id: segment_ord,
+                        doc_id: doc,
+                        docs: self.extract_doc_text(doc, segment_ord),
+                    };
+                    vec_row_ids_with_scores.push(vec_item);
+                },
+            )?;
+        } else if let Some(alive_bitset) = reader.alive_bitset() {
             weight.for_each(reader, &mut |doc, score| {
                 let row_id = row_id_field_reader.get_val(doc);
                 if self.row_id_treemap.is_some()
@@ -10,6 +10,7 @@ use crate::{common::constants::LOG_CALLBACK, ERROR};
 use roaring::RoaringBitmap;
 use std::sync::Arc;
 
+use super::strategy::query_strategy::RangeQueryStrategy;
 use super::strategy::query_strategy::RegexQueryStrategy;
 use super::strategy::query_strategy::SentenceQueryStrategy;
 use super::strategy::query_strategy::TermSetQueryStrategy;
@@ -31,7 +32,11 @@ pub fn query_term_with_range(
         })?;
 
     // Choose query strategy to construct query executor.
-    let term_query: SingleTermQueryStrategy<'_> = SingleTermQueryStrategy { column_name, term };
+    let term_query: SingleTermQueryStrategy<'_> = SingleTermQueryStrategy {
+        column_name,
+        term,
+        row_id_range: Some((lrange, rrange.saturating_add(1))),
+    };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&term_query);
 
     // Compute query results.
@@ -68,7 +73,11 @@ pub fn query_terms_with_range(
         })?;
 
     // Choose query strategy to construct query executor.
-    let terms_query: TermSetQueryStrategy<'_> = TermSetQueryStrategy { column_name, terms };
+    let terms_query: TermSetQueryStrategy<'_> = TermSetQueryStrategy {
+        column_name,
+        terms,
+        row_id_range: Some((lrange, rrange.saturating_add(1))),
+    };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&terms_query);
 
     // Compute query results.
@@ -108,6 +117,7 @@ pub fn query_sentence_with_range(
     let sentence_query: SentenceQueryStrategy<'_> = SentenceQueryStrategy {
         column_name,
         sentence,
+        row_id_range: Some((lrange, rrange.saturating_add(1))),
     };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&sentence_query);
 
@@ -148,6 +158,7 @@ pub fn regex_term_with_range(
     let sentence_query: RegexQueryStrategy<'_> = RegexQueryStrategy {
         column_name,
         pattern,
+        row_id_range: Some((lrange, rrange.saturating_add(1))),
     };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&sentence_query);
 
@@ -183,7 +194,11 @@ pub fn query_term_bitmap(
         })?;
 
     // Choose query strategy to construct query executor.
-    let term_query: SingleTermQueryStrategy<'_> = SingleTermQueryStrategy { column_name, term };
+    let term_query: SingleTermQueryStrategy<'_> = SingleTermQueryStrategy {
+        column_name,
+        term,
+        row_id_range: None,
+    };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&term_query);
 
     // Compute query results.
@@ -216,7 +231,11 @@ pub fn query_terms_bitmap(
         })?;
 
     // Choose query strategy to construct query executor.
-    let sentence_query: TermSetQueryStrategy<'_> = TermSetQueryStrategy { column_name, terms };
+    let sentence_query: TermSetQueryStrategy<'_> = TermSetQueryStrategy {
+        column_name,
+        terms,
+        row_id_range: None,
+    };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&sentence_query);
 
     // Compute query results.
@@ -252,6 +271,7 @@ pub fn query_sentence_bitmap(
     let sentence_query: SentenceQueryStrategy<'_> = SentenceQueryStrategy {
         column_name,
         sentence,
+        row_id_range: None,
     };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&sentence_query);
 
@@ -288,6 +308,7 @@ pub fn regex_term_bitmap(
     let sentence_query: RegexQueryStrategy<'_> = RegexQueryStrategy {
         column_name,
         pattern,
+        row_id_range: None,
     };
     let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&sentence_query);
 
@@ -305,3 +326,41 @@ pub fn regex_term_bitmap(
 
     Ok(u8_bitmap)
 }
+
+/// Execute Range Query.
+pub fn range_query_bitmap(
+    index_path: &str,
+    column_name: &str,
+    lower_bound: &str,
+    upper_bound: &str,
+) -> Result<Vec<u8>, TantivySearchError> {
+    // Get index_reader_bridge from CACHE
+    let index_reader_bridge: Arc<IndexReaderBridge> = FFI_INDEX_SEARCHER_CACHE
+        .get_index_reader_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function:"range_query_bitmap", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    // Choose query strategy to construct query executor.
+    let range_query: RangeQueryStrategy<'_> = RangeQueryStrategy {
+        column_name,
+        lower_bound,
+        upper_bound,
+    };
+    let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&range_query);
+
+    // Compute query results.
+    let result: Arc<RoaringBitmap> = query_executor
+        .execute(&index_reader_bridge.reader.searcher())
+        .map_err(|e| {
+            ERROR!(function:"range_query_bitmap", "{}", e);
+            TantivySearchError::IndexSearcherError(e)
+        })?;
+
+    // Convert results to u8 bitmap.
+    let row_ids_number: Vec<u32> = result.iter().collect();
+    let u8_bitmap: Vec<u8> = ConvertUtils::row_ids_to_u8_bitmap(&row_ids_number);
+
+    Ok(u8_bitmap)
+}
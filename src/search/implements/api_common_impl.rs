@@ -1,10 +1,12 @@
 use crate::common::errors::TantivySearchError;
+use crate::ffi::{FieldSpaceUsage, SegmentStatistics};
 use crate::logger::logger_bridge::TantivySearchLogger;
 use crate::tokenizer::dto::index_parameter_dto::IndexParameterDTO;
 use crate::tokenizer::vo::tokenizers_vo::TokenizerConfig;
 use crate::utils::index_utils::IndexUtils;
 use crate::DEBUG;
 use crate::FFI_INDEX_SEARCHER_CACHE;
+use crate::FFI_INDEX_WRITER_CACHE;
 use crate::{common::constants::LOG_CALLBACK, ERROR};
 
 use std::{path::Path, sync::Arc};
@@ -12,23 +14,49 @@ use std::{path::Path, sync::Arc};
 use crate::search::bridge::index_reader_bridge::IndexReaderBridge;
 use crate::tokenizer::tokenizer_utils::TokenizerUtils;
 use std::collections::HashMap;
+use tantivy::schema::{Field, Schema};
+use tantivy::space_usage::SearcherSpaceUsage;
 use tantivy::Directory;
 use tantivy::IndexReader;
-use tantivy::{Index, ReloadPolicy};
+use tantivy::{Index, ReloadPolicy, Searcher};
+
+// Resolve tantivy index of `index_path`, a cached writer or reader is used first,
+// so an in-memory index can be found as well as a disk index.
+pub fn resolve_index(function_name: &str, index_path: &str) -> Result<Index, TantivySearchError> {
+    if let Ok(index_writer_bridge) =
+        FFI_INDEX_WRITER_CACHE.get_index_writer_bridge(index_path.to_string())
+    {
+        return Ok(index_writer_bridge.index.clone());
+    }
+    if let Ok(index_reader_bridge) =
+        FFI_INDEX_SEARCHER_CACHE.get_index_reader_bridge(index_path.to_string())
+    {
+        return Ok(index_reader_bridge.index.clone());
+    }
 
-pub fn get_index_json_parameter(index_path: &str) -> Result<String, TantivySearchError> {
     // Verify index files directory.
     let index_files_directory = Path::new(index_path);
     if !index_files_directory.exists() || !index_files_directory.is_dir() {
         let error_info: String = format!("index_path not exists: {:?}", index_path);
         let error: TantivySearchError = TantivySearchError::IndexNotExists(error_info);
-        ERROR!(function:"get_index_json_parameter", "{}", error.to_string());
+        ERROR!(function: function_name, "{}", error.to_string());
         return Err(error);
     }
 
-    // Load index parameter DTO from local index files.
-    let index_parameter_dto: IndexParameterDTO = IndexUtils::load_custom_index_setting(
-        index_files_directory,
+    // Load tantivy index with given directory.
+    Index::open_in_dir(index_files_directory).map_err(|e| {
+        let error: TantivySearchError = TantivySearchError::TantivyError(e);
+        ERROR!(function: function_name, "{}", error.to_string());
+        error
+    })
+}
+
+pub fn get_index_json_parameter(index_path: &str) -> Result<String, TantivySearchError> {
+    let index: Index = resolve_index("get_index_json_parameter", index_path)?;
+
+    // Load index parameter DTO from index directory.
+    let index_parameter_dto: IndexParameterDTO = IndexUtils::load_custom_index_setting_of_index(
+        &index,
     )
     .map_err(|e: crate::common::errors::IndexUtilsError| {
         ERROR!(function:"get_index_json_parameter", "{}", e);
@@ -41,21 +69,7 @@ pub fn get_index_json_parameter(index_path: &str) -> Result<String, TantivySearc
 }
 
 pub fn get_index_meta_json(index_path: &str) -> Result<String, TantivySearchError> {
-    // Verify index files directory.
-    let index_files_directory = Path::new(index_path);
-    if !index_files_directory.exists() || !index_files_directory.is_dir() {
-        let error_info: String = format!("index_path not exists: {:?}", index_path);
-        let error: TantivySearchError = TantivySearchError::IndexNotExists(error_info);
-        ERROR!(function:"get_index_meta_json", "{}", error.to_string());
-        return Err(error);
-    }
-
-    // Load tantivy index with given directory.
-    let index: Index = Index::open_in_dir(index_files_directory).map_err(|e| {
-        let error: TantivySearchError = TantivySearchError::TantivyError(e);
-        ERROR!(function:"load_index_reader", "{}", error.to_string());
-        error
-    })?;
+    let index: Index = resolve_index("get_index_meta_json", index_path)?;
 
     let meta_json_path = Path::new("meta.json");
 
@@ -90,16 +104,8 @@ pub fn get_index_meta_json(index_path: &str) -> Result<String, TantivySearchErro
     Ok(meta_string)
 }
 
-pub fn load_index_reader(index_path: &str) -> Result<bool, TantivySearchError> {
-    // Verify index files directory.
-    let index_files_directory = Path::new(index_path);
-    if !index_files_directory.exists() || !index_files_directory.is_dir() {
-        let error_info: String = format!("index_path not exists: {:?}", index_path);
-        let error: TantivySearchError = TantivySearchError::IndexNotExists(error_info);
-        ERROR!(function:"load_index_reader", "{}", error.to_string());
-        return Err(error);
-    }
-
+// Open tantivy index in `index_files_directory` with tokenizers of its json parameter.
+fn open_index_with_tokenizers(index_files_directory: &Path) -> Result<Index, TantivySearchError> {
     // Load tantivy index with given directory.
     let mut index: Index = Index::open_in_dir(index_files_directory).map_err(|e| {
         let error: TantivySearchError = TantivySearchError::TantivyError(e);
@@ -128,20 +134,42 @@ pub fn load_index_reader(index_path: &str) -> Result<bool, TantivySearchError> {
             TantivySearchError::TokenizerUtilsError(e)
         })?;
 
-    // Register tokenizer config into `index`.
+    // Register tokenizer config into `index`, readers analyze queries with the search analyzer.
     for (column_name, tokenizer_config) in col_tokenizer_map.iter() {
         TokenizerUtils::register_tokenizer_to_index(
             &mut index,
             tokenizer_config.tokenizer_type.clone(),
             &column_name,
-            tokenizer_config.text_analyzer.clone(),
+            tokenizer_config.search_text_analyzer.clone(),
         )
         .map_err(|e| {
             ERROR!(function:"load_index_reader", "{}", e);
             TantivySearchError::TokenizerUtilsError(e)
         })?;
+        TokenizerUtils::register_query_synonyms_marker(&mut index, &column_name, tokenizer_config);
     }
 
+    Ok(index)
+}
+
+pub fn load_index_reader(index_path: &str) -> Result<bool, TantivySearchError> {
+    // Verify index files directory, an in-memory index only lives in writer cache.
+    let index_files_directory = Path::new(index_path);
+    let mut index: Index = if index_files_directory.exists() && index_files_directory.is_dir() {
+        open_index_with_tokenizers(index_files_directory)?
+    } else {
+        match FFI_INDEX_WRITER_CACHE.get_index_writer_bridge(index_path.to_string()) {
+            // Tokenizers are registered when the in-memory index is created.
+            Ok(index_writer_bridge) => index_writer_bridge.index.clone(),
+            Err(_) => {
+                let error_info: String = format!("index_path not exists: {:?}", index_path);
+                let error: TantivySearchError = TantivySearchError::IndexNotExists(error_info);
+                ERROR!(function:"load_index_reader", "{}", error.to_string());
+                return Err(error);
+            }
+        }
+    };
+
     #[cfg(feature = "use-shared-search-pool")]
     {
         // Set the multithreaded executor for search.
@@ -228,3 +256,69 @@ pub fn get_indexed_doc_counts(index_path: &str) -> Result<u64, TantivySearchErro
     let num_docs: u64 = index_reader_bridge.reader.searcher().num_docs();
     Ok(num_docs)
 }
+
+/// Get on-disk size of each segment of the loaded reader, and space usage of each field.
+/// Returns (segment statistics, field space usages, total bytes).
+pub fn get_index_statistics(
+    index_path: &str,
+) -> Result<(Vec<SegmentStatistics>, Vec<FieldSpaceUsage>, u64), TantivySearchError> {
+    // get index_reader_bridge from CACHE
+    let index_reader_bridge: Arc<IndexReaderBridge> = FFI_INDEX_SEARCHER_CACHE
+        .get_index_reader_bridge(index_path.to_string())
+        .map_err(|e| {
+            ERROR!(function:"get_index_statistics", "{}", e);
+            TantivySearchError::InternalError(e)
+        })?;
+
+    let searcher: Searcher = index_reader_bridge.reader.searcher();
+    let space_usage: SearcherSpaceUsage = searcher.space_usage().map_err(|e| {
+        let error_info: String = format!("Failed to compute space usage: {}", e);
+        ERROR!(function:"get_index_statistics", "{}", error_info);
+        TantivySearchError::InternalError(error_info)
+    })?;
+
+    // Segment readers and their space usages are in the same order.
+    let mut field_bytes: HashMap<Field, u64> = HashMap::new();
+    let mut segments: Vec<SegmentStatistics> = Vec::new();
+    for (segment_reader, segment_usage) in searcher
+        .segment_readers()
+        .iter()
+        .zip(space_usage.segments().iter())
+    {
+        for per_field_usage in [
+            segment_usage.termdict(),
+            segment_usage.postings(),
+            segment_usage.positions(),
+            segment_usage.fast_fields(),
+            segment_usage.fieldnorms(),
+        ] {
+            for (field, field_usage) in per_field_usage.fields() {
+                *field_bytes.entry(*field).or_insert(0) += field_usage.total().get_bytes();
+            }
+        }
+        segments.push(SegmentStatistics {
+            segment_id: segment_reader.segment_id().uuid_string(),
+            max_doc: segment_reader.max_doc(),
+            num_deleted_docs: segment_reader.num_deleted_docs(),
+            postings_bytes: segment_usage.postings().total().get_bytes(),
+            positions_bytes: segment_usage.positions().total().get_bytes(),
+            term_dict_bytes: segment_usage.termdict().total().get_bytes(),
+            fast_fields_bytes: segment_usage.fast_fields().total().get_bytes(),
+            fieldnorms_bytes: segment_usage.fieldnorms().total().get_bytes(),
+            store_bytes: segment_usage.store().total().get_bytes(),
+            deletes_bytes: segment_usage.deletes().get_bytes(),
+            total_bytes: segment_usage.total().get_bytes(),
+        });
+    }
+
+    let schema: Schema = searcher.schema().clone();
+    let fields: Vec<FieldSpaceUsage> = schema
+        .fields()
+        .map(|(field, field_entry)| FieldSpaceUsage {
+            field_name: field_entry.name().to_string(),
+            num_bytes: field_bytes.get(&field).copied().unwrap_or(0),
+        })
+        .collect();
+
+    Ok((segments, fields, space_usage.total().get_bytes()))
+}
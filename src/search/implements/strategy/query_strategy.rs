@@ -1,20 +1,26 @@
+use std::ops::Bound;
 use std::sync::Arc;
 
-use rayon::range;
 use roaring::{RoaringBitmap, RoaringTreemap};
-use tantivy::query::{Query, QueryParser, QueryParserError, RegexQuery, TermQuery, TermSetQuery};
+use tantivy::query::{
+    BooleanQuery, Occur, PhraseQuery, Query, QueryParser, QueryParserError, RangeQuery, RegexQuery,
+    TermQuery, TermSetQuery,
+};
 use tantivy::schema::{Field, FieldType, IndexRecordOption, TextFieldIndexing};
 use tantivy::tokenizer::{BoxTokenStream, TextAnalyzer};
-use tantivy::{schema::Schema, Searcher};
+use tantivy::{schema::Schema, Index, Searcher};
 use tantivy::{TantivyError, Term};
 
 use crate::common::constants::LOG_CALLBACK;
+use crate::common::converter::{parse_bool, parse_datetime, parse_ip};
 use crate::logger::logger_bridge::TantivySearchLogger;
 use crate::search::collector::row_id_bitmap_collector::RowIdRoaringCollector;
 use crate::search::collector::top_docs_with_bitmap_collector::TopDocsWithFilter;
 use crate::search::collector::top_docs_with_treemap_collector::TopDocsWithFilter64;
 use crate::search::collector::unlimited_docs_with_treemap_collector::UnlimitedDocsWithFilter64;
 use crate::search::utils::convert_utils::ConvertUtils;
+use crate::search::utils::index_searcher_utils::FFiIndexSearcherUtils;
+use crate::tokenizer::tokenizer_utils::TokenizerUtils;
 use crate::INFO;
 use crate::{common::errors::IndexSearcherError, ffi::RowIdWithScore, ERROR};
 
@@ -22,19 +28,247 @@ pub trait QueryStrategy<T> {
     fn execute(&self, searcher: &Searcher) -> Result<T, IndexSearcherError>;
 }
 
+/// Tokenize texts into terms of a path inside a json column, e.g. `attrs.color`.
+/// Returns `None` if `column_name` doesn't point into a json column.
+fn json_path_terms(
+    function_name: &str,
+    index: &Index,
+    column_name: &str,
+    texts: &[&str],
+) -> Result<Option<Vec<Term>>, IndexSearcherError> {
+    let schema: Schema = index.schema();
+    let (json_field, json_path) = match schema.find_field(column_name) {
+        Some(field_and_path) => field_and_path,
+        None => return Ok(None),
+    };
+    let json_options = match schema.get_field_entry(json_field).field_type() {
+        FieldType::JsonObject(json_options) => json_options.clone(),
+        _ => return Ok(None),
+    };
+    if json_path.is_empty() {
+        let error_msg: String = format!(
+            "column field:{} is a json column, query it with a json path like `{}.key`.",
+            column_name, column_name
+        );
+        ERROR!(function: function_name, "{}", error_msg);
+        return Err(IndexSearcherError::InternalError(error_msg));
+    }
+    let indexing_options: &TextFieldIndexing =
+        json_options.get_text_indexing_options().ok_or_else(|| {
+            let error_msg: String = format!("column field:{} not indexed.", column_name);
+            ERROR!(function: function_name, "{}", error_msg);
+            IndexSearcherError::InternalError(error_msg)
+        })?;
+    let mut text_analyzer: TextAnalyzer = index
+        .tokenizers()
+        .get(indexing_options.tokenizer())
+        .ok_or_else(|| {
+            let error_msg: String = format!(
+                "tokenizer:{} of column field:{} not registered.",
+                indexing_options.tokenizer(),
+                column_name
+            );
+            ERROR!(function: function_name, "{}", error_msg);
+            IndexSearcherError::InternalError(error_msg)
+        })?;
+
+    let mut terms: Vec<Term> = Vec::new();
+    for text in texts {
+        let mut token_stream: BoxTokenStream<'_> = text_analyzer.token_stream(text);
+        token_stream.process(&mut |token| {
+            let mut term: Term = Term::from_field_json_path(
+                json_field,
+                json_path,
+                json_options.is_expand_dots_enabled(),
+            );
+            term.append_type_and_str(&token.text);
+            terms.push(term);
+        });
+    }
+    Ok(Some(terms))
+}
+
+/// Phrase of synonyms is expanded into a disjunction of phrases, one for each combination
+/// of synonyms. Expanding stops at this limit, the query is rejected beyond it.
+const MAX_SYNONYM_PHRASES: usize = 64;
+
+/// Split terms of a phrase into groups of the same offset. Synonyms expanded by the
+/// search analyzer keep the position of their original token.
+fn synonym_groups(phrase_query: &PhraseQuery) -> Vec<(usize, Vec<Term>)> {
+    let mut groups: Vec<(usize, Vec<Term>)> = Vec::new();
+    for (offset, term) in phrase_query.phrase_terms_with_offsets().iter() {
+        match groups.last_mut() {
+            Some((group_offset, group)) if *group_offset == *offset => group.push(term.clone()),
+            _ => groups.push((*offset, vec![term.clone()])),
+        }
+    }
+    groups
+}
+
+/// QueryParser turns tokens at the same position into a phrase query, which requires all
+/// of them. For columns expanding synonyms at query side, synonyms are rewritten into a
+/// disjunction of phrases, so that any word of a synonym group matches in place.
+fn expand_synonym_phrases(
+    function_name: &str,
+    index: &Index,
+    query: Box<dyn Query>,
+) -> Result<Box<dyn Query>, IndexSearcherError> {
+    if let Some(boolean_query) = query.downcast_ref::<BooleanQuery>() {
+        let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+        for (occur, sub_query) in boolean_query.clauses() {
+            clauses.push((
+                *occur,
+                expand_synonym_phrases(function_name, index, sub_query.box_clone())?,
+            ));
+        }
+        return Ok(Box::new(BooleanQuery::new(clauses)));
+    }
+    let phrase_query: &PhraseQuery = match query.downcast_ref::<PhraseQuery>() {
+        Some(phrase_query) => phrase_query,
+        None => return Ok(query),
+    };
+    if !TokenizerUtils::has_query_synonyms(index, phrase_query.field()) {
+        return Ok(query);
+    }
+    let groups: Vec<(usize, Vec<Term>)> = synonym_groups(phrase_query);
+    if groups.iter().all(|(_, group)| group.len() == 1) {
+        return Ok(query);
+    }
+
+    // Each combination picks one term of every group, offsets between groups are kept.
+    let mut phrases: Vec<Vec<(usize, Term)>> = vec![Vec::new()];
+    for (offset, group) in groups.iter() {
+        if phrases.len() * group.len() > MAX_SYNONYM_PHRASES {
+            let error_msg: String = format!(
+                "phrase expands into more than {} synonym combinations",
+                MAX_SYNONYM_PHRASES
+            );
+            ERROR!(function: function_name, "{}", error_msg);
+            return Err(IndexSearcherError::QueryParserError(error_msg));
+        }
+        phrases = phrases
+            .iter()
+            .flat_map(|phrase| {
+                group.iter().map(move |term| {
+                    let mut phrase: Vec<(usize, Term)> = phrase.clone();
+                    phrase.push((*offset, term.clone()));
+                    phrase
+                })
+            })
+            .collect();
+    }
+    let clauses: Vec<(Occur, Box<dyn Query>)> = phrases
+        .into_iter()
+        .map(|phrase| -> (Occur, Box<dyn Query>) {
+            if phrase.len() == 1 {
+                let (_, term) = phrase.into_iter().next().unwrap();
+                return (
+                    Occur::Should,
+                    Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs)),
+                );
+            }
+            let mut expanded_phrase: PhraseQuery = PhraseQuery::new_with_offset(phrase);
+            expanded_phrase.set_slop(phrase_query.slop());
+            (Occur::Should, Box::new(expanded_phrase))
+        })
+        .collect();
+    Ok(Box::new(BooleanQuery::new(clauses)))
+}
+
+/// Build a term for non-text columns, `text` is parsed with the column type.
+fn non_text_term(
+    function_name: &str,
+    col_field: Field,
+    field_type: &FieldType,
+    text: &str,
+) -> Result<Term, IndexSearcherError> {
+    let parse_error = |e: String| {
+        let error_msg: String = format!(
+            "Can't parse '{}' as {:?} value: {}",
+            text,
+            field_type.value_type(),
+            e
+        );
+        ERROR!(function: function_name, "{}", error_msg);
+        IndexSearcherError::QueryParserError(error_msg)
+    };
+    let term: Term = match field_type {
+        FieldType::U64(_) => Term::from_field_u64(
+            col_field,
+            text.parse::<u64>()
+                .map_err(|e| parse_error(e.to_string()))?,
+        ),
+        FieldType::I64(_) => Term::from_field_i64(
+            col_field,
+            text.parse::<i64>()
+                .map_err(|e| parse_error(e.to_string()))?,
+        ),
+        FieldType::F64(_) => Term::from_field_f64(
+            col_field,
+            text.parse::<f64>()
+                .map_err(|e| parse_error(e.to_string()))?,
+        ),
+        FieldType::Bool(_) => Term::from_field_bool(
+            col_field,
+            parse_bool(text).map_err(|e| parse_error(e.to_string()))?,
+        ),
+        FieldType::IpAddr(_) => Term::from_field_ip_addr(
+            col_field,
+            parse_ip(text).map_err(|e| parse_error(e.to_string()))?,
+        ),
+        // Not Expected.
+        _ => Term::from_field_text(col_field, text),
+    };
+    Ok(term)
+}
+
+/// Execute a term set query, collect matched row_ids.
+fn search_term_set(
+    function_name: &str,
+    searcher: &Searcher,
+    terms: Vec<Term>,
+    row_id_range: Option<(u64, u64)>,
+) -> Result<Arc<RoaringBitmap>, IndexSearcherError> {
+    let ter_set_query: TermSetQuery = TermSetQuery::new(terms);
+    let mut row_id_collector: RowIdRoaringCollector =
+        RowIdRoaringCollector::with_field("row_id".to_string());
+    if let Some(row_id_range) = row_id_range {
+        row_id_collector = row_id_collector.with_range(row_id_range);
+    }
+
+    searcher
+        .search(&ter_set_query, &row_id_collector)
+        .map_err(|e| {
+            ERROR!(function: function_name, "{}", e);
+            IndexSearcherError::TantivyError(e)
+        })
+}
+
 /// Execute query for a group of terms.
 ///
 /// Params:
 /// - `column_name`: Execute query in which column.
 /// - `terms`: A group of terms.
+/// - `row_id_range`: Skip segments without row_ids in `[start, end)`, `None` means search all segments.
 ///
 pub struct TermSetQueryStrategy<'a> {
     pub column_name: &'a str,
     pub terms: &'a Vec<String>,
+    pub row_id_range: Option<(u64, u64)>,
 }
 
 impl<'a> QueryStrategy<Arc<RoaringBitmap>> for TermSetQueryStrategy<'a> {
     fn execute(&self, searcher: &Searcher) -> Result<Arc<RoaringBitmap>, IndexSearcherError> {
+        let texts: Vec<&str> = self.terms.iter().map(|term| term.as_str()).collect();
+        if let Some(terms) = json_path_terms(
+            "TermSetQueryStrategy",
+            searcher.index(),
+            self.column_name,
+            &texts,
+        )? {
+            return search_term_set("TermSetQueryStrategy", searcher, terms, self.row_id_range);
+        }
+
         let schema: Schema = searcher.index().schema();
 
         let col_field: Field = schema.get_field(self.column_name).map_err(|e| {
@@ -75,15 +309,22 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for TermSetQueryStrategy<'a> {
                 });
             }
         } else {
-            // Not Expected.
             for term in self.terms {
-                terms.push(Term::from_field_text(col_field, &term));
+                terms.push(non_text_term(
+                    "TermSetQueryStrategy",
+                    col_field,
+                    field_type,
+                    term,
+                )?);
             }
         }
 
         let ter_set_query: TermSetQuery = TermSetQuery::new(terms);
-        let row_id_collector: RowIdRoaringCollector =
+        let mut row_id_collector: RowIdRoaringCollector =
             RowIdRoaringCollector::with_field("row_id".to_string());
+        if let Some(row_id_range) = self.row_id_range {
+            row_id_collector = row_id_collector.with_range(row_id_range);
+        }
 
         searcher
             .search(&ter_set_query, &row_id_collector)
@@ -99,75 +340,167 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for TermSetQueryStrategy<'a> {
 /// Params:
 /// - `column_name`: Execute query in which column.
 /// - `term`: Term need to be queried.
+/// - `row_id_range`: Skip segments without row_ids in `[start, end)`, `None` means search all segments.
 ///
 pub struct SingleTermQueryStrategy<'a> {
     pub column_name: &'a str,
     pub term: &'a str,
+    pub row_id_range: Option<(u64, u64)>,
 }
 
 impl<'a> QueryStrategy<Arc<RoaringBitmap>> for SingleTermQueryStrategy<'a> {
     fn execute(&self, searcher: &Searcher) -> Result<Arc<RoaringBitmap>, IndexSearcherError> {
-        let schema: Schema = searcher.index().schema();
+        let term_query: Box<dyn Query> = single_term_query(
+            "SingleTermQueryStrategy",
+            searcher.index(),
+            self.column_name,
+            self.term,
+        )?;
+        let mut row_id_collector: RowIdRoaringCollector =
+            RowIdRoaringCollector::with_field("row_id".to_string());
+        if let Some(row_id_range) = self.row_id_range {
+            row_id_collector = row_id_collector.with_range(row_id_range);
+        }
 
-        let col_field: Field = schema.get_field(self.column_name).map_err(|e| {
-            let error: IndexSearcherError = IndexSearcherError::TantivyError(e);
-            ERROR!(function:"SingleTermQueryStrategy", "{}", error);
-            error
-        })?;
+        searcher
+            .search(&term_query, &row_id_collector)
+            .map_err(|e| {
+                ERROR!(function:"SingleTermQueryStrategy", "{}", e);
+                IndexSearcherError::TantivyError(e)
+            })
+    }
+}
 
-        let field_type: &FieldType = schema.get_field_entry(col_field).field_type();
-        if !field_type.is_indexed() {
-            let error_msg: String = format!("column field:{} not indexed.", self.column_name);
-            ERROR!(function:"SingleTermQueryStrategy", "{}", error_msg);
-            return Err(IndexSearcherError::InternalError(error_msg));
-        }
+/// Build query for a single term of a column, text terms are tokenized with the column tokenizer
+/// and matched by any token, other terms are parsed with the column type.
+pub fn single_term_query(
+    function_name: &str,
+    index: &Index,
+    column_name: &str,
+    term: &str,
+) -> Result<Box<dyn Query>, IndexSearcherError> {
+    if let Some(terms) = json_path_terms(function_name, index, column_name, &[term])? {
+        return Ok(Box::new(TermSetQuery::new(terms)));
+    }
 
-        if let FieldType::Str(ref str_options) = field_type {
-            let indexing_options: &TextFieldIndexing =
-                str_options.get_indexing_options().ok_or_else(|| {
-                    let error_msg: String = format!(
-                        "column field:{} not indexed, but this error msg shouldn't display",
-                        self.column_name
-                    );
-                    ERROR!(function:"SingleTermQueryStrategy", "{}", error_msg);
-                    IndexSearcherError::InternalError(error_msg)
-                })?;
-            let mut terms: Vec<Term> = Vec::new();
-            let mut text_analyzer: TextAnalyzer = searcher
-                .index()
-                .tokenizers()
-                .get(indexing_options.tokenizer())
-                .unwrap();
-            let mut token_stream: BoxTokenStream<'_> = text_analyzer.token_stream(self.term);
-            token_stream.process(&mut |token| {
-                let term: Term = Term::from_field_text(col_field, &token.text);
-                terms.push(term);
-            });
+    let schema: Schema = index.schema();
 
-            let ter_set_query: TermSetQuery = TermSetQuery::new(terms);
-            let row_id_collector: RowIdRoaringCollector =
-                RowIdRoaringCollector::with_field("row_id".to_string());
+    let col_field: Field = schema.get_field(column_name).map_err(|e| {
+        let error: IndexSearcherError = IndexSearcherError::TantivyError(e);
+        ERROR!(function: function_name, "{}", error);
+        error
+    })?;
 
-            searcher
-                .search(&ter_set_query, &row_id_collector)
-                .map_err(|e| {
-                    ERROR!(function:"SingleTermQueryStrategy", "{}", e);
-                    IndexSearcherError::TantivyError(e)
-                })
-        } else {
-            // Not Expected.
-            let term: Term = Term::from_field_text(col_field, self.term);
-            let term_query: TermQuery = TermQuery::new(term, IndexRecordOption::WithFreqs);
-            let row_id_collector: RowIdRoaringCollector =
-                RowIdRoaringCollector::with_field("row_id".to_string());
-            println!("for not str");
-            searcher
-                .search(&term_query, &row_id_collector)
-                .map_err(|e| {
-                    ERROR!(function:"SingleTermQueryStrategy", "{}", e);
-                    IndexSearcherError::TantivyError(e)
-                })
+    let field_type: &FieldType = schema.get_field_entry(col_field).field_type();
+    if !field_type.is_indexed() {
+        let error_msg: String = format!("column field:{} not indexed.", column_name);
+        ERROR!(function: function_name, "{}", error_msg);
+        return Err(IndexSearcherError::InternalError(error_msg));
+    }
+
+    if let FieldType::Str(ref str_options) = field_type {
+        let indexing_options: &TextFieldIndexing =
+            str_options.get_indexing_options().ok_or_else(|| {
+                let error_msg: String = format!(
+                    "column field:{} not indexed, but this error msg shouldn't display",
+                    column_name
+                );
+                ERROR!(function: function_name, "{}", error_msg);
+                IndexSearcherError::InternalError(error_msg)
+            })?;
+        let mut terms: Vec<Term> = Vec::new();
+        let mut text_analyzer: TextAnalyzer = index
+            .tokenizers()
+            .get(indexing_options.tokenizer())
+            .unwrap();
+        let mut token_stream: BoxTokenStream<'_> = text_analyzer.token_stream(term);
+        token_stream.process(&mut |token| {
+            let term: Term = Term::from_field_text(col_field, &token.text);
+            terms.push(term);
+        });
+
+        Ok(Box::new(TermSetQuery::new(terms)))
+    } else {
+        let term: Term = non_text_term(function_name, col_field, field_type, term)?;
+        Ok(Box::new(TermQuery::new(term, IndexRecordOption::Basic)))
+    }
+}
+
+/// Build a query of the single term analyzed from `term`, it's used to delete documents.
+/// Text analyzed into several tokens is rejected, only synonyms of the original token are
+/// ignored since they are indexed together with it.
+pub fn exact_term_query(
+    function_name: &str,
+    index: &Index,
+    column_name: &str,
+    term: &str,
+) -> Result<Box<dyn Query>, IndexSearcherError> {
+    let several_terms_error = |terms_count: usize| {
+        let error_msg: String = format!(
+            "'{}' is analyzed into {} terms of column field:{}, expect exactly one.",
+            term, terms_count, column_name
+        );
+        ERROR!(function: function_name, "{}", error_msg);
+        IndexSearcherError::QueryParserError(error_msg)
+    };
+    if let Some(mut terms) = json_path_terms(function_name, index, column_name, &[term])? {
+        if terms.len() != 1 {
+            return Err(several_terms_error(terms.len()));
         }
+        return Ok(Box::new(TermQuery::new(
+            terms.remove(0),
+            IndexRecordOption::Basic,
+        )));
+    }
+
+    let schema: Schema = index.schema();
+    let col_field: Field = schema.get_field(column_name).map_err(|e| {
+        let error: IndexSearcherError = IndexSearcherError::TantivyError(e);
+        ERROR!(function: function_name, "{}", error);
+        error
+    })?;
+    let field_type: &FieldType = schema.get_field_entry(col_field).field_type();
+    if !field_type.is_indexed() {
+        let error_msg: String = format!("column field:{} not indexed.", column_name);
+        ERROR!(function: function_name, "{}", error_msg);
+        return Err(IndexSearcherError::InternalError(error_msg));
+    }
+    if !matches!(field_type, FieldType::Str(_)) {
+        let term: Term = non_text_term(function_name, col_field, field_type, term)?;
+        return Ok(Box::new(TermQuery::new(term, IndexRecordOption::Basic)));
+    }
+
+    let mut text_analyzer: TextAnalyzer = index.tokenizer_for_field(col_field).map_err(|e| {
+        let error: IndexSearcherError = IndexSearcherError::TantivyError(e);
+        ERROR!(function: function_name, "{}", error);
+        error
+    })?;
+    // Synonyms keep position and offsets of their original token, while other tokens at
+    // the same position (e.g. grams of ngram tokenizer) cover different parts of the text.
+    let mut tokens: Vec<(usize, usize, usize, String)> = Vec::new();
+    let mut token_stream: BoxTokenStream<'_> = text_analyzer.token_stream(term);
+    token_stream.process(&mut |token| {
+        tokens.push((
+            token.position,
+            token.offset_from,
+            token.offset_to,
+            token.text.clone(),
+        ))
+    });
+    match tokens.first() {
+        Some((position, offset_from, offset_to, text))
+            if tokens
+                .iter()
+                .all(|(other_position, other_from, other_to, _)| {
+                    other_position == position && other_from == offset_from && other_to == offset_to
+                }) =>
+        {
+            Ok(Box::new(TermQuery::new(
+                Term::from_field_text(col_field, text),
+                IndexRecordOption::Basic,
+            )))
+        }
+        _ => Err(several_terms_error(tokens.len())),
     }
 }
 
@@ -176,10 +509,12 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for SingleTermQueryStrategy<'a> {
 /// Params:
 /// - `column_name`: Execute query in which column.
 /// - `pattern`: Regex query will execute with given pattern str.
+/// - `row_id_range`: Skip segments without row_ids in `[start, end)`, `None` means search all segments.
 ///
 pub struct RegexQueryStrategy<'a> {
     pub column_name: &'a str,
     pub pattern: &'a str,
+    pub row_id_range: Option<(u64, u64)>,
 }
 
 impl<'a> QueryStrategy<Arc<RoaringBitmap>> for RegexQueryStrategy<'a> {
@@ -192,8 +527,11 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for RegexQueryStrategy<'a> {
             error
         })?;
 
-        let row_id_collector: RowIdRoaringCollector =
+        let mut row_id_collector: RowIdRoaringCollector =
             RowIdRoaringCollector::with_field("row_id".to_string());
+        if let Some(row_id_range) = self.row_id_range {
+            row_id_collector = row_id_collector.with_range(row_id_range);
+        }
         let regex_query: RegexQuery = RegexQuery::from_pattern(&ConvertUtils::like_to_regex(self.pattern), col_field).map_err(|e|{
             ERROR!(function:"RegexQueryStrategy", "Error when parse regex query:{}. {}", ConvertUtils::like_to_regex(self.pattern), e);
             IndexSearcherError::TantivyError(e)
@@ -206,6 +544,96 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for RegexQueryStrategy<'a> {
     }
 }
 
+/// Execute range query for numeric, datetime or ip columns.
+/// Both bounds are inclusive, an empty bound means unbounded.
+///
+/// Params:
+/// - `column_name`: Execute query in which column.
+/// - `lower_bound`: Lower bound value, parsed with the column type.
+/// - `upper_bound`: Upper bound value, parsed with the column type.
+///
+pub struct RangeQueryStrategy<'a> {
+    pub column_name: &'a str,
+    pub lower_bound: &'a str,
+    pub upper_bound: &'a str,
+}
+
+impl<'a> QueryStrategy<Arc<RoaringBitmap>> for RangeQueryStrategy<'a> {
+    fn execute(&self, searcher: &Searcher) -> Result<Arc<RoaringBitmap>, IndexSearcherError> {
+        let range_query: RangeQuery = column_range_query(
+            "RangeQueryStrategy",
+            &searcher.index().schema(),
+            self.column_name,
+            self.lower_bound,
+            self.upper_bound,
+        )?;
+        let row_id_collector: RowIdRoaringCollector =
+            RowIdRoaringCollector::with_field("row_id".to_string());
+
+        searcher
+            .search(&range_query, &row_id_collector)
+            .map_err(|e| {
+                ERROR!(function:"RangeQueryStrategy", "{}", e);
+                IndexSearcherError::TantivyError(e)
+            })
+    }
+}
+
+/// Build inclusive range query for numeric, datetime or ip columns.
+/// Bounds are parsed with the column type, empty bound means unbounded.
+pub fn column_range_query(
+    function_name: &str,
+    schema: &Schema,
+    column_name: &str,
+    lower_bound: &str,
+    upper_bound: &str,
+) -> Result<RangeQuery, IndexSearcherError> {
+    let col_field: Field = schema.get_field(column_name).map_err(|e| {
+        let error: IndexSearcherError = IndexSearcherError::TantivyError(e);
+        ERROR!(function: function_name, "{}", error);
+        error
+    })?;
+
+    let field_type: &FieldType = schema.get_field_entry(col_field).field_type();
+    if !field_type.is_indexed() && !field_type.is_fast() {
+        let error_msg: String = format!("column field:{} not indexed or fast.", column_name);
+        ERROR!(function: function_name, "{}", error_msg);
+        return Err(IndexSearcherError::InternalError(error_msg));
+    }
+    if matches!(field_type, FieldType::Str(_) | FieldType::JsonObject(_)) {
+        let error_msg: String = format!(
+            "column field:{} is not a numeric, datetime or ip column.",
+            column_name
+        );
+        ERROR!(function: function_name, "{}", error_msg);
+        return Err(IndexSearcherError::InternalError(error_msg));
+    }
+
+    let to_bound = |value: &str| -> Result<Bound<Term>, IndexSearcherError> {
+        if value.is_empty() {
+            Ok(Bound::Unbounded)
+        } else {
+            let term: Term = match field_type {
+                FieldType::Date(_) => Term::from_field_date(
+                    col_field,
+                    parse_datetime(value).map_err(|e| {
+                        ERROR!(function: function_name, "{}", e);
+                        IndexSearcherError::QueryParserError(e.to_string())
+                    })?,
+                ),
+                _ => non_text_term(function_name, col_field, field_type, value)?,
+            };
+            Ok(Bound::Included(term))
+        }
+    };
+    Ok(RangeQuery::new_term_bounds(
+        column_name.to_string(),
+        field_type.value_type(),
+        &to_bound(lower_bound)?,
+        &to_bound(upper_bound)?,
+    ))
+}
+
 /// Execute query for a sentence, without natural language search.
 /// This sentence can be written by natural language, or just simple terms.
 /// It will convert to terms query when execute.
@@ -213,14 +641,25 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for RegexQueryStrategy<'a> {
 /// Params:
 /// - `column_name`: Execute query in which column.
 /// - `sentence`: Sentence need to query.
+/// - `row_id_range`: Skip segments without row_ids in `[start, end)`, `None` means search all segments.
 ///
 pub struct SentenceQueryStrategy<'a> {
     pub column_name: &'a str,
     pub sentence: &'a str,
+    pub row_id_range: Option<(u64, u64)>,
 }
 
 impl<'a> QueryStrategy<Arc<RoaringBitmap>> for SentenceQueryStrategy<'a> {
     fn execute(&self, searcher: &Searcher) -> Result<Arc<RoaringBitmap>, IndexSearcherError> {
+        if let Some(terms) = json_path_terms(
+            "SentenceQueryStrategy",
+            searcher.index(),
+            self.column_name,
+            &[self.sentence],
+        )? {
+            return search_term_set("SentenceQueryStrategy", searcher, terms, self.row_id_range);
+        }
+
         let schema: Schema = searcher.index().schema();
 
         let col_field: Field = schema.get_field(self.column_name).map_err(|e| {
@@ -266,8 +705,11 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for SentenceQueryStrategy<'a> {
         }
 
         let ter_set_query: TermSetQuery = TermSetQuery::new(terms);
-        let row_id_collector: RowIdRoaringCollector =
+        let mut row_id_collector: RowIdRoaringCollector =
             RowIdRoaringCollector::with_field("row_id".to_string());
+        if let Some(row_id_range) = self.row_id_range {
+            row_id_collector = row_id_collector.with_range(row_id_range);
+        }
 
         searcher
             .search(&ter_set_query, &row_id_collector)
@@ -309,6 +751,8 @@ impl<'a> QueryStrategy<Arc<RoaringBitmap>> for ParserQueryStrategy<'a> {
             ERROR!(function:"ParserQueryStrategy", "Error when parse: {}. {}", self.sentence, e);
             IndexSearcherError::QueryParserError(e.to_string())
         })?;
+        let text_query =
+            expand_synonym_phrases("ParserQueryStrategy", searcher.index(), text_query)?;
 
         searcher.search(&text_query, &row_id_collector).map_err(|e|{
             ERROR!(function:"ParserQueryStrategy", "Error when execute: {}. {}", self.sentence, e);
@@ -406,6 +850,8 @@ impl<'a> QueryStrategy<Vec<RowIdWithScore>> for BM25QueryStrategy<'a> {
                 IndexSearcherError::QueryParserError(e.to_string())
             },
         )?;
+        let text_query: Box<dyn Query> =
+            expand_synonym_phrases("BM25QueryStrategy", searcher.index(), text_query)?;
 
         searcher.search(&text_query, &top_docs_collector).map_err(|e: TantivyError|{
             ERROR!(function:"BM25QueryStrategy", "Error when execute: {}. {}", self.sentence, e);
@@ -496,7 +942,11 @@ impl<'a> QueryStrategy<Vec<RowIdWithScore>> for BM25QueryStrategy64<'a> {
             }
 
             if *self.query_with_id_range {
-                top_docs_collector = top_docs_collector.with_range((*self.start_id, *self.end_id));
+                top_docs_collector = top_docs_collector
+                    .with_range((*self.start_id, *self.end_id))
+                    .with_sorted_by_row_id(FFiIndexSearcherUtils::is_sorted_by_row_id(
+                        searcher.index(),
+                    ));
             }
 
             let query_parser: QueryParser = QueryParser::for_index(searcher.index(), fields);
@@ -506,6 +956,8 @@ impl<'a> QueryStrategy<Vec<RowIdWithScore>> for BM25QueryStrategy64<'a> {
                         IndexSearcherError::QueryParserError(e.to_string())
                     },
                 )?;
+            let text_query: Box<dyn Query> =
+                expand_synonym_phrases("BM25QueryStrategy64", searcher.index(), text_query)?;
 
             return searcher.search(&text_query, &top_docs_collector).map_err(|e: TantivyError|{
                     ERROR!(function:"BM25QueryStrategy64", "Error when execute: {}. {}", self.sentence, e);
@@ -524,7 +976,11 @@ impl<'a> QueryStrategy<Vec<RowIdWithScore>> for BM25QueryStrategy64<'a> {
         }
 
         if *self.query_with_id_range {
-            top_docs_collector = top_docs_collector.with_range((*self.start_id, *self.end_id));
+            top_docs_collector = top_docs_collector
+                .with_range((*self.start_id, *self.end_id))
+                .with_sorted_by_row_id(FFiIndexSearcherUtils::is_sorted_by_row_id(
+                    searcher.index(),
+                ));
         }
 
         let query_parser: QueryParser = QueryParser::for_index(searcher.index(), fields);
@@ -534,6 +990,8 @@ impl<'a> QueryStrategy<Vec<RowIdWithScore>> for BM25QueryStrategy64<'a> {
                 IndexSearcherError::QueryParserError(e.to_string())
             },
         )?;
+        let text_query: Box<dyn Query> =
+            expand_synonym_phrases("BM25QueryStrategy64", searcher.index(), text_query)?;
 
         searcher.search(&text_query, &top_docs_collector).map_err(|e: TantivyError|{
             ERROR!(function:"BM25QueryStrategy64", "Error when execute: {}. {}", self.sentence, e);
@@ -26,6 +26,7 @@ mod tests {
         let terms_query: TermSetQueryStrategy<'_> = TermSetQueryStrategy {
             terms: &["ancient".to_string(), "balance".to_string()].to_vec(),
             column_name: "col1",
+            row_id_range: None,
         };
         let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> =
             QueryExecutor::new(&terms_query);
@@ -45,6 +46,7 @@ mod tests {
         let term_query: SingleTermQueryStrategy<'_> = SingleTermQueryStrategy {
             term: "judgment",
             column_name: "col2",
+            row_id_range: None,
         };
         let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> = QueryExecutor::new(&term_query);
 
@@ -63,6 +65,7 @@ mod tests {
         let regex_query: RegexQueryStrategy<'_> = RegexQueryStrategy {
             column_name: "col2",
             pattern: "%dgmen%",
+            row_id_range: None,
         };
         let query_executor: QueryExecutor<'_, Arc<RoaringBitmap>> =
             QueryExecutor::new(&regex_query);
@@ -4,7 +4,7 @@ mod tests {
 
     use crate::common::tests::index_3column_docs_with_threads_merge;
     use crate::search::implements::api_common_impl::{
-        free_index_reader, get_indexed_doc_counts, load_index_reader,
+        free_index_reader, get_index_statistics, get_indexed_doc_counts, load_index_reader,
     };
     use crate::FFI_INDEX_SEARCHER_CACHE;
 
@@ -59,4 +59,43 @@ mod tests {
         assert!(res.is_ok());
         assert_eq!(res.clone().unwrap(), 5);
     }
+
+    #[test]
+    pub fn test_get_index_statistics() {
+        let temp_directory = TempDir::new().unwrap();
+        let temp_directory_str = temp_directory.path().to_str().unwrap();
+
+        index_3column_docs_with_threads_merge(temp_directory_str);
+        assert!(get_index_statistics(temp_directory_str).is_err());
+        assert!(load_index_reader(temp_directory_str).is_ok());
+
+        let (segments, fields, total_bytes) = get_index_statistics(temp_directory_str).unwrap();
+        assert!(!segments.is_empty());
+        assert_eq!(segments.iter().map(|s| s.max_doc as u64).sum::<u64>(), 5);
+        assert_eq!(
+            segments.iter().map(|s| s.total_bytes).sum::<u64>(),
+            total_bytes
+        );
+        for segment in segments.iter() {
+            assert_eq!(segment.segment_id.len(), 32);
+            assert_eq!(segment.num_deleted_docs, 0);
+            assert!(segment.postings_bytes > 0);
+            assert!(segment.term_dict_bytes > 0);
+            assert!(
+                segment.postings_bytes
+                    + segment.positions_bytes
+                    + segment.term_dict_bytes
+                    + segment.fast_fields_bytes
+                    + segment.fieldnorms_bytes
+                    + segment.store_bytes
+                    + segment.deletes_bytes
+                    <= segment.total_bytes
+            );
+        }
+
+        // Each column of schema is listed, row_id is a fast field.
+        let field_names: Vec<&str> = fields.iter().map(|f| f.field_name.as_str()).collect();
+        assert_eq!(field_names, vec!["row_id", "col1", "col2", "col3"]);
+        assert!(fields.iter().all(|f| f.num_bytes > 0));
+    }
 }
@@ -1,6 +1,10 @@
 use crate::common::errors::IndexSearcherError;
+use crate::SEGMENT_ROW_ID_RANGE_CACHE;
 use roaring::RoaringBitmap;
 use std::sync::Arc;
+use tantivy::columnar::{Cardinality, Column};
+use tantivy::query::Weight;
+use tantivy::{DocId, DocSet, Index, Order, Score, SegmentReader, TERMINATED};
 
 pub struct FFiIndexSearcherUtils;
 
@@ -28,4 +32,91 @@ impl FFiIndexSearcherUtils {
         row_id_range &= Arc::as_ref(&rowid_bitmap);
         Ok(Arc::new(row_id_range))
     }
+
+    /// Whether the documents of the index are sorted by `row_id` in ascending order.
+    pub fn is_sorted_by_row_id(index: &Index) -> bool {
+        match &index.settings().sort_by_field {
+            Some(sort_by_field) => {
+                sort_by_field.field == "row_id" && sort_by_field.order == Order::Asc
+            }
+            None => false,
+        }
+    }
+
+    /// For a segment sorted by `row_id`, find the doc range `[start_doc, end_doc)`
+    /// whose row_ids are located in `[start, end)` through binary search.
+    pub fn sorted_row_id_doc_range(
+        max_doc: DocId,
+        row_id_of: impl Fn(DocId) -> u64,
+        start: u64,
+        end: u64,
+    ) -> (DocId, DocId) {
+        let lower_bound = |row_id: u64| -> DocId {
+            let (mut low, mut high) = (0, max_doc);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if row_id_of(mid) < row_id {
+                    low = mid + 1;
+                } else {
+                    high = mid;
+                }
+            }
+            low
+        };
+        let start_doc = lower_bound(start);
+        let end_doc = lower_bound(end).max(start_doc);
+        (start_doc, end_doc)
+    }
+
+    /// Score the docs matching `weight` in `[start_doc, end_doc)` only,
+    /// the scorer stops as soon as it goes beyond `end_doc`.
+    pub fn for_each_in_doc_range(
+        weight: &dyn Weight,
+        reader: &SegmentReader,
+        start_doc: DocId,
+        end_doc: DocId,
+        callback: &mut dyn FnMut(DocId, Score),
+    ) -> tantivy::Result<()> {
+        if start_doc >= end_doc {
+            return Ok(());
+        }
+        let mut scorer = weight.scorer(reader, 1.0)?;
+        let mut doc = scorer.doc();
+        if doc < start_doc {
+            doc = scorer.seek(start_doc);
+        }
+        while doc != TERMINATED && doc < end_doc {
+            callback(doc, scorer.score());
+            doc = scorer.advance();
+        }
+        Ok(())
+    }
+
+    /// Min and max row_id of a segment, read from the `row_id` fast field column.
+    /// Segments are immutable, so the result is cached by segment id. Deleted docs are
+    /// included, `None` means the range is unknown and the segment can't be skipped.
+    pub fn segment_row_id_range(segment_reader: &SegmentReader) -> Option<(u64, u64)> {
+        SEGMENT_ROW_ID_RANGE_CACHE.resolve(segment_reader.segment_id(), || {
+            let row_id_column: Column<u64> = segment_reader.fast_fields().u64("row_id").ok()?;
+            // Collectors read a missing row_id as default value.
+            if segment_reader.max_doc() == 0
+                || !matches!(row_id_column.get_cardinality(), Cardinality::Full)
+            {
+                return None;
+            }
+            Some((row_id_column.min_value(), row_id_column.max_value()))
+        })
+    }
+
+    /// Whether a segment may contain row_ids in `[start_id, end_id)`.
+    pub fn segment_overlaps_row_id_range(
+        segment_reader: &SegmentReader,
+        start_id: u64,
+        end_id: u64,
+    ) -> bool {
+        match Self::segment_row_id_range(segment_reader) {
+            Some((min_row_id, max_row_id)) => start_id <= max_row_id && min_row_id < end_id,
+            None => true,
+        }
+    }
 }
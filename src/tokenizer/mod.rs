@@ -1,3 +1,4 @@
 pub mod dto;
+pub mod synonym_tokenizer;
 pub mod tokenizer_utils;
 pub mod vo;
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tantivy::tokenizer::{BoxTokenStream, TextAnalyzer, Token, TokenStream, Tokenizer};
+
+/// SynonymTokenizer wraps a text analyzer, each token produced by the analyzer
+/// is followed by all its equivalents at the same position.
+#[derive(Clone)]
+pub struct SynonymTokenizer {
+    analyzer: TextAnalyzer,
+    synonyms: Arc<HashMap<String, Vec<String>>>,
+}
+
+impl SynonymTokenizer {
+    /// Words of synonym groups are normalized by `analyzer` (e.g. lower cased or stemmed),
+    /// a word analyzed into several tokens is kept as it is.
+    pub fn new(mut analyzer: TextAnalyzer, groups: &[Vec<String>]) -> Self {
+        let mut synonyms: HashMap<String, Vec<String>> = HashMap::new();
+        for group in groups {
+            let mut words: Vec<String> = Vec::new();
+            for word in group {
+                let mut tokens: Vec<String> = Vec::new();
+                analyzer
+                    .token_stream(word)
+                    .process(&mut |token| tokens.push(token.text.clone()));
+                let word = match tokens.len() {
+                    1 => tokens.remove(0),
+                    _ => word.clone(),
+                };
+                if !words.contains(&word) {
+                    words.push(word);
+                }
+            }
+            for word in &words {
+                let equivalents = synonyms.entry(word.clone()).or_default();
+                for equivalent in &words {
+                    if equivalent != word && !equivalents.contains(equivalent) {
+                        equivalents.push(equivalent.clone());
+                    }
+                }
+            }
+        }
+        SynonymTokenizer {
+            analyzer,
+            synonyms: Arc::new(synonyms),
+        }
+    }
+}
+
+impl Tokenizer for SynonymTokenizer {
+    type TokenStream<'a> = SynonymTokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> SynonymTokenStream<'a> {
+        SynonymTokenStream {
+            tail: self.analyzer.token_stream(text),
+            synonyms: self.synonyms.as_ref(),
+            pending: Vec::new(),
+            token: Token::default(),
+        }
+    }
+}
+
+pub struct SynonymTokenStream<'a> {
+    tail: BoxTokenStream<'a>,
+    synonyms: &'a HashMap<String, Vec<String>>,
+    // Equivalents of current token waiting to be emitted, in reversed order.
+    pending: Vec<String>,
+    token: Token,
+}
+
+impl<'a> TokenStream for SynonymTokenStream<'a> {
+    fn advance(&mut self) -> bool {
+        // Equivalents keep offsets and position of the original token.
+        if let Some(equivalent) = self.pending.pop() {
+            self.token.text = equivalent;
+            return true;
+        }
+        if !self.tail.advance() {
+            return false;
+        }
+        self.token = self.tail.token().clone();
+        if let Some(equivalents) = self.synonyms.get(&self.token.text) {
+            self.pending = equivalents.iter().rev().cloned().collect();
+        }
+        true
+    }
+
+    fn token(&self) -> &Token {
+        &self.token
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.token
+    }
+}
@@ -2,23 +2,29 @@ use std::{str::FromStr, sync::Arc};
 
 use cang_jie::{CangJieTokenizer, TokenizerOption};
 use jieba_rs::Jieba;
+use tantivy::schema::{Field, FieldType};
 use tantivy::{
     tokenizer::{
         LowerCaser, NgramTokenizer, RawTokenizer, RemoveLongFilter, SimpleTokenizer, Stemmer,
-        StopWordFilter, TextAnalyzer, WhitespaceTokenizer,
+        StopWordFilter, TextAnalyzer, TextAnalyzerBuilder, WhitespaceTokenizer,
     },
     Index,
 };
 
 use crate::common::errors::TokenizerUtilsError;
 
+use super::synonym_tokenizer::SynonymTokenizer;
 use super::vo::{
+    index_settings_json_vo::IndexSettingsConfig,
     language::{SupportFilterLanguage, SupportLanguageAlgorithm},
-    tokenizer_json_vo::ColumnTokenizer,
+    tokenizer_json_vo::{ColumnTokenizer, SynonymExpand, SynonymsConfig},
     tokenizer_types::TokenizerType,
     tokenizers_vo::TokenizerConfig,
 };
 
+// No tokenizer type ends with this suffix, so the marker never collides with a column tokenizer.
+const QUERY_SYNONYMS_MARKER_SUFFIX: &str = "#query_synonyms";
+
 pub struct TokenizerUtils;
 
 impl TokenizerUtils {
@@ -94,10 +100,23 @@ impl TokenizerUtils {
                     column_name, tokenizer_name
                 ))
             }
+            TokenizerType::Json(tokenizer_name) => {
+                index
+                    .tokenizers()
+                    .register(&format!("{}_{}", column_name, tokenizer_name), tokenizer);
+                Ok(format!(
+                    "`{}-{}` tokenizer has been registered",
+                    column_name, tokenizer_name
+                ))
+            }
             TokenizerType::I64(tokenizer_name) => Ok(format!(
                 "`{}-{}` tokenizer is not text-based, no need to register",
                 column_name, tokenizer_name
             )),
+            TokenizerType::U64(tokenizer_name) => Ok(format!(
+                "`{}-{}` tokenizer is not text-based, no need to register",
+                column_name, tokenizer_name
+            )),
             TokenizerType::F64(tokenizer_name) => Ok(format!(
                 "`{}-{}` tokenizer is not text-based, no need to register",
                 column_name, tokenizer_name
@@ -114,12 +133,63 @@ impl TokenizerUtils {
                 "`{}-{}` tokenizer is not text-based, no need to register",
                 column_name, tokenizer_name
             )),
+            TokenizerType::Ip(tokenizer_name) => Ok(format!(
+                "`{}-{}` tokenizer is not text-based, no need to register",
+                column_name, tokenizer_name
+            )),
             _ => Err(TokenizerUtilsError::UnsupportedTokenizerType(
                 tokenizer_type.name().to_string(),
             )),
         }
     }
 
+    // Mark a column whose queries are expanded with synonyms, the marker is a tokenizer
+    // registered next to the column tokenizer. Phrase queries are only rewritten for them.
+    pub fn register_query_synonyms_marker(
+        index: &mut Index,
+        column_name: &str,
+        tokenizer_config: &TokenizerConfig,
+    ) {
+        if matches!(
+            tokenizer_config.synonym_expand,
+            Some(SynonymExpand::Query) | Some(SynonymExpand::Both)
+        ) {
+            index.tokenizers().register(
+                &format!(
+                    "{}_{}{}",
+                    column_name,
+                    tokenizer_config.tokenizer_type.name(),
+                    QUERY_SYNONYMS_MARKER_SUFFIX
+                ),
+                tokenizer_config.search_text_analyzer.clone(),
+            );
+        }
+    }
+
+    // Whether queries of `field` are expanded with synonyms, see `register_query_synonyms_marker`.
+    pub fn has_query_synonyms(index: &Index, field: Field) -> bool {
+        let tokenizer_name: Option<String> =
+            match index.schema().get_field_entry(field).field_type() {
+                FieldType::Str(text_options) => text_options
+                    .get_indexing_options()
+                    .map(|indexing_options| indexing_options.tokenizer().to_string()),
+                FieldType::JsonObject(json_options) => json_options
+                    .get_text_indexing_options()
+                    .map(|indexing_options| indexing_options.tokenizer().to_string()),
+                _ => None,
+            };
+        match tokenizer_name {
+            Some(tokenizer_name) => index
+                .tokenizers()
+                .get(&format!(
+                    "{}{}",
+                    tokenizer_name, QUERY_SYNONYMS_MARKER_SUFFIX
+                ))
+                .is_some(),
+            None => false,
+        }
+    }
+
     pub fn parse_tokenizer_json_to_config_map(
         json_str: &str,
     ) -> Result<std::collections::HashMap<String, TokenizerConfig>, TokenizerUtilsError> {
@@ -131,288 +201,546 @@ impl TokenizerUtils {
             std::collections::HashMap::new();
 
         for (col_name, col) in config.get_columns() {
-            match col.get_tokenizer() {
-                ColumnTokenizer::Default { store_doc } => {
-                    let analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
-                        .filter(RemoveLongFilter::limit(40))
-                        .filter(LowerCaser)
-                        .build();
-                    let tokenizer_config = TokenizerConfig::new(
-                        TokenizerType::Default("default".to_string()),
-                        analyzer,
-                        *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::Raw { store_doc } => {
-                    let analyzer = TextAnalyzer::builder(RawTokenizer::default()).build();
-                    let tokenizer_config = TokenizerConfig::new(
-                        TokenizerType::Raw("raw".to_string()),
-                        analyzer,
-                        *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::Simple {
-                    store_doc,
-                    stop_word_filters,
-                    length_limit,
-                    case_sensitive,
-                } => {
-                    let mut builder = TextAnalyzer::builder(SimpleTokenizer::default()).dynamic();
-
-                    builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
-
-                    for stop_word_filter in stop_word_filters {
-                        let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
-                            .unwrap()
-                            .to_language();
-                        if language.is_some() {
-                            builder = builder
-                                .filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
-                        }
-                    }
+            let tokenizer_config = Self::parse_column_tokenizer(col.get_tokenizer())?;
+            tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+        }
+        Ok(tokenizer_map)
+    }
+
+    // Parse tokenizer config of a single column.
+    fn parse_column_tokenizer(
+        column_tokenizer: &ColumnTokenizer,
+    ) -> Result<TokenizerConfig, TokenizerUtilsError> {
+        let tokenizer_config = match column_tokenizer {
+            ColumnTokenizer::Default { store_doc } => {
+                let analyzer = TextAnalyzer::builder(SimpleTokenizer::default())
+                    .filter(RemoveLongFilter::limit(40))
+                    .filter(LowerCaser)
+                    .build();
+                TokenizerConfig::new(
+                    TokenizerType::Default("default".to_string()),
+                    analyzer,
+                    *store_doc,
+                )
+            }
+            ColumnTokenizer::Raw { store_doc } => {
+                let analyzer = TextAnalyzer::builder(RawTokenizer::default()).build();
+                TokenizerConfig::new(TokenizerType::Raw("raw".to_string()), analyzer, *store_doc)
+            }
+            ColumnTokenizer::Simple {
+                store_doc,
+                stop_word_filters,
+                stop_words,
+                stop_words_file,
+                length_limit,
+                case_sensitive,
+                synonyms,
+            } => {
+                let mut builder = TextAnalyzer::builder(SimpleTokenizer::default()).dynamic();
 
-                    if *case_sensitive == false {
-                        builder = builder.filter_dynamic(LowerCaser);
+                builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
+
+                for stop_word_filter in stop_word_filters {
+                    let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
+                        .unwrap()
+                        .to_language();
+                    if language.is_some() {
+                        builder =
+                            builder.filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
                     }
+                }
 
-                    let tokenizer_config = TokenizerConfig::new(
+                builder = Self::filter_custom_stop_words(
+                    builder,
+                    stop_words,
+                    stop_words_file,
+                    *case_sensitive,
+                )?;
+
+                if *case_sensitive == false {
+                    builder = builder.filter_dynamic(LowerCaser);
+                }
+
+                Self::apply_synonyms(
+                    TokenizerConfig::new(
                         TokenizerType::Simple("simple".to_string()),
                         builder.build(),
                         *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::Stem {
-                    stop_word_filters,
-                    stem_languages,
-                    store_doc,
-                    length_limit,
-                    case_sensitive,
-                } => {
-                    let mut builder = TextAnalyzer::builder(SimpleTokenizer::default()).dynamic();
-
-                    builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
-
-                    for stop_word_filter in stop_word_filters {
-                        let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
-                            .unwrap()
-                            .to_language();
-                        if language.is_some() {
-                            builder = builder
-                                .filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
-                        }
-                    }
+                    ),
+                    synonyms,
+                )?
+            }
+            ColumnTokenizer::Stem {
+                stop_word_filters,
+                stop_words,
+                stop_words_file,
+                stem_languages,
+                store_doc,
+                length_limit,
+                case_sensitive,
+                synonyms,
+            } => {
+                let mut builder = TextAnalyzer::builder(SimpleTokenizer::default()).dynamic();
 
-                    for stem_language in stem_languages {
-                        let language = SupportLanguageAlgorithm::from_str(stem_language.as_str())
-                            .unwrap()
-                            .to_language();
-                        if language.is_some() {
-                            builder = builder.filter_dynamic(Stemmer::new(language.unwrap()));
-                        }
+                builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
+
+                for stop_word_filter in stop_word_filters {
+                    let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
+                        .unwrap()
+                        .to_language();
+                    if language.is_some() {
+                        builder =
+                            builder.filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
                     }
+                }
+
+                builder = Self::filter_custom_stop_words(
+                    builder,
+                    stop_words,
+                    stop_words_file,
+                    *case_sensitive,
+                )?;
 
-                    if *case_sensitive == false {
-                        builder = builder.filter_dynamic(LowerCaser);
+                for stem_language in stem_languages {
+                    let language = SupportLanguageAlgorithm::from_str(stem_language.as_str())
+                        .unwrap()
+                        .to_language();
+                    if language.is_some() {
+                        builder = builder.filter_dynamic(Stemmer::new(language.unwrap()));
                     }
+                }
 
-                    let tokenizer_config = TokenizerConfig::new(
+                if *case_sensitive == false {
+                    builder = builder.filter_dynamic(LowerCaser);
+                }
+
+                Self::apply_synonyms(
+                    TokenizerConfig::new(
                         TokenizerType::Stem("stem".to_string()),
                         builder.build(),
                         *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::Whitespace {
-                    store_doc,
-                    stop_word_filters,
-                    length_limit,
-                    case_sensitive,
-                } => {
-                    let mut builder =
-                        TextAnalyzer::builder(WhitespaceTokenizer::default()).dynamic();
-
-                    builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
-
-                    for stop_word_filter in stop_word_filters {
-                        let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
-                            .unwrap()
-                            .to_language();
-                        if language.is_some() {
-                            builder = builder
-                                .filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
-                        }
-                    }
+                    ),
+                    synonyms,
+                )?
+            }
+            ColumnTokenizer::Whitespace {
+                store_doc,
+                stop_word_filters,
+                stop_words,
+                stop_words_file,
+                length_limit,
+                case_sensitive,
+                synonyms,
+            } => {
+                let mut builder = TextAnalyzer::builder(WhitespaceTokenizer::default()).dynamic();
+
+                builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
 
-                    if *case_sensitive == false {
-                        builder = builder.filter_dynamic(LowerCaser);
+                for stop_word_filter in stop_word_filters {
+                    let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
+                        .unwrap()
+                        .to_language();
+                    if language.is_some() {
+                        builder =
+                            builder.filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
                     }
+                }
+
+                builder = Self::filter_custom_stop_words(
+                    builder,
+                    stop_words,
+                    stop_words_file,
+                    *case_sensitive,
+                )?;
+
+                if *case_sensitive == false {
+                    builder = builder.filter_dynamic(LowerCaser);
+                }
 
-                    let tokenizer_config = TokenizerConfig::new(
+                Self::apply_synonyms(
+                    TokenizerConfig::new(
                         TokenizerType::WhiteSpace("whitespace".to_string()),
                         builder.build(),
                         *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+                    ),
+                    synonyms,
+                )?
+            }
+            ColumnTokenizer::Ngram {
+                min_gram,
+                max_gram,
+                prefix_only,
+                store_doc,
+                stop_word_filters,
+                stop_words,
+                stop_words_file,
+                length_limit,
+                case_sensitive,
+            } => {
+                if min_gram >= max_gram || (*min_gram == 0 && *max_gram == 0) {
+                    return Err(TokenizerUtilsError::JsonParseError(
+                        "`min_gram` should be smaller than `max_gram`".to_string(),
+                    ));
                 }
-                ColumnTokenizer::Ngram {
-                    min_gram,
-                    max_gram,
-                    prefix_only,
-                    store_doc,
-                    stop_word_filters,
-                    length_limit,
-                    case_sensitive,
-                } => {
-                    if min_gram >= max_gram || (*min_gram == 0 && *max_gram == 0) {
-                        return Err(TokenizerUtilsError::JsonParseError(
-                            "`min_gram` should be smaller than `max_gram`".to_string(),
-                        ));
-                    }
 
-                    let mut builder = TextAnalyzer::builder(
-                        NgramTokenizer::new(*min_gram, *max_gram, *prefix_only).map_err(|e| {
-                            TokenizerUtilsError::ConfigTokenizerError(e.to_string())
-                        })?,
-                    )
-                    .dynamic();
-
-                    builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
-
-                    for stop_word_filter in stop_word_filters {
-                        let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
-                            .unwrap()
-                            .to_language();
-                        if language.is_some() {
-                            builder = builder
-                                .filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
-                        }
-                    }
+                let mut builder = TextAnalyzer::builder(
+                    NgramTokenizer::new(*min_gram, *max_gram, *prefix_only)
+                        .map_err(|e| TokenizerUtilsError::ConfigTokenizerError(e.to_string()))?,
+                )
+                .dynamic();
 
-                    if *case_sensitive == false {
-                        builder = builder.filter_dynamic(LowerCaser);
+                builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
+
+                for stop_word_filter in stop_word_filters {
+                    let language = SupportFilterLanguage::from_str(stop_word_filter.as_str())
+                        .unwrap()
+                        .to_language();
+                    if language.is_some() {
+                        builder =
+                            builder.filter_dynamic(StopWordFilter::new(language.unwrap()).unwrap());
                     }
+                }
 
-                    let tokenizer_config = TokenizerConfig::new(
-                        TokenizerType::Ngram("ngram".to_string()),
-                        builder.build(),
-                        *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+                builder = Self::filter_custom_stop_words(
+                    builder,
+                    stop_words,
+                    stop_words_file,
+                    *case_sensitive,
+                )?;
+
+                if *case_sensitive == false {
+                    builder = builder.filter_dynamic(LowerCaser);
                 }
-                ColumnTokenizer::Chinese {
-                    jieba,
-                    mode,
-                    hmm,
-                    store_doc,
-                    // length_limit,
-                } => {
-                    let jieba_mode: Jieba = match jieba.as_str() {
-                        "default" => Jieba::default(),
-                        "empty" => Jieba::empty(),
-                        _ => Jieba::empty(),
-                    };
-
-                    let tokenizer_option: TokenizerOption = match mode.as_str() {
-                        "all" => TokenizerOption::All,
-                        "unicode" => TokenizerOption::Unicode,
-                        "default" => TokenizerOption::Default { hmm: *hmm },
-                        "search" => TokenizerOption::ForSearch { hmm: *hmm },
-                        _ => TokenizerOption::Unicode, // default option
-                    };
-
-                    let builder = TextAnalyzer::builder(CangJieTokenizer {
-                        worker: Arc::new(jieba_mode),
-                        option: tokenizer_option,
-                    })
-                    .dynamic();
-                    // builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
-
-                    let tokenizer_config = TokenizerConfig::new(
+
+                TokenizerConfig::new(
+                    TokenizerType::Ngram("ngram".to_string()),
+                    builder.build(),
+                    *store_doc,
+                )
+            }
+            ColumnTokenizer::Chinese {
+                jieba,
+                mode,
+                hmm,
+                store_doc,
+                synonyms,
+                // length_limit,
+            } => {
+                let jieba_mode: Jieba = match jieba.as_str() {
+                    "default" => Jieba::default(),
+                    "empty" => Jieba::empty(),
+                    _ => Jieba::empty(),
+                };
+
+                let tokenizer_option: TokenizerOption = match mode.as_str() {
+                    "all" => TokenizerOption::All,
+                    "unicode" => TokenizerOption::Unicode,
+                    "default" => TokenizerOption::Default { hmm: *hmm },
+                    "search" => TokenizerOption::ForSearch { hmm: *hmm },
+                    _ => TokenizerOption::Unicode, // default option
+                };
+
+                let builder = TextAnalyzer::builder(CangJieTokenizer {
+                    worker: Arc::new(jieba_mode),
+                    option: tokenizer_option,
+                })
+                .dynamic();
+                // builder = builder.filter_dynamic(RemoveLongFilter::limit(*length_limit));
+
+                Self::apply_synonyms(
+                    TokenizerConfig::new(
                         TokenizerType::Chinese("chinese".to_string()),
                         builder.build(),
                         *store_doc,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::I64 {
-                    store_doc,
-                    indexed,
-                    fast,
-                    coerce,
-                } => {
-                    let tokenizer_config = TokenizerConfig::new_non_text(
-                        TokenizerType::I64("i64".to_string()),
-                        *store_doc,
-                        *indexed,
-                        *fast,
-                        *coerce,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::F64 {
-                    store_doc,
-                    indexed,
-                    fast,
-                    coerce,
-                } => {
-                    let tokenizer_config = TokenizerConfig::new_non_text(
-                        TokenizerType::F64("f64".to_string()),
-                        *store_doc,
-                        *indexed,
-                        *fast,
-                        *coerce,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
-                }
-                ColumnTokenizer::Bytes {
-                    store_doc,
-                    indexed,
-                    fast,
-                } => {
-                    let tokenizer_config = TokenizerConfig::new_non_text(
-                        TokenizerType::Bytes("bytes".to_string()),
-                        *store_doc,
-                        *indexed,
-                        *fast,
-                        false,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+                    ),
+                    synonyms,
+                )?
+            }
+            ColumnTokenizer::I64 {
+                store_doc,
+                indexed,
+                fast,
+                coerce,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::I64("i64".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                *coerce,
+            ),
+            ColumnTokenizer::U64 {
+                store_doc,
+                indexed,
+                fast,
+                coerce,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::U64("u64".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                *coerce,
+            ),
+            ColumnTokenizer::F64 {
+                store_doc,
+                indexed,
+                fast,
+                coerce,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::F64("f64".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                *coerce,
+            ),
+            ColumnTokenizer::Bytes {
+                store_doc,
+                indexed,
+                fast,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::Bytes("bytes".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                false,
+            ),
+            ColumnTokenizer::DateTime {
+                store_doc,
+                indexed,
+                fast,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::DateTime("datetime".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                false,
+            ),
+            ColumnTokenizer::Bool {
+                store_doc,
+                indexed,
+                fast,
+                coerce,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::Bool("bool".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                *coerce,
+            ),
+            ColumnTokenizer::Ip {
+                store_doc,
+                indexed,
+                fast,
+            } => TokenizerConfig::new_non_text(
+                TokenizerType::Ip("ip".to_string()),
+                *store_doc,
+                *indexed,
+                *fast,
+                false,
+            ),
+            ColumnTokenizer::Json {
+                tokenizer,
+                store_doc,
+                expand_dots,
+            } => {
+                // String leaves of json column are tokenized by a text tokenizer.
+                let leaf_tokenizer_config = Self::parse_column_tokenizer(tokenizer)?;
+                if !leaf_tokenizer_config.is_text_field {
+                    return Err(TokenizerUtilsError::ConfigTokenizerError(format!(
+                        "json column requires a text tokenizer, but got `{}`",
+                        leaf_tokenizer_config.tokenizer_type.name()
+                    )));
                 }
-                ColumnTokenizer::DateTime {
-                    store_doc,
-                    indexed,
-                    fast,
-                } => {
-                    let tokenizer_config = TokenizerConfig::new_non_text(
-                        TokenizerType::DateTime("datetime".to_string()),
-                        *store_doc,
-                        *indexed,
-                        *fast,
-                        false,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+                let mut json_tokenizer_config = TokenizerConfig::new_json(
+                    leaf_tokenizer_config.text_analyzer,
+                    leaf_tokenizer_config.search_text_analyzer,
+                    *store_doc,
+                    *expand_dots,
+                );
+                json_tokenizer_config.synonym_expand = leaf_tokenizer_config.synonym_expand;
+                json_tokenizer_config
+            }
+        };
+        Ok(tokenizer_config)
+    }
+
+    // Wrap analyzers of indexing and searching with synonyms, as configured by `expand`.
+    fn apply_synonyms(
+        mut tokenizer_config: TokenizerConfig,
+        synonyms: &Option<SynonymsConfig>,
+    ) -> Result<TokenizerConfig, TokenizerUtilsError> {
+        let synonyms = match synonyms {
+            Some(synonyms) => synonyms,
+            None => return Ok(tokenizer_config),
+        };
+        let mut groups: Vec<Vec<String>> = synonyms.groups.clone();
+        if let Some(synonyms_file) = &synonyms.file {
+            groups.extend(Self::read_synonyms_file(synonyms_file)?);
+        }
+        let synonym_analyzer: TextAnalyzer = TextAnalyzer::builder(SynonymTokenizer::new(
+            tokenizer_config.text_analyzer.clone(),
+            &groups,
+        ))
+        .build();
+        if synonyms.expand != SynonymExpand::Query {
+            tokenizer_config.text_analyzer = synonym_analyzer.clone();
+        }
+        if synonyms.expand != SynonymExpand::Index {
+            tokenizer_config.search_text_analyzer = synonym_analyzer;
+        }
+        tokenizer_config.synonym_expand = Some(synonyms.expand);
+        Ok(tokenizer_config)
+    }
+
+    fn read_synonyms_file(synonyms_file: &str) -> Result<Vec<Vec<String>>, TokenizerUtilsError> {
+        let content = std::fs::read_to_string(synonyms_file).map_err(|e| {
+            TokenizerUtilsError::ConfigTokenizerError(format!(
+                "Failed to read synonyms file `{}`: {}",
+                synonyms_file, e
+            ))
+        })?;
+        Ok(content
+            .lines()
+            .map(|line| {
+                line.split(',')
+                    .map(|word| word.trim())
+                    .filter(|word| !word.is_empty())
+                    .map(|word| word.to_string())
+                    .collect::<Vec<String>>()
+            })
+            .filter(|group| !group.is_empty())
+            .collect())
+    }
+
+    // Collect custom stop words of inline list and stop-word file, one word per line in the file.
+    // Remove custom stop words, tokens are lowercased before matching when `case_sensitive`
+    // is false, so stop words are matched in any case.
+    fn filter_custom_stop_words(
+        mut builder: TextAnalyzerBuilder,
+        stop_words: &[String],
+        stop_words_file: &Option<String>,
+        case_sensitive: bool,
+    ) -> Result<TextAnalyzerBuilder, TokenizerUtilsError> {
+        let mut custom_stop_words = Self::load_custom_stop_words(stop_words, stop_words_file)?;
+        if custom_stop_words.is_empty() {
+            return Ok(builder);
+        }
+        if !case_sensitive {
+            builder = builder.filter_dynamic(LowerCaser);
+            custom_stop_words = custom_stop_words
+                .iter()
+                .map(|stop_word| stop_word.to_lowercase())
+                .collect();
+        }
+        Ok(builder.filter_dynamic(StopWordFilter::remove(custom_stop_words)))
+    }
+
+    fn load_custom_stop_words(
+        stop_words: &[String],
+        stop_words_file: &Option<String>,
+    ) -> Result<Vec<String>, TokenizerUtilsError> {
+        let mut custom_stop_words: Vec<String> = stop_words.to_vec();
+        if let Some(stop_words_file) = stop_words_file {
+            custom_stop_words.extend(Self::read_stop_words_file(stop_words_file)?);
+        }
+        Ok(custom_stop_words)
+    }
+
+    fn read_stop_words_file(stop_words_file: &str) -> Result<Vec<String>, TokenizerUtilsError> {
+        let content = std::fs::read_to_string(stop_words_file).map_err(|e| {
+            TokenizerUtilsError::ConfigTokenizerError(format!(
+                "Failed to read stop words file `{}`: {}",
+                stop_words_file, e
+            ))
+        })?;
+        Ok(content
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .map(|line| line.to_string())
+            .collect())
+    }
+
+    /// Replace stop-word and synonyms files of each column tokenizer with their contents,
+    /// so the json parameter persisted with index doesn't rely on these files anymore.
+    pub fn inline_tokenizer_files(json_str: &str) -> Result<String, TokenizerUtilsError> {
+        let mut json_value: serde_json::Value = serde_json::from_str(json_str)
+            .map_err(|e| TokenizerUtilsError::JsonParseError(e.to_string()))?;
+        let mut inlined = false;
+        if let Some(json_object) = json_value.as_object_mut() {
+            for (column_name, column) in json_object.iter_mut() {
+                if column_name == "index_settings" {
+                    continue;
                 }
-                ColumnTokenizer::Bool {
-                    store_doc,
-                    indexed,
-                    fast,
-                    coerce,
-                } => {
-                    let tokenizer_config = TokenizerConfig::new_non_text(
-                        TokenizerType::Bool("bool".to_string()),
-                        *store_doc,
-                        *indexed,
-                        *fast,
-                        *coerce,
-                    );
-                    tokenizer_map.insert(col_name.to_string(), tokenizer_config);
+                // Json column keeps its text tokenizer in a nested `tokenizer`.
+                let mut tokenizer = column.get_mut("tokenizer");
+                while let Some(tokenizer_object) = tokenizer.and_then(|t| t.as_object_mut()) {
+                    if let Some(stop_words_file) = tokenizer_object.remove("stop_words_file") {
+                        let stop_words_file = stop_words_file.as_str().ok_or_else(|| {
+                            TokenizerUtilsError::JsonParseError(format!(
+                                "`stop_words_file` of column `{}` should be a string",
+                                column_name
+                            ))
+                        })?;
+                        let mut stop_words: Vec<serde_json::Value> =
+                            match tokenizer_object.remove("stop_words") {
+                                Some(serde_json::Value::Array(stop_words)) => stop_words,
+                                _ => Vec::new(),
+                            };
+                        stop_words.extend(
+                            Self::read_stop_words_file(stop_words_file)?
+                                .into_iter()
+                                .map(serde_json::Value::String),
+                        );
+                        tokenizer_object.insert(
+                            "stop_words".to_string(),
+                            serde_json::Value::Array(stop_words),
+                        );
+                        inlined = true;
+                    }
+                    if let Some(synonyms_object) = tokenizer_object
+                        .get_mut("synonyms")
+                        .and_then(|synonyms| synonyms.as_object_mut())
+                    {
+                        if let Some(synonyms_file) = synonyms_object.remove("file") {
+                            let synonyms_file = synonyms_file.as_str().ok_or_else(|| {
+                                TokenizerUtilsError::JsonParseError(format!(
+                                    "synonyms `file` of column `{}` should be a string",
+                                    column_name
+                                ))
+                            })?;
+                            let mut groups: Vec<serde_json::Value> =
+                                match synonyms_object.remove("groups") {
+                                    Some(serde_json::Value::Array(groups)) => groups,
+                                    _ => Vec::new(),
+                                };
+                            groups.extend(
+                                Self::read_synonyms_file(synonyms_file)?
+                                    .into_iter()
+                                    .map(|group| serde_json::json!(group)),
+                            );
+                            synonyms_object
+                                .insert("groups".to_string(), serde_json::Value::Array(groups));
+                            inlined = true;
+                        }
+                    }
+                    tokenizer = tokenizer_object.get_mut("tokenizer");
                 }
             }
         }
-        Ok(tokenizer_map)
+        // Keep the json parameter untouched when no file is referenced.
+        if !inlined {
+            return Ok(json_str.to_string());
+        }
+        Ok(json_value.to_string())
+    }
+
+    pub fn parse_index_settings_json(
+        json_str: &str,
+    ) -> Result<IndexSettingsConfig, TokenizerUtilsError> {
+        let config: crate::tokenizer::vo::tokenizer_json_vo::Config =
+            serde_json::from_str(json_str)
+                .map_err(|e| TokenizerUtilsError::JsonDeserializeError(e.to_string()))?;
+        Ok(config.get_index_settings())
     }
 
     pub fn varify_json_parameter(json_str: &str) -> Result<bool, TokenizerUtilsError> {
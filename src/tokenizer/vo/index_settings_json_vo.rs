@@ -0,0 +1,172 @@
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Same as `MAX_NUM_THREAD` in tantivy index writer, more threads will be refused.
+const MAX_WRITER_NUM_THREADS: usize = 8;
+
+/// IndexSettingsConfig stores index level configurations, it is located
+/// in the `index_settings` section of the JSON parameter, next to the column configurations.
+/// All of them will be applied again when the index writer is reloaded.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct IndexSettingsConfig {
+    #[serde(
+        default = "default_writer_num_threads",
+        deserialize_with = "writer_num_threads_validator"
+    )]
+    pub writer_num_threads: usize,
+    #[serde(default = "default_writer_memory_budget")]
+    pub writer_memory_budget: usize,
+    #[serde(default)]
+    pub merge_policy: MergePolicyConfig,
+    #[serde(default)]
+    pub docstore_compressor: DocstoreCompressorConfig,
+    #[serde(
+        default = "default_docstore_block_size",
+        deserialize_with = "docstore_block_size_validator"
+    )]
+    pub docstore_block_size: usize,
+    #[serde(default)]
+    pub sort_by_row_id: bool,
+}
+
+impl Default for IndexSettingsConfig {
+    fn default() -> Self {
+        Self {
+            writer_num_threads: default_writer_num_threads(),
+            writer_memory_budget: default_writer_memory_budget(),
+            merge_policy: MergePolicyConfig::default(),
+            docstore_compressor: DocstoreCompressorConfig::default(),
+            docstore_block_size: default_docstore_block_size(),
+            sort_by_row_id: false,
+        }
+    }
+}
+
+/// MergePolicyConfig decides how the index writer merges segments in background.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[serde(tag = "type")]
+pub enum MergePolicyConfig {
+    #[serde(rename = "log")]
+    Log {
+        #[serde(default = "default_min_num_segments")]
+        min_num_segments: usize,
+        #[serde(default = "default_max_docs_before_merge")]
+        max_docs_before_merge: usize,
+        #[serde(default = "default_min_layer_size")]
+        min_layer_size: u32,
+        #[serde(default = "default_level_log_size")]
+        level_log_size: f64,
+        #[serde(
+            default = "default_del_docs_ratio_before_merge",
+            deserialize_with = "del_docs_ratio_validator"
+        )]
+        del_docs_ratio_before_merge: f32,
+    },
+    #[serde(rename = "no_merge")]
+    NoMerge,
+}
+
+impl Default for MergePolicyConfig {
+    fn default() -> Self {
+        MergePolicyConfig::Log {
+            min_num_segments: default_min_num_segments(),
+            max_docs_before_merge: default_max_docs_before_merge(),
+            min_layer_size: default_min_layer_size(),
+            level_log_size: default_level_log_size(),
+            del_docs_ratio_before_merge: default_del_docs_ratio_before_merge(),
+        }
+    }
+}
+
+/// DocstoreCompressorConfig decides how the stored documents (`store_doc`) are compressed.
+/// It's recorded in meta.json together with `docstore_block_size` when the index is created,
+/// so both are kept after the index writer is reloaded.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(deny_unknown_fields)]
+#[serde(tag = "type")]
+pub enum DocstoreCompressorConfig {
+    #[serde(rename = "none")]
+    None,
+    #[default]
+    #[serde(rename = "lz4")]
+    Lz4,
+    #[serde(rename = "zstd")]
+    Zstd {
+        #[serde(default)]
+        level: Option<i32>,
+    },
+}
+
+fn default_writer_num_threads() -> usize {
+    2
+}
+
+fn default_writer_memory_budget() -> usize {
+    1024 * 1024 * 64
+}
+
+fn default_min_num_segments() -> usize {
+    5
+}
+
+fn default_max_docs_before_merge() -> usize {
+    10_000_000
+}
+
+fn default_min_layer_size() -> u32 {
+    10_000
+}
+
+fn default_level_log_size() -> f64 {
+    0.75
+}
+
+fn default_del_docs_ratio_before_merge() -> f32 {
+    1.0
+}
+
+fn default_docstore_block_size() -> usize {
+    16_384
+}
+
+fn del_docs_ratio_validator<'de, D>(deserializer: D) -> Result<f32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let ratio = f32::deserialize(deserializer)?;
+    if ratio <= 0.0 || ratio > 1.0 {
+        return Err(serde::de::Error::custom(format!(
+            "Invalid value for del_docs_ratio_before_merge: {}. Expected in range (0, 1].",
+            ratio
+        )));
+    }
+    Ok(ratio)
+}
+
+fn writer_num_threads_validator<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let num_threads = usize::deserialize(deserializer)?;
+    if num_threads == 0 || num_threads > MAX_WRITER_NUM_THREADS {
+        return Err(serde::de::Error::custom(format!(
+            "Invalid value for writer_num_threads: {}. Expected in range [1, {}].",
+            num_threads, MAX_WRITER_NUM_THREADS
+        )));
+    }
+    Ok(num_threads)
+}
+
+fn docstore_block_size_validator<'de, D>(deserializer: D) -> Result<usize, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let block_size = usize::deserialize(deserializer)?;
+    if block_size == 0 {
+        return Err(serde::de::Error::custom(
+            "Invalid value for docstore_block_size: 0. Expected a positive number.",
+        ));
+    }
+    Ok(block_size)
+}
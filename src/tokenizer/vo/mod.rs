@@ -1,3 +1,4 @@
+pub mod index_settings_json_vo;
 pub mod language;
 pub mod tokenizer_json_vo;
 pub mod tokenizer_types;
@@ -2,6 +2,7 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Deserializer, Serialize};
 
+use super::index_settings_json_vo::IndexSettingsConfig;
 use super::language::{SupportFilterLanguage, SupportLanguageAlgorithm};
 
 /// ColumnTokenizer stores the specific configuration information
@@ -26,16 +27,26 @@ pub enum ColumnTokenizer {
         #[serde(default, deserialize_with = "stop_word_filters_validator")]
         stop_word_filters: Vec<String>,
         #[serde(default)]
+        stop_words: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stop_words_file: Option<String>,
+        #[serde(default)]
         store_doc: bool,
         #[serde(default = "default_length_limit")]
         length_limit: usize,
         #[serde(default)]
         case_sensitive: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        synonyms: Option<SynonymsConfig>,
     },
     #[serde(rename = "stem")]
     Stem {
         #[serde(default, deserialize_with = "stop_word_filters_validator")]
         stop_word_filters: Vec<String>,
+        #[serde(default)]
+        stop_words: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stop_words_file: Option<String>,
         #[serde(default, deserialize_with = "stem_languages_filters_validator")]
         stem_languages: Vec<String>,
         #[serde(default)]
@@ -44,17 +55,25 @@ pub enum ColumnTokenizer {
         length_limit: usize,
         #[serde(default)]
         case_sensitive: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        synonyms: Option<SynonymsConfig>,
     },
     #[serde(rename = "whitespace")]
     Whitespace {
         #[serde(default, deserialize_with = "stop_word_filters_validator")]
         stop_word_filters: Vec<String>,
         #[serde(default)]
+        stop_words: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stop_words_file: Option<String>,
+        #[serde(default)]
         store_doc: bool,
         #[serde(default = "default_length_limit")]
         length_limit: usize,
         #[serde(default)]
         case_sensitive: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        synonyms: Option<SynonymsConfig>,
     },
     #[serde(rename = "ngram")]
     Ngram {
@@ -67,6 +86,10 @@ pub enum ColumnTokenizer {
         #[serde(default, deserialize_with = "stop_word_filters_validator")]
         stop_word_filters: Vec<String>,
         #[serde(default)]
+        stop_words: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        stop_words_file: Option<String>,
+        #[serde(default)]
         store_doc: bool,
         #[serde(default = "default_length_limit")]
         length_limit: usize,
@@ -89,6 +112,8 @@ pub enum ColumnTokenizer {
         hmm: bool,
         #[serde(default)]
         store_doc: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        synonyms: Option<SynonymsConfig>,
     },
     #[serde(rename = "i64")]
     I64 {
@@ -101,6 +126,17 @@ pub enum ColumnTokenizer {
         #[serde(default)]
         coerce: bool,
     },
+    #[serde(rename = "u64")]
+    U64 {
+        #[serde(default)]
+        store_doc: bool,
+        #[serde(default = "default_indexed")]
+        indexed: bool,
+        #[serde(default)]
+        fast: bool,
+        #[serde(default)]
+        coerce: bool,
+    },
     #[serde(rename = "f64")]
     F64 {
         #[serde(default)]
@@ -141,6 +177,47 @@ pub enum ColumnTokenizer {
         #[serde(default)]
         coerce: bool,
     },
+    #[serde(rename = "ip")]
+    Ip {
+        #[serde(default)]
+        store_doc: bool,
+        #[serde(default = "default_indexed")]
+        indexed: bool,
+        #[serde(default)]
+        fast: bool,
+    },
+    #[serde(rename = "json")]
+    Json {
+        #[serde(default = "json_tokenizer_default")]
+        tokenizer: Box<ColumnTokenizer>,
+        #[serde(default)]
+        store_doc: bool,
+        #[serde(default)]
+        expand_dots: bool,
+    },
+}
+
+/// Where synonyms are expanded: when indexing docs, when analyzing queries, or both.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SynonymExpand {
+    Index,
+    Query,
+    #[default]
+    Both,
+}
+
+/// Synonym groups of a text tokenizer, each token of a group is expanded into
+/// all the others. `file` holds one group per line with words separated by `,`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct SynonymsConfig {
+    #[serde(default)]
+    pub groups: Vec<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub expand: SynonymExpand,
 }
 
 fn default_length_limit() -> usize {
@@ -159,6 +236,10 @@ fn default_indexed() -> bool {
     true
 }
 
+fn json_tokenizer_default() -> Box<ColumnTokenizer> {
+    Box::new(ColumnTokenizer::Default { store_doc: false })
+}
+
 fn chinese_jieba_default() -> String {
     "default".to_string()
 }
@@ -247,13 +328,20 @@ impl Column {
 /// Config represents the parameter configuration passed by ClickHouse when creating a Tantivy index.
 /// Currently, ClickHouse only passes a single string to Tantivy,
 /// and this string needs to conform to the JSON5 specification and be parsed into the Config struct.
+/// The key `index_settings` is reserved for index level configurations, it can't be used as a column name.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index_settings: Option<IndexSettingsConfig>,
     #[serde(flatten)]
     columns: std::collections::HashMap<String, Column>,
 }
 
 impl Config {
+    pub fn get_index_settings(&self) -> IndexSettingsConfig {
+        self.index_settings.clone().unwrap_or_default()
+    }
+
     pub fn get_columns(&self) -> &std::collections::HashMap<String, Column> {
         &self.columns
     }
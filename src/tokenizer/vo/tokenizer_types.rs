@@ -8,10 +8,13 @@ pub enum TokenizerType {
     Ngram(String),
     Chinese(String),
     I64(String),
+    U64(String),
     F64(String),
     Bytes(String),
     DateTime(String),
     Bool(String),
+    Ip(String),
+    Json(String),
 }
 
 impl TokenizerType {
@@ -25,10 +28,13 @@ impl TokenizerType {
             TokenizerType::Ngram(name) => name,
             TokenizerType::Chinese(name) => name,
             TokenizerType::I64(name) => name,
+            TokenizerType::U64(name) => name,
             TokenizerType::F64(name) => name,
             TokenizerType::Bytes(name) => name,
             TokenizerType::DateTime(name) => name,
             TokenizerType::Bool(name) => name,
+            TokenizerType::Ip(name) => name,
+            TokenizerType::Json(name) => name,
         }
     }
 }
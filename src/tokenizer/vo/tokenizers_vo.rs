@@ -1,15 +1,22 @@
 use tantivy::tokenizer::TextAnalyzer;
 
+use super::tokenizer_json_vo::SynonymExpand;
 use super::tokenizer_types::TokenizerType;
 
 pub struct TokenizerConfig {
     pub tokenizer_type: TokenizerType,
     pub text_analyzer: TextAnalyzer,
+    // Analyzer registered by index readers, it only differs from `text_analyzer`
+    // when synonyms are expanded at one side.
+    pub search_text_analyzer: TextAnalyzer,
+    // Where synonyms are expanded, `None` means no synonyms.
+    pub synonym_expand: Option<SynonymExpand>,
     pub doc_store: bool,
     pub doc_index: bool,
     pub is_text_field: bool,
     pub doc_fast: bool,
     pub doc_coerce: bool,
+    pub doc_expand_dots: bool,
 }
 
 impl TokenizerConfig {
@@ -17,11 +24,14 @@ impl TokenizerConfig {
         Self {
             tokenizer_type: tokenizer_type.clone(),
             text_analyzer: analyzer.clone(),
+            search_text_analyzer: analyzer.clone(),
+            synonym_expand: None,
             doc_store: stored,
             doc_index: true,
             is_text_field: true,
             doc_fast: false,
             doc_coerce: false,
+            doc_expand_dots: false,
         }
     }
 
@@ -35,11 +45,34 @@ impl TokenizerConfig {
         Self {
             tokenizer_type: tokenizer_type.clone(),
             text_analyzer: TextAnalyzer::default(),
+            search_text_analyzer: TextAnalyzer::default(),
+            synonym_expand: None,
             doc_store: stored,
             doc_index: indexed,
             is_text_field: false,
             doc_fast: fast,
             doc_coerce: coerce,
+            doc_expand_dots: false,
+        }
+    }
+
+    pub fn new_json(
+        analyzer: TextAnalyzer,
+        search_analyzer: TextAnalyzer,
+        stored: bool,
+        expand_dots: bool,
+    ) -> Self {
+        Self {
+            tokenizer_type: TokenizerType::Json("json".to_string()),
+            text_analyzer: analyzer,
+            search_text_analyzer: search_analyzer,
+            synonym_expand: None,
+            doc_store: stored,
+            doc_index: true,
+            is_text_field: false,
+            doc_fast: false,
+            doc_coerce: false,
+            doc_expand_dots: expand_dots,
         }
     }
 }
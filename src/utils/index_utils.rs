@@ -6,6 +6,8 @@ use crate::{common::constants::LOG_CALLBACK, WARNING};
 use std::fs::File;
 use std::io::{Read, Write};
 use std::{fs, path::Path};
+use tantivy::directory::error::OpenReadError;
+use tantivy::{Directory, Index};
 
 /// `IndexUtils` serves as a collection of utility functions for index operations.
 /// It encapsulates global functions related to managing index directory.
@@ -101,4 +103,34 @@ impl IndexUtils {
         })?;
         Ok(result)
     }
+
+    /// Load the custom index settings through directory of `index`,
+    /// it works for both disk index and in-memory index.
+    pub fn load_custom_index_setting_of_index(
+        index: &Index,
+    ) -> Result<IndexParameterDTO, IndexUtilsError> {
+        let contents: Vec<u8> = match index
+            .directory()
+            .atomic_read(Path::new(INDEX_INFO_FILE_NAME))
+        {
+            Ok(contents) => contents,
+            Err(OpenReadError::FileDoesNotExist(_)) => return Ok(IndexParameterDTO::default()),
+            Err(e) => {
+                return Err(IndexUtilsError::ReadFileError(format!(
+                    "file: {}, message: {}",
+                    INDEX_INFO_FILE_NAME,
+                    e.to_string()
+                )))
+            }
+        };
+
+        let result: IndexParameterDTO = serde_json::from_slice(&contents).map_err(|e| {
+            IndexUtilsError::JsonDeserializeError(format!(
+                "file: {}, message: {}",
+                INDEX_INFO_FILE_NAME,
+                e.to_string()
+            ))
+        })?;
+        Ok(result)
+    }
 }